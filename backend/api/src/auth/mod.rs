@@ -1,2 +1,3 @@
 pub mod cookie;
 pub mod jwt;
+pub mod suspension;
@@ -0,0 +1,26 @@
+use chrono::Utc;
+use models::{
+    domain::{user::User, Id},
+    errors::{AppError, AppResult},
+};
+use repository::user_suspension_repository::UserSuspensionRepository;
+
+use crate::AppState;
+
+/// Fails with the reason and end of the suspension when the user is currently suspended.
+pub async fn ensure_not_suspended(state: &AppState, user_id: Id<User>) -> AppResult<()> {
+    let suspension = state
+        .user_suspension_repository
+        .get_active_by_user_id(user_id, Utc::now())
+        .await?
+        .into_iter()
+        .next();
+
+    match suspension {
+        Some(suspension) => Err(AppError::Suspended {
+            reason: suspension.reason,
+            until: suspension.ends_at,
+        }),
+        None => Ok(()),
+    }
+}
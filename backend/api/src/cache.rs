@@ -0,0 +1,30 @@
+use redis::{self, Commands};
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Returns the value stored under `key`, values which no longer deserialize count as missing.
+pub fn get_cached<T: DeserializeOwned>(
+    redis_client: &redis::Client,
+    key: &str,
+) -> redis::RedisResult<Option<T>> {
+    let mut conn = redis_client.get_connection()?;
+    let value: Option<String> = conn.get(key)?;
+    Ok(value.and_then(|value| serde_json::from_str(&value).ok()))
+}
+
+pub fn set_cached<T: Serialize>(
+    redis_client: &redis::Client,
+    key: &str,
+    value: &T,
+    ttl_seconds: u64,
+) -> redis::RedisResult<()> {
+    let value = serde_json::to_string(value).map_err(|err| {
+        redis::RedisError::from((
+            redis::ErrorKind::TypeError,
+            "Failed to serialize cached value",
+            err.to_string(),
+        ))
+    })?;
+
+    let mut conn = redis_client.get_connection()?;
+    conn.set_ex(key, value, ttl_seconds)
+}
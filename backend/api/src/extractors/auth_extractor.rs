@@ -10,13 +10,17 @@ use axum_extra::headers::{Cookie, HeaderMapExt};
 use chrono::Utc;
 use jsonwebtoken::{DecodingKey, Validation};
 use models::{
-    domain::user::UserType,
+    domain::{user::UserType, Id},
     errors::{AppError, AppResult},
 };
+use repository::user_repository::UserRepository;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::{auth::jwt::is_token_blacklisted, AppState};
+use crate::{
+    auth::{jwt::is_token_blacklisted, suspension::ensure_not_suspended},
+    AppState,
+};
 
 // Default session duration is 30 minutes
 const DEFAULT_SESSION_DURATION: time::Duration = time::Duration::from_secs(60 * 30);
@@ -130,6 +134,27 @@ where
                 }
 
                 let auth_user = AuthUser::from_jwt(token, &state.jwt_secret)?;
+
+                let Some(user) = state
+                    .user_repository
+                    .get_by_id(Id::new(auth_user.id))
+                    .await?
+                else {
+                    return Err(AppError::Unauthorized("Unauthorized".into()));
+                };
+
+                // Other sessions end once the account is deactivated, the request
+                // goes on as if signed out
+                if user.is_deactivated() {
+                    return Ok(Self(None));
+                }
+
+                // Tokens issued before the suspension must stop working right away,
+                // the flag mirrors the active suspensions
+                if user.is_blocked {
+                    ensure_not_suspended(&state, user.id).await?;
+                }
+
                 return Ok(Self(Some(auth_user)));
             }
         }
@@ -0,0 +1,31 @@
+use std::time::Duration;
+
+use usecase::user::lift_expired_suspensions::LiftExpiredSuspensionsUseCase;
+
+use crate::AppState;
+
+const LIFT_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Marks ended suspensions as lifted and unblocks users with no other suspension left.
+/// Sign-in does not wait for this, expired suspensions are ignored right away.
+pub fn spawn(app_state: AppState) {
+    tokio::spawn(async move {
+        let lift_use_case = LiftExpiredSuspensionsUseCase::new(
+            app_state.user_repository.clone(),
+            app_state.user_suspension_repository.clone(),
+        );
+        let mut interval = tokio::time::interval(LIFT_INTERVAL);
+
+        loop {
+            interval.tick().await;
+
+            match lift_use_case.execute().await {
+                Ok(output) if output.lifted > 0 => {
+                    println!("Lifted {} expired suspensions", output.lifted)
+                }
+                Ok(_) => {}
+                Err(err) => println!("Failed to lift expired suspensions: {:?}", err),
+            }
+        }
+    });
+}
@@ -0,0 +1,11 @@
+use crate::AppState;
+
+pub mod lift_suspensions;
+pub mod purge_accounts;
+pub mod purge_deleted;
+
+pub fn spawn_jobs(app_state: AppState) {
+    purge_deleted::spawn(app_state.clone());
+    purge_accounts::spawn(app_state.clone());
+    lift_suspensions::spawn(app_state);
+}
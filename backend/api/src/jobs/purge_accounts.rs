@@ -0,0 +1,40 @@
+use std::time::Duration;
+
+use usecase::user::purge_deleted_accounts::{
+    PurgeDeletedAccountsInput, PurgeDeletedAccountsUseCase,
+};
+
+use crate::AppState;
+
+const DEFAULT_GRACE_DAYS: i64 = 30;
+const PURGE_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Periodically removes accounts whose deletion was requested longer than
+/// `ACCOUNT_DELETION_GRACE_DAYS` (30 days by default) ago.
+pub fn spawn(app_state: AppState) {
+    let grace_days = std::env::var("ACCOUNT_DELETION_GRACE_DAYS")
+        .ok()
+        .and_then(|days| days.parse::<i64>().ok())
+        .unwrap_or(DEFAULT_GRACE_DAYS);
+
+    tokio::spawn(async move {
+        let purge_use_case = PurgeDeletedAccountsUseCase::new(app_state.user_repository.clone());
+        let mut interval = tokio::time::interval(PURGE_INTERVAL);
+
+        loop {
+            interval.tick().await;
+
+            let input = PurgeDeletedAccountsInput {
+                grace_period: chrono::Duration::days(grace_days),
+            };
+
+            match purge_use_case.execute(input).await {
+                Ok(output) => println!(
+                    "Purged {} deleted accounts, {} failed",
+                    output.purged_users, output.failed_users
+                ),
+                Err(err) => println!("Failed to purge deleted accounts: {:?}", err),
+            }
+        }
+    });
+}
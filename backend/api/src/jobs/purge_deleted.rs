@@ -0,0 +1,41 @@
+use std::time::Duration;
+
+use usecase::post::purge_deleted::{PurgeDeletedInput, PurgeDeletedUseCase};
+
+use crate::AppState;
+
+const DEFAULT_RETENTION_DAYS: i64 = 30;
+const PURGE_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Periodically removes posts and comments that have been in the trash longer than
+/// `TRASH_RETENTION_DAYS` (30 days by default).
+pub fn spawn(app_state: AppState) {
+    let retention_days = std::env::var("TRASH_RETENTION_DAYS")
+        .ok()
+        .and_then(|days| days.parse::<i64>().ok())
+        .unwrap_or(DEFAULT_RETENTION_DAYS);
+
+    tokio::spawn(async move {
+        let purge_use_case = PurgeDeletedUseCase::new(
+            app_state.post_repository.clone(),
+            app_state.post_comments_repository.clone(),
+        );
+        let mut interval = tokio::time::interval(PURGE_INTERVAL);
+
+        loop {
+            interval.tick().await;
+
+            let input = PurgeDeletedInput {
+                retention: chrono::Duration::days(retention_days),
+            };
+
+            match purge_use_case.execute(input).await {
+                Ok(output) => println!(
+                    "Purged {} posts and {} comments from trash",
+                    output.purged_posts, output.purged_comments
+                ),
+                Err(err) => println!("Failed to purge trash: {:?}", err),
+            }
+        }
+    });
+}
@@ -0,0 +1,483 @@
+use axum::{
+    extract::{Path, Query, State},
+    routing::{delete, get, post},
+};
+use chrono::{DateTime, NaiveDate, Utc};
+use models::{
+    domain::{
+        audit_log::{AuditAction, AuditLogEntry, AuditTargetType},
+        content_filter::{ContentFilter, ContentFilterAction, ContentFilterKind},
+        stats::{StatsBucket, StatsPoint, StatsRange, StatsTotals},
+        user::{User, UserType},
+    },
+    errors::{AppError, AppResult},
+};
+use repository::{audit_log_repository::AuditLogFilter, user_repository::UserListFilter};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use usecase::{
+    audit::get_audit_log::{GetAuditLogInput, GetAuditLogUseCase},
+    content_filter::{
+        create_content_filter::{CreateContentFilterInput, CreateContentFilterUseCase},
+        delete_content_filter::{DeleteContentFilterInput, DeleteContentFilterUseCase},
+        get_content_filters::{GetContentFiltersInput, GetContentFiltersUseCase},
+    },
+    stats::get_stats::{GetStatsInput, GetStatsUseCase},
+    user::{
+        bulk_update_users::{BulkUpdateUsersInput, BulkUpdateUsersUseCase, BulkUserAction},
+        list_users::{ListUsersInput, ListUsersUseCase, UserSortBy},
+    },
+};
+use uuid::Uuid;
+
+use crate::{
+    cache,
+    extractors::{auth_extractor::AuthUser, json_extractor::Json},
+    pagination::{self, PaginationParams},
+    routes::user::DEFAULT_SUSPENSION_REASON,
+    AppState,
+};
+
+/// Optional reason of a moderation action, recorded in the audit log.
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct AuditReasonQuery {
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct AuditLogEntryResponse {
+    id: Uuid,
+    actor_id: Uuid,
+    action: AuditAction,
+    target_type: AuditTargetType,
+    target_id: Uuid,
+    reason: Option<String>,
+    before: Option<Value>,
+    after: Option<Value>,
+    created_at: DateTime<Utc>,
+}
+
+impl From<AuditLogEntry> for AuditLogEntryResponse {
+    fn from(entry: AuditLogEntry) -> Self {
+        Self {
+            id: entry.id.into(),
+            actor_id: entry.actor_id.into(),
+            action: entry.action,
+            target_type: entry.target_type,
+            target_id: entry.target_id,
+            reason: entry.reason,
+            before: entry.before,
+            after: entry.after,
+            created_at: entry.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct AuditLogQuery {
+    actor_id: Option<Uuid>,
+    action: Option<AuditAction>,
+    target_type: Option<AuditTargetType>,
+    target_id: Option<Uuid>,
+    since: Option<DateTime<Utc>>,
+    until: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct AuditLogResponse {
+    entries: Vec<AuditLogEntryResponse>,
+}
+
+async fn get_audit_log(
+    state: State<AppState>,
+    user: AuthUser,
+    Query(query): Query<AuditLogQuery>,
+    Query(pagination): Query<PaginationParams>,
+) -> AppResult<Json<AuditLogResponse>> {
+    let get_audit_log_use_case = GetAuditLogUseCase::new(state.audit_log_repository.clone());
+    let pagination = pagination::Pagination::from(pagination);
+
+    let output = get_audit_log_use_case
+        .execute(GetAuditLogInput {
+            filter: AuditLogFilter {
+                actor_id: query.actor_id.map(|id| id.into()),
+                action: query.action,
+                target_type: query.target_type,
+                target_id: query.target_id,
+                since: query.since,
+                until: query.until,
+            },
+            is_administrator: user.role.is_administrator(),
+            pagination: (pagination.offset, pagination.limit),
+        })
+        .await?;
+
+    Ok(Json(AuditLogResponse {
+        entries: output
+            .entries
+            .into_iter()
+            .map(AuditLogEntryResponse::from)
+            .collect(),
+    }))
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ContentFilterResponse {
+    id: Uuid,
+    pattern: String,
+    kind: ContentFilterKind,
+    action: ContentFilterAction,
+    created_by: Option<Uuid>,
+    created_at: DateTime<Utc>,
+}
+
+impl From<ContentFilter> for ContentFilterResponse {
+    fn from(filter: ContentFilter) -> Self {
+        Self {
+            id: filter.id.into(),
+            pattern: filter.pattern,
+            kind: filter.kind,
+            action: filter.action,
+            created_by: filter.created_by.map(|id| id.into()),
+            created_at: filter.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ContentFiltersResponse {
+    filters: Vec<ContentFilterResponse>,
+}
+
+async fn get_content_filters(
+    state: State<AppState>,
+    user: AuthUser,
+) -> AppResult<Json<ContentFiltersResponse>> {
+    let get_content_filters_use_case =
+        GetContentFiltersUseCase::new(state.content_filter_repository.clone());
+
+    let output = get_content_filters_use_case
+        .execute(GetContentFiltersInput {
+            is_administrator: user.role.is_administrator(),
+        })
+        .await?;
+
+    Ok(Json(ContentFiltersResponse {
+        filters: output
+            .filters
+            .into_iter()
+            .map(ContentFilterResponse::from)
+            .collect(),
+    }))
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct CreateContentFilterRequest {
+    pattern: String,
+    kind: ContentFilterKind,
+    action: ContentFilterAction,
+}
+
+async fn create_content_filter(
+    state: State<AppState>,
+    user: AuthUser,
+    Json(payload): Json<CreateContentFilterRequest>,
+) -> AppResult<Json<ContentFilterResponse>> {
+    let create_content_filter_use_case =
+        CreateContentFilterUseCase::new(state.content_filter_repository.clone());
+
+    let output = create_content_filter_use_case
+        .execute(CreateContentFilterInput {
+            pattern: payload.pattern,
+            kind: payload.kind,
+            action: payload.action,
+            created_by: user.id,
+            is_administrator: user.role.is_administrator(),
+        })
+        .await?;
+
+    Ok(Json(ContentFilterResponse::from(output.filter)))
+}
+
+async fn delete_content_filter(
+    state: State<AppState>,
+    user: AuthUser,
+    Path(id): Path<Uuid>,
+) -> AppResult<()> {
+    let delete_content_filter_use_case =
+        DeleteContentFilterUseCase::new(state.content_filter_repository.clone());
+
+    delete_content_filter_use_case
+        .execute(DeleteContentFilterInput {
+            id,
+            is_administrator: user.role.is_administrator(),
+        })
+        .await
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct AdminUserResponse {
+    id: Uuid,
+    username: String,
+    email: Option<String>,
+    user_type: UserType,
+    is_blocked: bool,
+    is_limited: bool,
+    wall_id: Uuid,
+    created_at: DateTime<Utc>,
+}
+
+impl From<User> for AdminUserResponse {
+    fn from(user: User) -> Self {
+        Self {
+            id: user.id.into(),
+            username: user.username,
+            email: user.email,
+            user_type: user.user_type,
+            is_blocked: user.is_blocked,
+            is_limited: user.is_limited,
+            wall_id: user.wall_id.into(),
+            created_at: user.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct ListUsersQuery {
+    role: Option<UserType>,
+    is_blocked: Option<bool>,
+    is_limited: Option<bool>,
+    username: Option<String>,
+    created_after: Option<DateTime<Utc>>,
+    created_before: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct UserSortQuery {
+    sort_by: Option<UserSortBy>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ListUsersResponse {
+    users: Vec<AdminUserResponse>,
+    total: u64,
+}
+
+async fn list_users(
+    state: State<AppState>,
+    user: AuthUser,
+    Query(query): Query<ListUsersQuery>,
+    Query(sort): Query<UserSortQuery>,
+    Query(pagination): Query<PaginationParams>,
+) -> AppResult<Json<ListUsersResponse>> {
+    let list_users_use_case = ListUsersUseCase::new(state.user_repository.clone());
+    let pagination = pagination::Pagination::from(pagination);
+
+    let output = list_users_use_case
+        .execute(ListUsersInput {
+            filter: UserListFilter {
+                role: query.role,
+                is_blocked: query.is_blocked,
+                is_limited: query.is_limited,
+                username_prefix: query.username.filter(|prefix| !prefix.is_empty()),
+                created_after: query.created_after,
+                created_before: query.created_before,
+            },
+            sort_by: sort.sort_by.unwrap_or_default(),
+            is_moderator: user.role.has_higher_privilege_than(&UserType::Regular),
+            pagination: (pagination.offset, pagination.limit),
+        })
+        .await?;
+
+    Ok(Json(ListUsersResponse {
+        users: output
+            .users
+            .into_iter()
+            .map(AdminUserResponse::from)
+            .collect(),
+        total: output.total,
+    }))
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "action", rename_all = "camelCase")]
+enum BulkUserActionRequest {
+    Block {
+        /// Without an end the users stay blocked until unblocked by a moderator
+        until: Option<DateTime<Utc>>,
+    },
+    Unblock,
+    ChangeRole {
+        role: UserType,
+    },
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct BulkUpdateUsersRequest {
+    user_ids: Vec<Uuid>,
+    /// Recorded in the audit log, and as the suspension reason when blocking
+    reason: Option<String>,
+    #[serde(flatten)]
+    action: BulkUserActionRequest,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct BulkUpdateUsersResponse {
+    updated: Vec<AdminUserResponse>,
+    skipped: Vec<Uuid>,
+}
+
+async fn bulk_update_users(
+    state: State<AppState>,
+    actor: AuthUser,
+    Json(payload): Json<BulkUpdateUsersRequest>,
+) -> AppResult<Json<BulkUpdateUsersResponse>> {
+    let bulk_update_users_use_case = BulkUpdateUsersUseCase::new(state.user_repository.clone());
+
+    let reason = payload
+        .reason
+        .map(|reason| reason.trim().to_string())
+        .filter(|reason| !reason.is_empty());
+
+    let action = match payload.action {
+        BulkUserActionRequest::Block { until } => BulkUserAction::Block {
+            reason: reason
+                .clone()
+                .unwrap_or_else(|| DEFAULT_SUSPENSION_REASON.into()),
+            ends_at: until,
+        },
+        BulkUserActionRequest::Unblock => BulkUserAction::Unblock,
+        BulkUserActionRequest::ChangeRole { role } => BulkUserAction::ChangeRole { role },
+    };
+
+    let output = bulk_update_users_use_case
+        .execute(BulkUpdateUsersInput {
+            actor_id: actor.id,
+            actor_role: actor.role,
+            user_ids: payload.user_ids,
+            action,
+            reason,
+        })
+        .await?;
+
+    Ok(Json(BulkUpdateUsersResponse {
+        updated: output
+            .changes
+            .into_iter()
+            .map(|(_, after)| AdminUserResponse::from(after))
+            .collect(),
+        skipped: output.skipped,
+    }))
+}
+
+/// Buckets shown when the request doesn't bound the range
+const DEFAULT_STATS_BUCKETS: i64 = 30;
+const DEFAULT_STATS_CACHE_SECONDS: u64 = 300;
+
+#[derive(Debug, Clone, Deserialize)]
+struct StatsQuery {
+    /// First day of the range, inclusive
+    from: Option<NaiveDate>,
+    /// Last day of the range, inclusive
+    to: Option<NaiveDate>,
+    bucket: Option<StatsBucket>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct StatsSeriesResponse {
+    signups: Vec<StatsPoint>,
+    posts: Vec<StatsPoint>,
+    comments: Vec<StatsPoint>,
+    likes: Vec<StatsPoint>,
+    active_users: Vec<StatsPoint>,
+    groups: Vec<StatsPoint>,
+    join_requests: Vec<StatsPoint>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct StatsResponse {
+    from: NaiveDate,
+    to: NaiveDate,
+    bucket: StatsBucket,
+    totals: StatsTotals,
+    active_users: i64,
+    series: StatsSeriesResponse,
+}
+
+async fn get_stats(
+    state: State<AppState>,
+    user: AuthUser,
+    Query(query): Query<StatsQuery>,
+) -> AppResult<Json<StatsResponse>> {
+    // Checked before the cache, which is shared by all administrators
+    if !user.role.is_administrator() {
+        return Err(AppError::Unauthorized(
+            "Only administrators can read the statistics".into(),
+        ));
+    }
+
+    let bucket = query.bucket.unwrap_or_default();
+    let default_days = DEFAULT_STATS_BUCKETS * bucket.days();
+    let to = query.to.unwrap_or_else(|| Utc::now().date_naive());
+    let range = match query.from {
+        Some(from) => StatsRange::new(from, to, bucket)?,
+        None => StatsRange::last_days(to, default_days, bucket)?,
+    };
+
+    let cache_key = format!("admin_stats:{}:{}:{}", range.bucket, range.from, range.to);
+
+    // The cache is only an optimization, when Redis is unavailable the stats are computed
+    if let Ok(Some(cached)) = cache::get_cached(&state.redis_client, &cache_key) {
+        return Ok(Json(cached));
+    }
+
+    let get_stats_use_case = GetStatsUseCase::new(state.stats_repository.clone());
+
+    let output = get_stats_use_case
+        .execute(GetStatsInput {
+            range,
+            is_administrator: user.role.is_administrator(),
+        })
+        .await?;
+
+    let response = StatsResponse {
+        from: range.from,
+        to: range.to,
+        bucket: range.bucket,
+        totals: output.totals,
+        active_users: output.active_users,
+        series: StatsSeriesResponse {
+            signups: output.series.signups,
+            posts: output.series.posts,
+            comments: output.series.comments,
+            likes: output.series.likes,
+            active_users: output.series.active_users,
+            groups: output.series.groups,
+            join_requests: output.series.join_requests,
+        },
+    };
+
+    let ttl = std::env::var("ADMIN_STATS_CACHE_SECONDS")
+        .ok()
+        .and_then(|seconds| seconds.parse::<u64>().ok())
+        .unwrap_or(DEFAULT_STATS_CACHE_SECONDS);
+
+    if let Err(err) = cache::set_cached(&state.redis_client, &cache_key, &response, ttl) {
+        println!("Failed to cache admin stats: {:?}", err);
+    }
+
+    Ok(Json(response))
+}
+
+pub fn admin_routes() -> axum::Router<crate::AppState> {
+    axum::Router::new()
+        .route("/audit-log", get(get_audit_log))
+        .route("/stats", get(get_stats))
+        .route("/users", get(list_users))
+        .route("/users/bulk", post(bulk_update_users))
+        .route(
+            "/content-filters",
+            get(get_content_filters).post(create_content_filter),
+        )
+        .route("/content-filters/:id", delete(delete_content_filter))
+}
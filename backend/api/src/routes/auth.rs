@@ -10,11 +10,12 @@ use serde::{Deserialize, Serialize};
 use time::OffsetDateTime;
 use usecase::user::{
     auth_utils::verify_password,
+    cancel_account_deletion::{CancelAccountDeletionInput, CancelAccountDeletionUseCase},
     register_user::{RegisterUserInput, RegisterUserUseCase},
 };
 
 use crate::{
-    auth::cookie::create_cookie,
+    auth::{cookie::create_cookie, suspension::ensure_not_suspended},
     extractors::{auth_extractor::AuthUser, json_extractor::Json},
     AppState,
 };
@@ -41,12 +42,18 @@ pub async fn login(
         .await?
         .ok_or_else(|| AppError::Unauthorized("Invalid username".into()))?;
 
-    if user.is_blocked {
-        return Err(AppError::Unauthorized("User is blocked".into()));
-    }
-
     verify_password(payload.password, user.password_hash).await?;
 
+    // Only told after the password checks out, so the reason is not shown to anyone else
+    ensure_not_suspended(&state, user.id.clone()).await?;
+
+    // Signing in within the grace period keeps the account
+    let user = CancelAccountDeletionUseCase::new(user_repository)
+        .execute(CancelAccountDeletionInput {
+            user_id: user.id.into(),
+        })
+        .await?;
+
     let auth_user = AuthUser::new(user.id.into(), user.username.clone(), user.user_type);
     let token = auth_user.to_jwt(&state.jwt_secret);
 
@@ -81,7 +88,11 @@ async fn register(
     Json(payload): Json<RegisterRequest>,
 ) -> AppResult<(CookieJar, ())> {
     let register_user_usecase =
-        RegisterUserUseCase::new(state.user_repository.clone(), state.wall_repository.clone());
+        RegisterUserUseCase::new(
+        state.user_repository.clone(),
+        state.wall_repository.clone(),
+        state.content_filter_repository.clone(),
+    );
 
     let inserted = register_user_usecase
         .execute(RegisterUserInput {
@@ -2,16 +2,34 @@ use axum::{
     extract::{Path, Query, State},
     routing::{delete, get, post},
 };
-use models::errors::AppResult;
+use models::{
+    domain::{location::Location, user::UserType},
+    errors::{AppError, AppResult},
+};
 use serde::{Deserialize, Serialize};
 
-use usecase::location::create_location::CreateLocationUseCase;
+use usecase::location::{
+    create_location::CreateLocationUseCase,
+    get_location::{GetLocationInput, GetLocationUseCase},
+    get_locations_in_bounds::{GetLocationsInBoundsInput, GetLocationsInBoundsUseCase},
+    get_nearby::{GetNearbyInput, GetNearbyUseCase},
+    merge_locations::{MergeLocationsInput, MergeLocationsUseCase},
+};
 use uuid::Uuid;
 
-use crate::{extractors::json_extractor::Json, AppState};
+use crate::{
+    extractors::{
+        auth_extractor::{AuthUser, OptionalAuthUser},
+        json_extractor::Json,
+    },
+    pagination::{self, PaginationParams},
+    routes::wall::PostItem,
+    AppState,
+};
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct CreateLocationResponse {
     id: Uuid,
+    duplicates: Vec<SearchLocation>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -37,7 +55,14 @@ async fn create_location(
 
     let result = create_location_use_case.execute(input).await?;
 
-    Ok(Json(CreateLocationResponse { id: result.id }))
+    Ok(Json(CreateLocationResponse {
+        id: result.id,
+        duplicates: result
+            .duplicates
+            .into_iter()
+            .map(SearchLocation::from)
+            .collect(),
+    }))
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -81,6 +106,18 @@ struct SearchLocation {
     picture_url: Option<String>,
 }
 
+impl From<Location> for SearchLocation {
+    fn from(location: Location) -> Self {
+        Self {
+            id: location.id.into(),
+            name: location.name,
+            latitude: location.latitude,
+            longitude: location.longitude,
+            picture_url: location.picture_url,
+        }
+    }
+}
+
 async fn search_location(
     state: State<AppState>,
     Query(params): Query<SearchLocationRequest>,
@@ -110,9 +147,191 @@ async fn search_location(
     }))
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct GetLocationResponse {
+    location: SearchLocation,
+    posts: Vec<PostItem>,
+}
+
+async fn get_location(
+    state: State<AppState>,
+    OptionalAuthUser(user): OptionalAuthUser,
+    Path(id): Path<Uuid>,
+    Query(pagination): Query<PaginationParams>,
+) -> AppResult<Json<GetLocationResponse>> {
+    let get_location_use_case = GetLocationUseCase::new(
+        state.location_repository.clone(),
+        state.wall_repository.clone(),
+    );
+    let pagination = pagination::Pagination::from(pagination);
+
+    let output = get_location_use_case
+        .execute(GetLocationInput {
+            id,
+            user_id: user.map(|user| user.id.into()),
+            pagination: (pagination.offset, pagination.limit),
+        })
+        .await?
+        .ok_or(AppError::NotFound("Location".into()))?;
+
+    Ok(Json(GetLocationResponse {
+        location: SearchLocation::from(output.location),
+        posts: output.posts.into_iter().map(PostItem::from).collect(),
+    }))
+}
+
+const DEFAULT_NEARBY_RADIUS_KM: f64 = 10.0;
+const DEFAULT_BOUNDS_LIMIT: i64 = 200;
+const MAX_BOUNDS_LIMIT: i64 = 500;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct NearbyRequest {
+    lat: f64,
+    lon: f64,
+    radius: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct NearbyResponse {
+    locations: Vec<NearbyLocation>,
+    posts: Vec<NearbyPost>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct NearbyLocation {
+    #[serde(flatten)]
+    location: SearchLocation,
+    distance_km: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct NearbyPost {
+    #[serde(flatten)]
+    post: PostItem,
+    distance_km: f64,
+}
+
+async fn get_nearby(
+    state: State<AppState>,
+    OptionalAuthUser(user): OptionalAuthUser,
+    Query(params): Query<NearbyRequest>,
+    Query(pagination): Query<PaginationParams>,
+) -> AppResult<Json<NearbyResponse>> {
+    let get_nearby_use_case = GetNearbyUseCase::new(
+        state.location_repository.clone(),
+        state.wall_repository.clone(),
+    );
+    let pagination = pagination::Pagination::from(pagination);
+
+    let output = get_nearby_use_case
+        .execute(GetNearbyInput {
+            latitude: params.lat,
+            longitude: params.lon,
+            radius_km: params.radius.unwrap_or(DEFAULT_NEARBY_RADIUS_KM),
+            user_id: user.map(|user| user.id.into()),
+            pagination: (pagination.offset, pagination.limit),
+        })
+        .await?;
+
+    Ok(Json(NearbyResponse {
+        locations: output
+            .locations
+            .into_iter()
+            .map(|(location, distance_km)| NearbyLocation {
+                location: SearchLocation::from(location),
+                distance_km,
+            })
+            .collect(),
+        posts: output
+            .posts
+            .into_iter()
+            .map(|(post, distance_km)| NearbyPost {
+                post: PostItem::from(post),
+                distance_km,
+            })
+            .collect(),
+    }))
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct BoundsRequest {
+    south: f64,
+    west: f64,
+    north: f64,
+    east: f64,
+    limit: Option<i64>,
+}
+
+async fn get_locations_in_bounds(
+    state: State<AppState>,
+    Query(params): Query<BoundsRequest>,
+) -> AppResult<Json<SearchLocationResponse>> {
+    let get_locations_in_bounds_use_case =
+        GetLocationsInBoundsUseCase::new(state.location_repository.clone());
+
+    let output = get_locations_in_bounds_use_case
+        .execute(GetLocationsInBoundsInput {
+            south: params.south,
+            west: params.west,
+            north: params.north,
+            east: params.east,
+            limit: params
+                .limit
+                .unwrap_or(DEFAULT_BOUNDS_LIMIT)
+                .clamp(1, MAX_BOUNDS_LIMIT),
+        })
+        .await?;
+
+    Ok(Json(SearchLocationResponse {
+        locations: output
+            .locations
+            .into_iter()
+            .map(SearchLocation::from)
+            .collect(),
+    }))
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct MergeLocationsRequest {
+    duplicate_ids: Vec<Uuid>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct MergeLocationsResponse {
+    location: SearchLocation,
+    moved_posts: u64,
+}
+
+async fn merge_locations(
+    state: State<AppState>,
+    user: AuthUser,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<MergeLocationsRequest>,
+) -> AppResult<Json<MergeLocationsResponse>> {
+    let merge_locations_use_case = MergeLocationsUseCase::new(state.location_repository.clone());
+
+    let output = merge_locations_use_case
+        .execute(MergeLocationsInput {
+            canonical_id: id,
+            duplicate_ids: payload.duplicate_ids,
+            is_moderator: user.role.has_higher_privilege_than(&UserType::Regular),
+        })
+        .await?
+        .ok_or(AppError::NotFound("Location".into()))?;
+
+    Ok(Json(MergeLocationsResponse {
+        location: SearchLocation::from(output.location),
+        moved_posts: output.moved_posts,
+    }))
+}
+
 pub fn location_routes() -> axum::Router<crate::AppState> {
     axum::Router::new()
         .route("/", post(create_location))
+        .route("/:id", get(get_location))
         .route("/:id", delete(delete_location))
+        .route("/:id/merge", post(merge_locations))
         .route("/search", get(search_location))
+        .route("/nearby", get(get_nearby))
+        .route("/bbox", get(get_locations_in_bounds))
 }
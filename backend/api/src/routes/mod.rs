@@ -1,9 +1,11 @@
+pub mod admin;
 pub mod auth;
 pub mod group;
 pub mod group_join_request;
 pub mod location;
 pub mod post;
 pub mod post_tag;
+pub mod report;
 pub mod search;
 pub mod user;
 pub mod wall;
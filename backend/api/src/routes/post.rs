@@ -1,19 +1,34 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDateTime, Utc};
+use repository::media_storage::MediaStorage;
 use serde::{Deserialize, Serialize};
 use usecase::{
     group::{create_group, delete_group},
+    notification::notify_mentions::{NotifyMentionsInput, NotifyMentionsUseCase},
     post::{
         add_post_to_wall::{AddPostToWallInput, AddPostToWallUseCase},
         comment_post::{CommentPostInput, CommentPostUseCase},
         create_post::{CreatePostInput, CreatePostUseCase},
         delete_post::{DeletePostInput, DeletePostUseCase},
-        get_comment::{GetCommentInput, GetCommentUseCase},
+        edit_comment::{EditCommentInput, EditCommentUseCase},
+        get_comment_replies::{GetCommentRepliesInput, GetCommentRepliesUseCase},
+        get_comment_tree::{GetCommentTreeInput, GetCommentTreeUseCase},
+        get_deleted_posts::{GetDeletedPostsInput, GetDeletedPostsUseCase},
+        get_held_content::{GetHeldContentInput, GetHeldContentUseCase},
         get_post::{GetPostInput, GetPostUseCase},
         get_post_comments::{GetPostCommentsInput, GetPostCommentsUseCase},
         get_post_is_liked_by_user::{PostLikedByUserInput, PostLikedByUserUseCase},
         get_post_likes::{self, GetPostLikesInput, GetPostLikesUseCase},
+        get_post_mentions::{GetPostMentionsInput, GetPostMentionsUseCase},
+        get_post_reactions::{GetPostReactionsInput, GetPostReactionsUseCase},
+        get_reaction_users::{GetReactionUsersInput, GetReactionUsersUseCase},
+        hide_comment::{HideCommentInput, HideCommentUseCase},
+        like_comment::{LikeCommentInput, LikeCommentUseCase},
         like_post::{LikePostInput, LikePostUseCase},
+        react_post::{ReactPostInput, ReactPostUseCase},
+        restore_comment::{RestoreCommentInput, RestoreCommentUseCase},
+        restore_post::{RestorePostInput, RestorePostUseCase},
         uncomment_post::{UncommentPostInput, UncommentPostUseCase},
+        unlike_comment::{UnlikeCommentInput, UnlikeCommentUseCase},
         unlike_post::{UnlikePostInput, UnlikePostUseCase},
         update_post::{UpdatePostInput, UpdatePostUseCase},
         upload_image::{UploadImageInput, UploadImageUseCase},
@@ -23,7 +38,12 @@ use usecase::{
         delete_tag::{DeletePostTagInput, DeletePostTagUseCase},
         get_post_tags::{self, GetPostTagsInput, GetPostTagsUseCase},
     },
-    user::get_user,
+    saved::{
+        save_post::{SavePostInput, SavePostUseCase},
+        unsave_post::{UnsavePostInput, UnsavePostUseCase},
+    },
+    user::get_user::{GetUserInput, GetUserUseCase},
+    user_relation::check_user_block::{CheckUserBlockInput, CheckUserBlockUseCase},
     visibility::{
         create_post_group_visibility::{
             self, CreateGroupPostVisibilityInput, CreateGroupPostVisibilityUseCase,
@@ -42,21 +62,33 @@ use usecase::{
     },
 };
 use uuid::Uuid;
-use validator::ValidationErrors;
+use validator::{ValidationError, ValidationErrors};
 
 use crate::{
-    extractors::{auth_extractor::AuthUser, json_extractor::Json},
+    extractors::{
+        auth_extractor::{AuthUser, OptionalAuthUser},
+        json_extractor::Json,
+    },
+    pagination::{self, PaginationParams},
+    routes::admin::AuditReasonQuery,
     AppState,
 };
 
 use axum::{
-    extract::{DefaultBodyLimit, Path, State},
+    extract::{DefaultBodyLimit, Path, Query, State},
     routing::{delete, get, post, put},
 };
 use models::{
     domain::{
-        post::{Post, PostType, PostVisibilityType},
-        user::UserType,
+        comment_tree::CommentNode,
+        image_variant::{ImageLimits, ImageVariant},
+        mention::{stored_mention_spans, Mention, MentionSpan},
+        post::{CommentPolicy, Post, PostType, PostVisibilityType},
+        post_comment::MAX_COMMENT_DEPTH,
+        post_tag::{extract_hashtags, PostTag},
+        reaction::ReactionCount,
+        user::{User, UserType},
+        Id,
     },
     errors::{AppError, AppResult},
 };
@@ -67,16 +99,23 @@ struct CreatePostRequest {
     description: String,
     post_type: String,
     content_url: String,
+    /// Variants returned by the image upload
+    #[serde(default)]
+    image_variants: Vec<ImageVariant>,
     visibility: String,
     location_id: Option<Uuid>,
     tags: Option<Vec<String>>,
     allowed_users: Option<Vec<Uuid>>,
     allowed_groups: Option<Vec<Uuid>>,
+    #[serde(default)]
+    comment_policy: CommentPolicy,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct CreatePostResponse {
     id: Uuid,
+    /// The post waits for a moderator before it is published
+    held: bool,
 }
 
 async fn create_post(
@@ -84,10 +123,34 @@ async fn create_post(
     user: AuthUser,
     Json(payload): Json<CreatePostRequest>,
 ) -> AppResult<Json<CreatePostResponse>> {
+    // Variants are taken only from our own storage, a client can't put other URLs in the srcset
+    if payload
+        .image_variants
+        .iter()
+        .any(|variant| !state.media_storage.is_stored_url(&variant.url))
+    {
+        let mut errors = ValidationErrors::new();
+        errors.add(
+            "image_variants",
+            ValidationError::new("Image variants must be uploaded first"),
+        );
+        return Err(AppError::ValidationError(errors));
+    }
+
     let post_usecase = CreatePostUseCase::new(
         state.post_repository.clone(),
         state.wall_post_repository.clone(),
         state.user_repository.clone(),
+        state.mention_repository.clone(),
+        state.content_filter_repository.clone(),
+        state.report_repository.clone(),
+        state.user_relation_repository.clone(),
+    );
+    let notify_mentions_use_case = NotifyMentionsUseCase::new(
+        state.wall_repository.clone(),
+        state.notification_repository.clone(),
+        state.mention_repository.clone(),
+        state.user_relation_repository.clone(),
     );
     let create_tag_use_case = usecase::post_tag::create_post_tag::CreatePostTagUseCase::new(
         state.post_tag_repository.clone(),
@@ -116,19 +179,30 @@ async fn create_post(
             _ => return Err(AppError::ValidationError(ValidationErrors::new())),
         },
         location_id: payload.location_id,
+        image_variants: payload.image_variants,
+        comment_policy: payload.comment_policy,
     };
 
+    // Explicit tags are checked before anything is stored, an invalid one must not leave
+    // the post behind
+    let mut tags = payload.tags.clone().unwrap_or_default();
+
+    for tag in &tags {
+        PostTag::new(Id::gen(), tag.clone())?;
+    }
+
     let output = post_usecase.execute(input).await?;
 
-    if !payload.tags.is_none() {
-        for tag in payload.tags.unwrap() {
-            let tag_input = usecase::post_tag::create_post_tag::CreatePostTagInput {
-                post_id: output.id,
-                tag,
-            };
+    // Explicit tags are merged with the hashtags left in the description after filtering
+    tags.extend(extract_hashtags(&output.description));
 
-            create_tag_use_case.execute(tag_input).await?;
-        }
+    for tag in tags {
+        let tag_input = usecase::post_tag::create_post_tag::CreatePostTagInput {
+            post_id: output.id,
+            tag,
+        };
+
+        create_tag_use_case.execute(tag_input).await?;
     }
 
     if !payload.allowed_users.is_none() {
@@ -158,7 +232,17 @@ async fn create_post(
         }
     }
 
-    anyhow::Result::Ok(Json(CreatePostResponse { id: output.id }))
+    // Only now the visibility is complete and mentioned users who can't see the post are skipped
+    notify_mentions_use_case
+        .execute(NotifyMentionsInput {
+            mentions: output.mentions,
+        })
+        .await?;
+
+    anyhow::Result::Ok(Json(CreatePostResponse {
+        id: output.id,
+        held: output.held,
+    }))
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -169,24 +253,35 @@ struct GetPostResponse {
     post_type: String,
     author_id: Uuid,
     content_url: String,
+    srcset: Vec<ImageVariant>,
     visibility: String,
+    mentions: Vec<MentionSpan>,
     like_count: i32,
+    reactions: Vec<ReactionCount>,
     comments: Option<Vec<GetPostCommentResponse>>,
     location_id: Option<Uuid>,
     created_at: DateTime<Utc>,
     tags: Option<Vec<String>>,
     allowed_users: Option<Vec<AllowedUserResponse>>,
     allowed_groups: Option<Vec<AllowedGroupResponse>>,
+    comment_policy: CommentPolicy,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct GetPostCommentResponse {
     id: Uuid,
     content: String,
+    mentions: Vec<MentionSpan>,
     username: String,
     avatar_url: Option<String>,
     user_id: Uuid,
     parent_id: Option<Uuid>,
+    like_count: i64,
+    created_at: DateTime<Utc>,
+    updated_at: Option<DateTime<Utc>>,
+    edited: bool,
+    /// Hidden by the author of the post, only they, the commenter and moderators see it
+    hidden: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -205,9 +300,19 @@ struct AllowedGroupResponse {
 async fn get_post(
     state: State<AppState>,
     Path(id): Path<Uuid>,
+    OptionalAuthUser(user): OptionalAuthUser,
 ) -> AppResult<Json<GetPostResponse>> {
+    let viewer_id = user.as_ref().map(|user| user.id);
+    let is_moderator = user
+        .as_ref()
+        .is_some_and(|user| user.role.has_higher_privilege_than(&UserType::Regular));
+
     let post_usecase = GetPostUseCase::new(state.post_repository.clone());
     let get_post_likes_use_case = GetPostLikesUseCase::new(state.post_likes_repository.clone());
+    let get_post_reactions_use_case = GetPostReactionsUseCase::new(
+        state.post_likes_repository.clone(),
+        state.user_relation_repository.clone(),
+    );
     let get_post_comments_use_case =
         GetPostCommentsUseCase::new(state.post_comments_repository.clone());
 
@@ -219,15 +324,57 @@ async fn get_post(
     let get_post_tags_use_case = GetPostTagsUseCase::new(state.post_tag_repository.clone());
 
     let post = post_usecase.execute(GetPostInput { id }).await?;
+
+    // Posts of limited users and of users who blocked the viewer are hidden as if missing
+    if let Some(post) = &post {
+        let author = GetUserUseCase::new(state.user_repository.clone())
+            .execute(GetUserInput {
+                id: post.post.author_id.id,
+            })
+            .await?;
+
+        if author.is_some_and(|author| {
+            !author.is_content_visible_to(viewer_id.map(Id::new).as_ref(), is_moderator)
+        }) {
+            return Err(AppError::NotFound("Post".into()));
+        }
+
+        let block = CheckUserBlockUseCase::new(state.user_relation_repository.clone())
+            .execute(CheckUserBlockInput {
+                user_id: post.post.author_id.id,
+                viewer_id: viewer_id.filter(|_| !is_moderator),
+            })
+            .await?;
+
+        if block.blocked {
+            return Err(AppError::NotFound("Post".into()));
+        }
+    }
+
     let likes = get_post_likes_use_case
         .execute(GetPostLikesInput { id: id })
         .await?;
+    let reactions = get_post_reactions_use_case
+        .execute(GetPostReactionsInput {
+            post_id: id,
+            user_id: None,
+            is_moderator,
+        })
+        .await?;
     let comments = get_post_comments_use_case
-        .execute(GetPostCommentsInput { id: id })
+        .execute(GetPostCommentsInput {
+            id,
+            viewer_id,
+            is_moderator,
+        })
         .await?;
     let tags = get_post_tags_use_case
         .execute(GetPostTagsInput { id })
         .await?;
+    let mentions = GetPostMentionsUseCase::new(state.mention_repository.clone())
+        .execute(GetPostMentionsInput { post_id: id })
+        .await?
+        .mentions;
 
     let group_visibility = get_group_post_visibility_use_case
         .execute(GetGroupPostVisibilityInput { post_id: id })
@@ -240,12 +387,14 @@ async fn get_post(
         anyhow::Result::Ok(Json(GetPostResponse {
             id: post.post.id.into(),
             title: post.post.title,
+            mentions: stored_mention_spans(&post.post.description, None, &mentions),
             description: post.post.description,
             post_type: match post.post.post_type {
                 PostType::Photo => "photo".into(),
             },
             author_id: post.post.author_id.into(),
             content_url: post.post.content_url,
+            srcset: post.post.image_variants,
             visibility: match post.post.visibility {
                 PostVisibilityType::Public => "public".into(),
                 PostVisibilityType::Private => "private".into(),
@@ -254,6 +403,7 @@ async fn get_post(
             like_count: likes
                 .unwrap_or(get_post_likes::GetPostLikesOutput { like_count: 0 })
                 .like_count,
+            reactions: reactions.reactions,
             comments: comments.map(|comments| {
                 comments
                     .comments
@@ -263,11 +413,22 @@ async fn get_post(
                         username: comment.1.username.clone(),
                         avatar_url: comment.1.avatar_url.clone(),
                         content: comment.0.content.clone(),
+                        mentions: stored_mention_spans(
+                            &comment.0.content,
+                            Some(&comment.0.id),
+                            &mentions,
+                        ),
                         user_id: comment.0.clone().user_id.into(),
                         parent_id: comment.0.clone().parent_id.map(|id| id.into()),
+                        like_count: comment.2,
+                        created_at: comment.0.created_at,
+                        updated_at: comment.0.updated_at,
+                        edited: comment.0.is_edited(),
+                        hidden: comment.0.is_hidden(),
                     })
                     .collect()
             }),
+            comment_policy: post.post.comment_policy,
             tags: Some(tags.tags.into_iter().map(|tag| tag.tag).collect()),
             allowed_users: Some(
                 user_visibility
@@ -306,6 +467,7 @@ async fn delete_post(
     state: State<AppState>,
     Path(id): Path<Uuid>,
     user: AuthUser,
+    Query(audit): Query<AuditReasonQuery>,
 ) -> AppResult<Json<DeletePostResponse>> {
     let post_use_case = GetPostUseCase::new(state.post_repository.clone());
     let delete_use_case = DeletePostUseCase::new(state.post_repository.clone());
@@ -315,14 +477,20 @@ async fn delete_post(
         return Err(AppError::NotFound("Post".into()));
     }
 
-    if user.id != post.unwrap().post.author_id.id
-        && user.role != UserType::Administrator
-        && user.role != UserType::Moderator
-    {
+    let post = post.unwrap().post;
+    let is_author = user.id == post.author_id.id;
+
+    if !is_author && user.role != UserType::Administrator && user.role != UserType::Moderator {
         return Err(AppError::Unauthorized("Unauthorized".into()));
     }
 
-    let result = delete_use_case.execute(DeletePostInput { id }).await?;
+    let result = delete_use_case
+        .execute(DeletePostInput {
+            id,
+            deleted_by: user.id,
+            reason: audit.reason,
+        })
+        .await?;
 
     if result.is_none() {
         return Err(AppError::NotFound("Post".into()));
@@ -341,12 +509,15 @@ struct UpdatePostRequest {
     tags: Option<Vec<String>>,
     allowed_users: Option<Vec<Uuid>>,
     allowed_groups: Option<Vec<Uuid>>,
+    /// Left unchanged when missing
+    comment_policy: Option<CommentPolicy>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct UpdatePostResponse {
     id: Uuid,
     description: String,
+    mentions: Vec<MentionSpan>,
     post_type: String,
     content_url: String,
     author_id: Uuid,
@@ -356,6 +527,9 @@ struct UpdatePostResponse {
     tags: Option<Vec<String>>,
     allowed_users: Option<Vec<Uuid>>,
     allowed_groups: Option<Vec<Uuid>>,
+    comment_policy: CommentPolicy,
+    /// The post went back to waiting for a moderator
+    held: bool,
 }
 
 async fn update_post(
@@ -365,7 +539,20 @@ async fn update_post(
     Json(payload): Json<UpdatePostRequest>,
 ) -> AppResult<Json<UpdatePostResponse>> {
     let post_use_case = GetPostUseCase::new(state.post_repository.clone());
-    let update_post_use_case = UpdatePostUseCase::new(state.post_repository.clone());
+    let update_post_use_case = UpdatePostUseCase::new(
+        state.post_repository.clone(),
+        state.user_repository.clone(),
+        state.mention_repository.clone(),
+        state.content_filter_repository.clone(),
+        state.report_repository.clone(),
+        state.user_relation_repository.clone(),
+    );
+    let notify_mentions_use_case = NotifyMentionsUseCase::new(
+        state.wall_repository.clone(),
+        state.notification_repository.clone(),
+        state.mention_repository.clone(),
+        state.user_relation_repository.clone(),
+    );
     let create_post_tag_use_case = usecase::post_tag::create_post_tag::CreatePostTagUseCase::new(
         state.post_tag_repository.clone(),
     );
@@ -425,6 +612,13 @@ async fn update_post(
         },
         location_id: payload.location_id.map(|id| id.into()),
         created_at: unwraped_post.post.created_at,
+        deleted_at: None,
+        deleted_by: None,
+        image_variants: unwraped_post.post.image_variants,
+        comment_policy: payload
+            .comment_policy
+            .unwrap_or(unwraped_post.post.comment_policy),
+        held: false,
     };
 
     let result = update_post_use_case
@@ -456,6 +650,13 @@ async fn update_post(
         }
     }
 
+    // Hashtags from the description are always kept, even when the tags get replaced
+    for tag in extract_hashtags(&result.as_ref().unwrap().post.description) {
+        let tag_input = usecase::post_tag::create_post_tag::CreatePostTagInput { post_id: id, tag };
+
+        create_post_tag_use_case.execute(tag_input).await?;
+    }
+
     let group_visibility = get_group_post_visibility_use_case
         .execute(GetGroupPostVisibilityInput { post_id: id })
         .await?;
@@ -523,8 +724,21 @@ async fn update_post(
         .await?;
 
     let updated_post = result.unwrap();
+
+    notify_mentions_use_case
+        .execute(NotifyMentionsInput {
+            mentions: updated_post.mentions,
+        })
+        .await?;
+
+    let mentions = GetPostMentionsUseCase::new(state.mention_repository.clone())
+        .execute(GetPostMentionsInput { post_id: id })
+        .await?
+        .mentions;
+
     anyhow::Result::Ok(Json(UpdatePostResponse {
         id: updated_post.post.id.into(),
+        mentions: stored_mention_spans(&updated_post.post.description, None, &mentions),
         description: updated_post.post.description,
         post_type: match updated_post.post.post_type {
             PostType::Photo => "photo".into(),
@@ -537,6 +751,7 @@ async fn update_post(
         },
         location_id: updated_post.post.location_id.map(|id| id.into()),
         created_at: updated_post.post.created_at,
+        comment_policy: updated_post.post.comment_policy,
         tags: Some(tags.tags.into_iter().map(|tag| tag.tag).collect()),
         allowed_users: Some(
             user_visibility
@@ -552,6 +767,7 @@ async fn update_post(
                 .map(|visibility| visibility.0.group_id.clone().into())
                 .collect(),
         ),
+        held: updated_post.held,
     }))
 }
 
@@ -565,7 +781,10 @@ async fn like_post(
     Path(id): Path<Uuid>,
     user: AuthUser,
 ) -> AppResult<Json<LikePostResponse>> {
-    let like_use_case = LikePostUseCase::new(state.post_likes_repository.clone());
+    let like_use_case = LikePostUseCase::new(
+        state.post_likes_repository.clone(),
+        state.user_relation_repository.clone(),
+    );
 
     let result = like_use_case
         .execute(LikePostInput {
@@ -647,6 +866,212 @@ async fn check_like_get(
     }))
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ReactPostRequest {
+    kind: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ReactPostResponse {
+    kind: String,
+}
+
+async fn react_post(
+    state: State<AppState>,
+    Path(id): Path<Uuid>,
+    user: AuthUser,
+    Json(payload): Json<ReactPostRequest>,
+) -> AppResult<Json<ReactPostResponse>> {
+    let react_post_use_case = ReactPostUseCase::new(
+        state.post_repository.clone(),
+        state.post_likes_repository.clone(),
+        state.user_relation_repository.clone(),
+        state.reaction_kinds.clone(),
+    );
+
+    let result = react_post_use_case
+        .execute(ReactPostInput {
+            post_id: id,
+            user_id: user.id,
+            kind: payload.kind,
+        })
+        .await?;
+
+    match result {
+        Some(output) => anyhow::Result::Ok(Json(ReactPostResponse { kind: output.kind })),
+        None => Err(AppError::NotFound("Post".into())),
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct GetPostReactionsResponse {
+    reactions: Vec<ReactionCount>,
+    user_reaction: Option<String>,
+}
+
+async fn get_post_reactions(
+    state: State<AppState>,
+    Path(id): Path<Uuid>,
+    OptionalAuthUser(user): OptionalAuthUser,
+) -> AppResult<Json<GetPostReactionsResponse>> {
+    let get_post_reactions_use_case = GetPostReactionsUseCase::new(
+        state.post_likes_repository.clone(),
+        state.user_relation_repository.clone(),
+    );
+
+    let output = get_post_reactions_use_case
+        .execute(GetPostReactionsInput {
+            post_id: id,
+            user_id: user.as_ref().map(|user| user.id),
+            is_moderator: user
+                .as_ref()
+                .is_some_and(|user| user.role.has_higher_privilege_than(&UserType::Regular)),
+        })
+        .await?;
+
+    anyhow::Result::Ok(Json(GetPostReactionsResponse {
+        reactions: output.reactions,
+        user_reaction: output.user_reaction,
+    }))
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ReactionUserResponse {
+    id: Uuid,
+    username: String,
+    avatar_url: Option<String>,
+    created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct GetReactionUsersResponse {
+    kind: String,
+    users: Vec<ReactionUserResponse>,
+}
+
+async fn get_reaction_users(
+    state: State<AppState>,
+    Path((id, kind)): Path<(Uuid, String)>,
+    OptionalAuthUser(user): OptionalAuthUser,
+    Query(pagination): Query<PaginationParams>,
+) -> AppResult<Json<GetReactionUsersResponse>> {
+    if !state.reaction_kinds.contains(&kind) {
+        return Err(AppError::BadRequest(format!(
+            "Unknown reaction kind '{}'",
+            kind
+        )));
+    }
+
+    let get_reaction_users_use_case = GetReactionUsersUseCase::new(
+        state.post_likes_repository.clone(),
+        state.user_relation_repository.clone(),
+    );
+    let pagination = pagination::Pagination::from(pagination);
+
+    let output = get_reaction_users_use_case
+        .execute(GetReactionUsersInput {
+            post_id: id,
+            kind: kind.clone(),
+            pagination: (pagination.offset, pagination.limit),
+            viewer_id: user.as_ref().map(|user| user.id),
+            is_moderator: user
+                .as_ref()
+                .is_some_and(|user| user.role.has_higher_privilege_than(&UserType::Regular)),
+        })
+        .await?;
+
+    anyhow::Result::Ok(Json(GetReactionUsersResponse {
+        kind,
+        users: output
+            .reactions
+            .into_iter()
+            .map(|(like, user)| ReactionUserResponse {
+                id: user.id.into(),
+                username: user.username,
+                avatar_url: user.avatar_url,
+                created_at: like.created_at,
+            })
+            .collect(),
+    }))
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct GetReactionKindsResponse {
+    kinds: Vec<String>,
+}
+
+async fn get_reaction_kinds(state: State<AppState>) -> AppResult<Json<GetReactionKindsResponse>> {
+    anyhow::Result::Ok(Json(GetReactionKindsResponse {
+        kinds: state.reaction_kinds.kinds().to_vec(),
+    }))
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+struct SavePostRequest {
+    collection_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct SavePostResponse {
+    collection_id: Option<Uuid>,
+    created_at: DateTime<Utc>,
+}
+
+async fn save_post(
+    state: State<AppState>,
+    Path(id): Path<Uuid>,
+    user: AuthUser,
+    payload: Option<Json<SavePostRequest>>,
+) -> AppResult<Json<SavePostResponse>> {
+    let save_post_use_case = SavePostUseCase::new(
+        state.wall_repository.clone(),
+        state.saved_posts_repository.clone(),
+    );
+    // The body is optional, without it the post is saved outside of any collection
+    let payload = payload.map(|Json(payload)| payload).unwrap_or_default();
+
+    let result = save_post_use_case
+        .execute(SavePostInput {
+            user_id: user.id,
+            post_id: id,
+            collection_id: payload.collection_id,
+        })
+        .await?;
+
+    match result {
+        Some(output) => anyhow::Result::Ok(Json(SavePostResponse {
+            collection_id: output.saved_post.collection_id.map(|id| id.into()),
+            created_at: output.saved_post.created_at,
+        })),
+        None => Err(AppError::NotFound("Post".into())),
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct UnsavePostResponse {
+    success: bool,
+}
+
+async fn unsave_post(
+    state: State<AppState>,
+    Path(id): Path<Uuid>,
+    user: AuthUser,
+) -> AppResult<Json<UnsavePostResponse>> {
+    let unsave_post_use_case = UnsavePostUseCase::new(state.saved_posts_repository.clone());
+
+    let result = unsave_post_use_case
+        .execute(UnsavePostInput {
+            user_id: user.id,
+            post_id: id,
+        })
+        .await?;
+
+    match result {
+        Some(_) => anyhow::Result::Ok(Json(UnsavePostResponse { success: true })),
+        None => Err(AppError::NotFound("Saved post".into())),
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct CommentPostRequest {
     content: String,
@@ -656,6 +1081,8 @@ struct CommentPostRequest {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct CommentPostResponse {
     id: Uuid,
+    /// The comment waits for a moderator before it is shown
+    held: bool,
 }
 
 async fn comment_post(
@@ -664,7 +1091,24 @@ async fn comment_post(
     user: AuthUser,
     Json(payload): Json<CommentPostRequest>,
 ) -> AppResult<Json<CommentPostResponse>> {
-    let comment_use_case = CommentPostUseCase::new(state.post_comments_repository.clone());
+    let comment_use_case = CommentPostUseCase::new(
+        state.post_comments_repository.clone(),
+        state.post_repository.clone(),
+        state.user_repository.clone(),
+        state.mention_repository.clone(),
+        state.content_filter_repository.clone(),
+        state.report_repository.clone(),
+        state.user_relation_repository.clone(),
+    );
+    let notify_mentions_use_case = NotifyMentionsUseCase::new(
+        state.wall_repository.clone(),
+        state.notification_repository.clone(),
+        state.mention_repository.clone(),
+        state.user_relation_repository.clone(),
+    );
+    let create_tag_use_case = usecase::post_tag::create_post_tag::CreatePostTagUseCase::new(
+        state.post_tag_repository.clone(),
+    );
 
     let result = comment_use_case
         .execute(CommentPostInput {
@@ -675,15 +1119,299 @@ async fn comment_post(
         })
         .await?;
 
-    if result.is_none() {
+    let Some(output) = result else {
         return Err(AppError::NotFound("Comment".into()));
+    };
+
+    // Hashtags used in comments tag the commented post
+    for tag in extract_hashtags(&output.content) {
+        let tag_input = usecase::post_tag::create_post_tag::CreatePostTagInput { post_id: id, tag };
+
+        create_tag_use_case.execute(tag_input).await?;
     }
 
+    notify_mentions_use_case
+        .execute(NotifyMentionsInput {
+            mentions: output.mentions,
+        })
+        .await?;
+
     anyhow::Result::Ok(Json(CommentPostResponse {
-        id: result.unwrap().id,
+        id: output.id,
+        held: output.held,
     }))
 }
 
+const DEFAULT_COMMENT_DEPTH: usize = 3;
+const DEFAULT_REPLIES_LIMIT: usize = 3;
+const MAX_REPLIES_LIMIT: usize = 20;
+
+#[derive(Debug, Deserialize)]
+struct CommentTreeQuery {
+    offset: Option<i64>,
+    limit: Option<i64>,
+    depth: Option<usize>,
+    replies_limit: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CommentNodeResponse {
+    id: Uuid,
+    content: String,
+    mentions: Vec<MentionSpan>,
+    username: String,
+    avatar_url: Option<String>,
+    user_id: Uuid,
+    parent_id: Option<Uuid>,
+    like_count: i64,
+    created_at: DateTime<Utc>,
+    updated_at: Option<DateTime<Utc>>,
+    edited: bool,
+    hidden: bool,
+    reply_count: usize,
+    replies: Vec<CommentNodeResponse>,
+}
+
+impl CommentNodeResponse {
+    /// `mentions` are the stored mentions of the post the comments belong to
+    fn new(node: CommentNode, mentions: &[(Mention, User)]) -> Self {
+        Self {
+            edited: node.comment.is_edited(),
+            hidden: node.comment.is_hidden(),
+            mentions: stored_mention_spans(&node.comment.content, Some(&node.comment.id), mentions),
+            id: node.comment.id.into(),
+            content: node.comment.content,
+            username: node.author.username,
+            avatar_url: node.author.avatar_url,
+            user_id: node.comment.user_id.into(),
+            parent_id: node.comment.parent_id.map(|id| id.into()),
+            like_count: node.like_count,
+            created_at: node.comment.created_at,
+            updated_at: node.comment.updated_at,
+            reply_count: node.reply_count,
+            replies: node
+                .replies
+                .into_iter()
+                .map(|reply| Self::new(reply, mentions))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct GetCommentTreeResponse {
+    comments: Vec<CommentNodeResponse>,
+    total: usize,
+}
+
+async fn get_post_comment_tree(
+    state: State<AppState>,
+    Path(id): Path<Uuid>,
+    OptionalAuthUser(user): OptionalAuthUser,
+    Query(query): Query<CommentTreeQuery>,
+) -> AppResult<Json<GetCommentTreeResponse>> {
+    let comment_tree_use_case = GetCommentTreeUseCase::new(
+        state.post_comments_repository.clone(),
+        state.user_relation_repository.clone(),
+    );
+    let pagination = pagination::Pagination::from(PaginationParams {
+        offset: query.offset,
+        limit: query.limit,
+    });
+
+    let output = comment_tree_use_case
+        .execute(GetCommentTreeInput {
+            post_id: id,
+            pagination: (pagination.offset, pagination.limit),
+            max_depth: query
+                .depth
+                .unwrap_or(DEFAULT_COMMENT_DEPTH)
+                .min(MAX_COMMENT_DEPTH),
+            replies_limit: query
+                .replies_limit
+                .unwrap_or(DEFAULT_REPLIES_LIMIT)
+                .min(MAX_REPLIES_LIMIT),
+            viewer_id: user.as_ref().map(|user| user.id),
+            is_moderator: user
+                .as_ref()
+                .is_some_and(|user| user.role.has_higher_privilege_than(&UserType::Regular)),
+        })
+        .await?;
+
+    let mentions = GetPostMentionsUseCase::new(state.mention_repository.clone())
+        .execute(GetPostMentionsInput { post_id: id })
+        .await?
+        .mentions;
+
+    anyhow::Result::Ok(Json(GetCommentTreeResponse {
+        comments: output
+            .comments
+            .into_iter()
+            .map(|node| CommentNodeResponse::new(node, &mentions))
+            .collect(),
+        total: output.total,
+    }))
+}
+
+async fn get_comment_replies(
+    state: State<AppState>,
+    Path(ids): Path<(Uuid, Uuid)>,
+    OptionalAuthUser(user): OptionalAuthUser,
+    Query(query): Query<CommentTreeQuery>,
+) -> AppResult<Json<GetCommentTreeResponse>> {
+    let comment_replies_use_case = GetCommentRepliesUseCase::new(
+        state.post_comments_repository.clone(),
+        state.user_relation_repository.clone(),
+    );
+    let pagination = pagination::Pagination::from(PaginationParams {
+        offset: query.offset,
+        limit: query.limit,
+    });
+
+    let output = comment_replies_use_case
+        .execute(GetCommentRepliesInput {
+            post_id: ids.0,
+            comment_id: ids.1,
+            pagination: (pagination.offset, pagination.limit),
+            max_depth: query
+                .depth
+                .unwrap_or(DEFAULT_COMMENT_DEPTH)
+                .min(MAX_COMMENT_DEPTH),
+            replies_limit: query
+                .replies_limit
+                .unwrap_or(DEFAULT_REPLIES_LIMIT)
+                .min(MAX_REPLIES_LIMIT),
+            viewer_id: user.as_ref().map(|user| user.id),
+            is_moderator: user
+                .as_ref()
+                .is_some_and(|user| user.role.has_higher_privilege_than(&UserType::Regular)),
+        })
+        .await?;
+
+    let Some(output) = output else {
+        return Err(AppError::NotFound("Comment".into()));
+    };
+
+    let mentions = GetPostMentionsUseCase::new(state.mention_repository.clone())
+        .execute(GetPostMentionsInput { post_id: ids.0 })
+        .await?
+        .mentions;
+
+    anyhow::Result::Ok(Json(GetCommentTreeResponse {
+        comments: output
+            .replies
+            .into_iter()
+            .map(|node| CommentNodeResponse::new(node, &mentions))
+            .collect(),
+        total: output.total,
+    }))
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct EditPostCommentRequest {
+    content: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct EditPostCommentResponse {
+    id: Uuid,
+    content: String,
+    updated_at: Option<DateTime<Utc>>,
+    edited: bool,
+    /// The edited comment waits for a moderator before it is shown again
+    held: bool,
+}
+
+async fn edit_post_comment(
+    state: State<AppState>,
+    Path(ids): Path<(Uuid, Uuid)>,
+    user: AuthUser,
+    Json(payload): Json<EditPostCommentRequest>,
+) -> AppResult<Json<EditPostCommentResponse>> {
+    let edit_comment_use_case = EditCommentUseCase::new(
+        state.post_comments_repository.clone(),
+        state.content_filter_repository.clone(),
+        state.report_repository.clone(),
+    );
+
+    let result = edit_comment_use_case
+        .execute(EditCommentInput {
+            id: ids.1,
+            post_id: ids.0,
+            user_id: user.id,
+            content: payload.content,
+        })
+        .await?;
+
+    let Some(result) = result else {
+        return Err(AppError::NotFound("Comment".into()));
+    };
+
+    anyhow::Result::Ok(Json(EditPostCommentResponse {
+        id: result.comment.id.clone().into(),
+        edited: result.comment.is_edited(),
+        content: result.comment.content,
+        updated_at: result.comment.updated_at,
+        held: result.held,
+    }))
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct LikeCommentResponse {
+    success: bool,
+}
+
+async fn like_post_comment(
+    state: State<AppState>,
+    Path(ids): Path<(Uuid, Uuid)>,
+    user: AuthUser,
+) -> AppResult<Json<LikeCommentResponse>> {
+    let like_comment_use_case = LikeCommentUseCase::new(
+        state.post_comments_repository.clone(),
+        state.comment_likes_repository.clone(),
+        state.user_relation_repository.clone(),
+    );
+
+    let result = like_comment_use_case
+        .execute(LikeCommentInput {
+            comment_id: ids.1,
+            post_id: ids.0,
+            user_id: user.id,
+        })
+        .await?;
+
+    if result.is_none() {
+        return Err(AppError::NotFound("Comment".into()));
+    }
+
+    anyhow::Result::Ok(Json(LikeCommentResponse { success: true }))
+}
+
+async fn unlike_post_comment(
+    state: State<AppState>,
+    Path(ids): Path<(Uuid, Uuid)>,
+    user: AuthUser,
+) -> AppResult<Json<LikeCommentResponse>> {
+    let unlike_comment_use_case = UnlikeCommentUseCase::new(
+        state.post_comments_repository.clone(),
+        state.comment_likes_repository.clone(),
+    );
+
+    let result = unlike_comment_use_case
+        .execute(UnlikeCommentInput {
+            comment_id: ids.1,
+            post_id: ids.0,
+            user_id: user.id,
+        })
+        .await?;
+
+    if result.is_none() {
+        return Err(AppError::NotFound("Comment".into()));
+    }
+
+    anyhow::Result::Ok(Json(LikeCommentResponse { success: true }))
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct DeletePostCommentResponse {
     success: bool,
@@ -693,44 +1421,254 @@ async fn delete_post_comment(
     state: State<AppState>,
     Path(ids): Path<(Uuid, Uuid)>,
     user: AuthUser,
+    Query(audit): Query<AuditReasonQuery>,
 ) -> AppResult<Json<DeletePostCommentResponse>> {
-    let uncomment_use_case = UncommentPostUseCase::new(state.post_comments_repository.clone());
-    let post_use_case = GetPostUseCase::new(state.post_repository.clone());
-    let comment_use_case = GetCommentUseCase::new(state.post_comments_repository.clone());
+    let uncomment_use_case = UncommentPostUseCase::new(
+        state.post_comments_repository.clone(),
+        state.post_repository.clone(),
+    );
 
-    let post = post_use_case.execute(GetPostInput { id: ids.0 }).await?;
-    let comment = comment_use_case
-        .execute(GetCommentInput { id: ids.1 })
+    let result = uncomment_use_case
+        .execute(UncommentPostInput {
+            id: ids.1,
+            post_id: ids.0,
+            deleted_by: user.id,
+            is_moderator: user.role.has_higher_privilege_than(&UserType::Regular),
+            reason: audit.reason,
+        })
         .await?;
 
-    if post.is_none() {
-        return Err(AppError::NotFound("Post".into()));
-    }
-
-    if comment.is_none() {
+    let Some(output) = result else {
         return Err(AppError::NotFound("Comment".into()));
+    };
+
+    anyhow::Result::Ok(Json(DeletePostCommentResponse {
+        success: output.success,
+    }))
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct HideCommentResponse {
+    hidden: bool,
+}
+
+async fn set_comment_hidden(
+    state: &AppState,
+    ids: (Uuid, Uuid),
+    user: AuthUser,
+    hidden: bool,
+) -> AppResult<Json<HideCommentResponse>> {
+    let hide_comment_use_case = HideCommentUseCase::new(
+        state.post_comments_repository.clone(),
+        state.post_repository.clone(),
+    );
+
+    let result = hide_comment_use_case
+        .execute(HideCommentInput {
+            id: ids.1,
+            post_id: ids.0,
+            user_id: user.id,
+            hidden,
+        })
+        .await?;
+
+    match result {
+        Some(output) => anyhow::Result::Ok(Json(HideCommentResponse {
+            hidden: output.comment.is_hidden(),
+        })),
+        None => Err(AppError::NotFound("Comment".into())),
     }
+}
 
-    let unwraped_post = post.unwrap();
-    let unwraped_comment = comment.unwrap();
+async fn hide_post_comment(
+    state: State<AppState>,
+    Path(ids): Path<(Uuid, Uuid)>,
+    user: AuthUser,
+) -> AppResult<Json<HideCommentResponse>> {
+    set_comment_hidden(&state, ids, user, true).await
+}
 
-    if user.id != unwraped_post.post.author_id.id
-        && user.id != unwraped_comment.comment.user_id.id
-        && user.role != UserType::Administrator
-        && user.role != UserType::Moderator
-    {
-        return Err(AppError::Unauthorized("Unauthorized".into()));
+async fn unhide_post_comment(
+    state: State<AppState>,
+    Path(ids): Path<(Uuid, Uuid)>,
+    user: AuthUser,
+) -> AppResult<Json<HideCommentResponse>> {
+    set_comment_hidden(&state, ids, user, false).await
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RestoreResponse {
+    success: bool,
+}
+
+async fn restore_post(
+    state: State<AppState>,
+    Path(id): Path<Uuid>,
+    user: AuthUser,
+) -> AppResult<Json<RestoreResponse>> {
+    let restore_use_case = RestorePostUseCase::new(state.post_repository.clone());
+
+    let result = restore_use_case
+        .execute(RestorePostInput {
+            id,
+            user_id: user.id,
+            is_moderator: user.role.has_higher_privilege_than(&UserType::Regular),
+        })
+        .await?;
+
+    if result.is_none() {
+        return Err(AppError::NotFound("Post".into()));
     }
 
-    let result = uncomment_use_case
-        .execute(UncommentPostInput { id: ids.1 })
+    anyhow::Result::Ok(Json(RestoreResponse {
+        success: result.unwrap().success,
+    }))
+}
+
+async fn restore_post_comment(
+    state: State<AppState>,
+    Path(ids): Path<(Uuid, Uuid)>,
+    user: AuthUser,
+) -> AppResult<Json<RestoreResponse>> {
+    let restore_use_case = RestoreCommentUseCase::new(state.post_comments_repository.clone());
+
+    let result = restore_use_case
+        .execute(RestoreCommentInput {
+            id: ids.1,
+            post_id: ids.0,
+            user_id: user.id,
+            is_moderator: user.role.has_higher_privilege_than(&UserType::Regular),
+        })
         .await?;
 
     if result.is_none() {
         return Err(AppError::NotFound("Comment".into()));
     }
 
-    anyhow::Result::Ok(Json(DeletePostCommentResponse { success: true }))
+    anyhow::Result::Ok(Json(RestoreResponse {
+        success: result.unwrap().success,
+    }))
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct DeletedPostResponse {
+    id: Uuid,
+    title: String,
+    description: String,
+    author_id: Uuid,
+    content_url: String,
+    created_at: DateTime<Utc>,
+    deleted_at: Option<DateTime<Utc>>,
+    deleted_by: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct GetTrashResponse {
+    posts: Vec<DeletedPostResponse>,
+}
+
+async fn get_trash(
+    state: State<AppState>,
+    user: AuthUser,
+    Query(pagination): Query<PaginationParams>,
+) -> AppResult<Json<GetTrashResponse>> {
+    let get_deleted_posts_use_case = GetDeletedPostsUseCase::new(state.post_repository.clone());
+    let pagination = pagination::Pagination::from(pagination);
+
+    // Moderators see the whole trash, everyone else only their own posts
+    let author_id = if user.role.has_higher_privilege_than(&UserType::Regular) {
+        None
+    } else {
+        Some(user.id)
+    };
+
+    let output = get_deleted_posts_use_case
+        .execute(GetDeletedPostsInput {
+            author_id,
+            pagination: (pagination.offset, pagination.limit),
+        })
+        .await?;
+
+    anyhow::Result::Ok(Json(GetTrashResponse {
+        posts: output
+            .posts
+            .into_iter()
+            .map(|post| DeletedPostResponse {
+                id: post.id.into(),
+                title: post.title,
+                description: post.description,
+                author_id: post.author_id.into(),
+                content_url: post.content_url,
+                created_at: post.created_at,
+                deleted_at: post.deleted_at,
+                deleted_by: post.deleted_by.map(|id| id.into()),
+            })
+            .collect(),
+    }))
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct HeldCommentResponse {
+    id: Uuid,
+    post_id: Uuid,
+    user_id: Uuid,
+    content: String,
+    created_at: DateTime<Utc>,
+    deleted_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct GetReviewQueueResponse {
+    posts: Vec<DeletedPostResponse>,
+    comments: Vec<HeldCommentResponse>,
+}
+
+async fn get_review_queue(
+    state: State<AppState>,
+    user: AuthUser,
+    Query(pagination): Query<PaginationParams>,
+) -> AppResult<Json<GetReviewQueueResponse>> {
+    let get_held_content_use_case = GetHeldContentUseCase::new(
+        state.post_repository.clone(),
+        state.post_comments_repository.clone(),
+    );
+    let pagination = pagination::Pagination::from(pagination);
+
+    let output = get_held_content_use_case
+        .execute(GetHeldContentInput {
+            is_moderator: user.role.has_higher_privilege_than(&UserType::Regular),
+            pagination: (pagination.offset, pagination.limit),
+        })
+        .await?;
+
+    Ok(Json(GetReviewQueueResponse {
+        posts: output
+            .posts
+            .into_iter()
+            .map(|post| DeletedPostResponse {
+                id: post.id.into(),
+                title: post.title,
+                description: post.description,
+                author_id: post.author_id.into(),
+                content_url: post.content_url,
+                created_at: post.created_at,
+                deleted_at: post.deleted_at,
+                deleted_by: post.deleted_by.map(|id| id.into()),
+            })
+            .collect(),
+        comments: output
+            .comments
+            .into_iter()
+            .map(|comment| HeldCommentResponse {
+                id: comment.id.into(),
+                post_id: comment.post_id.into(),
+                user_id: comment.user_id.into(),
+                content: comment.content,
+                created_at: comment.created_at,
+                deleted_at: comment.deleted_at,
+            })
+            .collect(),
+    }))
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -761,41 +1699,117 @@ async fn delete_from_group(
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct UploadImageRequest {
     image: String,
+    #[serde(default)]
+    keep_metadata: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct UploadImageResponse {
     link: String,
+    variants: Vec<ImageVariant>,
+    captured_at: Option<NaiveDateTime>,
+    orientation: Option<u16>,
+    latitude: Option<f64>,
+    longitude: Option<f64>,
+    suggested_location: Option<SuggestedLocationResponse>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct SuggestedLocationResponse {
+    id: Uuid,
+    name: String,
+    latitude: f64,
+    longitude: f64,
+    picture_url: Option<String>,
 }
 
 async fn upload_image(
     state: State<AppState>,
     Json(payload): Json<UploadImageRequest>,
 ) -> AppResult<Json<UploadImageResponse>> {
-    let upload_image_use_case = UploadImageUseCase::new(state.cloudinary_repository.clone());
+    let upload_image_use_case = UploadImageUseCase::new(
+        state.media_storage.clone(),
+        state.location_repository.clone(),
+    );
 
     let input = UploadImageInput {
         image: payload.image,
+        keep_metadata: payload.keep_metadata,
+        limits: image_limits(),
     };
 
     let output = upload_image_use_case.execute(input).await?;
 
-    anyhow::Result::Ok(Json(UploadImageResponse { link: output.url }))
+    anyhow::Result::Ok(Json(UploadImageResponse {
+        link: output.url,
+        variants: output.variants,
+        captured_at: output.metadata.captured_at,
+        orientation: output.metadata.orientation,
+        latitude: output.metadata.location.map(|point| point.latitude),
+        longitude: output.metadata.location.map(|point| point.longitude),
+        suggested_location: output
+            .suggested_location
+            .map(|location| SuggestedLocationResponse {
+                id: location.id.into(),
+                name: location.name,
+                latitude: location.latitude,
+                longitude: location.longitude,
+                picture_url: location.picture_url,
+            }),
+    }))
 }
 
 pub fn post_routes() -> axum::Router<crate::AppState> {
     axum::Router::new()
         .route("/", post(create_post))
+        .route("/trash", get(get_trash))
+        .route("/review", get(get_review_queue))
+        .route("/reactions/kinds", get(get_reaction_kinds))
         .route("/:id", get(get_post))
         .route("/:id", delete(delete_post))
         .route("/:id", put(update_post))
         .route("/:id/comment", post(comment_post))
+        .route("/:id/comments", get(get_post_comment_tree))
+        .route("/:id/comment/:comment_id/replies", get(get_comment_replies))
         .route("/:id/comment/:comment_id", delete(delete_post_comment))
+        .route("/:id/comment/:comment_id", put(edit_post_comment))
+        .route("/:id/comment/:comment_id/like", post(like_post_comment))
+        .route("/:id/comment/:comment_id/like", delete(unlike_post_comment))
+        .route("/:id/comment/:comment_id/hide", post(hide_post_comment))
+        .route("/:id/comment/:comment_id/hide", delete(unhide_post_comment))
+        .route(
+            "/:id/comment/:comment_id/restore",
+            post(restore_post_comment),
+        )
+        .route("/:id/restore", post(restore_post))
         .route("/:id/like/check", get(check_like_get))
         .route("/:id/like", post(like_post))
         .route("/:id/like", delete(unlike_post))
+        .route("/:id/reaction", put(react_post))
+        .route("/:id/reaction", delete(unlike_post))
+        .route("/:id/reactions", get(get_post_reactions))
+        .route("/:id/reactions/:kind", get(get_reaction_users))
+        .route("/:id/save", post(save_post))
+        .route("/:id/save", delete(unsave_post))
         .route("/:id/group/:group_id", delete(delete_from_group))
         .route("/upload_image", post(upload_image))
-        // Limit the size of the request body to 10mb
-        .layer(DefaultBodyLimit::max(1024 * 1024 * 10))
+        // Limit the size of the request body to the base64 of the largest allowed image
+        .layer(DefaultBodyLimit::max(
+            (image_limits().max_bytes / 3 + 1) * 4 + 1024 * 1024,
+        ))
+}
+
+fn image_limits() -> ImageLimits {
+    let defaults = ImageLimits::default();
+
+    ImageLimits {
+        max_bytes: std::env::var("IMAGE_MAX_BYTES")
+            .ok()
+            .and_then(|bytes| bytes.parse().ok())
+            .unwrap_or(defaults.max_bytes),
+        max_pixels: std::env::var("IMAGE_MAX_PIXELS")
+            .ok()
+            .and_then(|pixels| pixels.parse().ok())
+            .unwrap_or(defaults.max_pixels),
+    }
 }
@@ -4,20 +4,39 @@ use axum::{
     response::IntoResponse,
     routing::{delete, get, post},
 };
-use models::errors::AppResult;
+use chrono::{DateTime, Utc};
+use models::{
+    domain::Id,
+    errors::{AppError, AppResult},
+};
 use serde::{Deserialize, Serialize};
 
 use usecase::post_tag::{
     create_post_tag::{CreatePostTagInput, CreatePostTagUseCase},
     delete_tag::{DeletePostTagInput, DeletePostTagUseCase},
+    follow_tag::{FollowTagInput, FollowTagUseCase},
+    get_followed_tags::{GetFollowedTagsInput, GetFollowedTagsUseCase},
+    get_tag_detail::{GetTagDetailInput, GetTagDetailUseCase},
+    get_trending_tags::{GetTrendingTagsInput, GetTrendingTagsUseCase},
     search_tag::{SearchPostTagInput, SearchPostTagUseCase},
+    unfollow_tag::{UnfollowTagInput, UnfollowTagUseCase},
 };
 use uuid::Uuid;
 
 use crate::{
-    extractors::{auth_extractor::AuthUser, json_extractor::Json},
+    cache,
+    extractors::{
+        auth_extractor::{AuthUser, OptionalAuthUser},
+        json_extractor::Json,
+    },
     AppState,
 };
+
+const DEFAULT_TRENDING_WINDOW_HOURS: i64 = 24;
+const MAX_TRENDING_WINDOW_HOURS: i64 = 24 * 30;
+const DEFAULT_TRENDING_LIMIT: i64 = 10;
+const MAX_TRENDING_LIMIT: i64 = 50;
+const DEFAULT_TRENDING_CACHE_SECONDS: u64 = 60;
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct CreatePostTagResponse {
     id: String,
@@ -114,9 +133,233 @@ async fn search_tag(
     Ok(Json(SearchPostTagResponse { tags }))
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+struct GetTrendingTagsRequest {
+    window_hours: Option<i64>,
+    limit: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct GetTrendingTagsResponse {
+    tags: Vec<TrendingTagResponse>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TrendingTagResponse {
+    tag: String,
+    post_count: i64,
+    like_count: i64,
+    comment_count: i64,
+    score: f64,
+}
+
+async fn get_trending_tags(
+    state: State<AppState>,
+    OptionalAuthUser(user): OptionalAuthUser,
+    Query(params): Query<GetTrendingTagsRequest>,
+) -> AppResult<Json<GetTrendingTagsResponse>> {
+    let window_hours = params
+        .window_hours
+        .unwrap_or(DEFAULT_TRENDING_WINDOW_HOURS)
+        .clamp(1, MAX_TRENDING_WINDOW_HOURS);
+    let limit = params
+        .limit
+        .unwrap_or(DEFAULT_TRENDING_LIMIT)
+        .clamp(1, MAX_TRENDING_LIMIT);
+
+    // Visibility differs between users, so every user gets their own cache entry
+    let cache_key = format!(
+        "trending_tags:{}:{}:{}",
+        user.as_ref()
+            .map(|user| user.id.to_string())
+            .unwrap_or_else(|| "anonymous".into()),
+        window_hours,
+        limit
+    );
+
+    // The cache is only an optimization, when Redis is unavailable the tags are computed
+    if let Ok(Some(cached)) = cache::get_cached(&state.redis_client, &cache_key) {
+        return Ok(Json(cached));
+    }
+
+    let get_trending_tags_use_case = GetTrendingTagsUseCase::new(state.post_tag_repository.clone());
+
+    let output = get_trending_tags_use_case
+        .execute(GetTrendingTagsInput {
+            user_id: user.map(|user| Id::new(user.id)),
+            window: chrono::Duration::hours(window_hours),
+            limit,
+        })
+        .await?;
+
+    let response = GetTrendingTagsResponse {
+        tags: output
+            .tags
+            .into_iter()
+            .map(|tag| TrendingTagResponse {
+                tag: tag.tag,
+                post_count: tag.post_count,
+                like_count: tag.like_count,
+                comment_count: tag.comment_count,
+                score: tag.score,
+            })
+            .collect(),
+    };
+
+    let ttl = std::env::var("TRENDING_TAGS_CACHE_SECONDS")
+        .ok()
+        .and_then(|seconds| seconds.parse::<u64>().ok())
+        .unwrap_or(DEFAULT_TRENDING_CACHE_SECONDS);
+
+    if let Err(err) = cache::set_cached(&state.redis_client, &cache_key, &response, ttl) {
+        println!("Failed to cache trending tags: {:?}", err);
+    }
+
+    Ok(Json(response))
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct GetTagDetailResponse {
+    tag: String,
+    post_count: i64,
+    first_used_at: DateTime<Utc>,
+    related_tags: Vec<RelatedTagResponse>,
+    is_following: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RelatedTagResponse {
+    tag: String,
+    post_count: i64,
+}
+
+async fn get_tag_detail(
+    state: State<AppState>,
+    OptionalAuthUser(user): OptionalAuthUser,
+    Path(tag): Path<String>,
+) -> AppResult<Json<GetTagDetailResponse>> {
+    let get_tag_detail_use_case = GetTagDetailUseCase::new(
+        state.post_tag_repository.clone(),
+        state.tag_follow_repository.clone(),
+    );
+
+    let output = get_tag_detail_use_case
+        .execute(GetTagDetailInput {
+            tag,
+            user_id: user.map(|user| Id::new(user.id)),
+        })
+        .await?
+        .ok_or(AppError::NotFound("Tag".into()))?;
+
+    Ok(Json(GetTagDetailResponse {
+        tag: output.detail.tag,
+        post_count: output.detail.post_count,
+        first_used_at: output.detail.first_used_at,
+        related_tags: output
+            .detail
+            .related_tags
+            .into_iter()
+            .map(|related| RelatedTagResponse {
+                tag: related.tag,
+                post_count: related.post_count,
+            })
+            .collect(),
+        is_following: output.is_following,
+    }))
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct FollowTagResponse {
+    tag: String,
+    following: bool,
+}
+
+async fn follow_tag(
+    state: State<AppState>,
+    user: AuthUser,
+    Path(tag): Path<String>,
+) -> AppResult<Json<FollowTagResponse>> {
+    let follow_tag_use_case = FollowTagUseCase::new(state.tag_follow_repository.clone());
+
+    let output = follow_tag_use_case
+        .execute(FollowTagInput {
+            tag,
+            user_id: user.id,
+        })
+        .await?;
+
+    Ok(Json(FollowTagResponse {
+        tag: output.tag,
+        following: true,
+    }))
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct UnfollowTagResponse {
+    success: bool,
+}
+
+async fn unfollow_tag(
+    state: State<AppState>,
+    user: AuthUser,
+    Path(tag): Path<String>,
+) -> AppResult<Json<UnfollowTagResponse>> {
+    let unfollow_tag_use_case = UnfollowTagUseCase::new(state.tag_follow_repository.clone());
+
+    let output = unfollow_tag_use_case
+        .execute(UnfollowTagInput {
+            tag,
+            user_id: user.id,
+        })
+        .await?;
+
+    Ok(Json(UnfollowTagResponse {
+        success: output.success,
+    }))
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct GetFollowedTagsResponse {
+    tags: Vec<FollowedTagResponse>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct FollowedTagResponse {
+    tag: String,
+    followed_at: DateTime<Utc>,
+}
+
+async fn get_followed_tags(
+    state: State<AppState>,
+    user: AuthUser,
+) -> AppResult<Json<GetFollowedTagsResponse>> {
+    let get_followed_tags_use_case =
+        GetFollowedTagsUseCase::new(state.tag_follow_repository.clone());
+
+    let output = get_followed_tags_use_case
+        .execute(GetFollowedTagsInput { user_id: user.id })
+        .await?;
+
+    Ok(Json(GetFollowedTagsResponse {
+        tags: output
+            .tags
+            .into_iter()
+            .map(|follow| FollowedTagResponse {
+                tag: follow.tag,
+                followed_at: follow.created_at,
+            })
+            .collect(),
+    }))
+}
+
 pub fn post_tag_routes() -> axum::Router<crate::AppState> {
     axum::Router::new()
         .route("/", post(create_tag))
         .route("/", delete(delete_tag))
         .route("/search", get(search_tag))
+        .route("/trending", get(get_trending_tags))
+        .route("/followed", get(get_followed_tags))
+        .route("/:tag", get(get_tag_detail))
+        .route("/:tag/follow", post(follow_tag))
+        .route("/:tag/follow", delete(unfollow_tag))
 }
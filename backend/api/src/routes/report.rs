@@ -0,0 +1,225 @@
+use axum::{
+    extract::{Path, Query, State},
+    routing::{get, post},
+};
+use chrono::{DateTime, Utc};
+use models::{
+    domain::{
+        report::{Report, ReportOutcome, ReportReason, ReportStatus, ReportTargetType},
+        user::UserType,
+    },
+    errors::{AppError, AppResult},
+};
+use serde::{Deserialize, Serialize};
+use usecase::report::{
+    claim_report::{ClaimReportInput, ClaimReportUseCase},
+    create_report::{CreateReportInput, CreateReportUseCase},
+    dismiss_report::{DismissReportInput, DismissReportUseCase},
+    get_report_queue::{GetReportQueueInput, GetReportQueueUseCase},
+    resolve_report::{ReportAction, ResolveReportInput, ResolveReportUseCase},
+};
+use uuid::Uuid;
+
+use crate::{
+    extractors::{auth_extractor::AuthUser, json_extractor::Json},
+    pagination::{self, PaginationParams},
+    AppState,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ReportResponse {
+    id: Uuid,
+    reporter_id: Option<Uuid>,
+    target_type: ReportTargetType,
+    target_id: Uuid,
+    reason: ReportReason,
+    details: String,
+    status: ReportStatus,
+    moderator_id: Option<Uuid>,
+    outcome: Option<ReportOutcome>,
+    resolution_note: Option<String>,
+    created_at: DateTime<Utc>,
+    claimed_at: Option<DateTime<Utc>>,
+    closed_at: Option<DateTime<Utc>>,
+}
+
+impl From<Report> for ReportResponse {
+    fn from(report: Report) -> Self {
+        Self {
+            id: report.id.into(),
+            reporter_id: report.reporter_id.map(|id| id.into()),
+            target_type: report.target_type,
+            target_id: report.target_id,
+            reason: report.reason,
+            details: report.details,
+            status: report.status,
+            moderator_id: report.moderator_id.map(|id| id.into()),
+            outcome: report.outcome,
+            resolution_note: report.resolution_note,
+            created_at: report.created_at,
+            claimed_at: report.claimed_at,
+            closed_at: report.closed_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CreateReportRequest {
+    target_type: ReportTargetType,
+    target_id: Uuid,
+    reason: ReportReason,
+    #[serde(default)]
+    details: String,
+}
+
+async fn create_report(
+    state: State<AppState>,
+    user: AuthUser,
+    Json(payload): Json<CreateReportRequest>,
+) -> AppResult<Json<ReportResponse>> {
+    let create_report_use_case = CreateReportUseCase::new(
+        state.report_repository.clone(),
+        state.post_comments_repository.clone(),
+        state.user_repository.clone(),
+        state.group_repository.clone(),
+        state.wall_repository.clone(),
+    );
+
+    let output = create_report_use_case
+        .execute(CreateReportInput {
+            reporter_id: user.id,
+            target_type: payload.target_type,
+            target_id: payload.target_id,
+            reason: payload.reason,
+            details: payload.details,
+        })
+        .await?;
+
+    Ok(Json(output.report.into()))
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct ReportQueueQuery {
+    status: Option<ReportStatus>,
+    target_type: Option<ReportTargetType>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ReportQueueResponse {
+    reports: Vec<ReportResponse>,
+}
+
+async fn get_report_queue(
+    state: State<AppState>,
+    user: AuthUser,
+    Query(query): Query<ReportQueueQuery>,
+    Query(pagination): Query<PaginationParams>,
+) -> AppResult<Json<ReportQueueResponse>> {
+    let get_report_queue_use_case = GetReportQueueUseCase::new(state.report_repository.clone());
+    let pagination = pagination::Pagination::from(pagination);
+
+    let output = get_report_queue_use_case
+        .execute(GetReportQueueInput {
+            status: query.status,
+            target_type: query.target_type,
+            is_moderator: user.role.has_higher_privilege_than(&UserType::Regular),
+            pagination: (pagination.offset, pagination.limit),
+        })
+        .await?;
+
+    Ok(Json(ReportQueueResponse {
+        reports: output
+            .reports
+            .into_iter()
+            .map(ReportResponse::from)
+            .collect(),
+    }))
+}
+
+async fn claim_report(
+    state: State<AppState>,
+    user: AuthUser,
+    Path(id): Path<Uuid>,
+) -> AppResult<Json<ReportResponse>> {
+    let claim_report_use_case = ClaimReportUseCase::new(state.report_repository.clone());
+
+    let output = claim_report_use_case
+        .execute(ClaimReportInput {
+            report_id: id,
+            moderator_id: user.id,
+            is_moderator: user.role.has_higher_privilege_than(&UserType::Regular),
+        })
+        .await?
+        .ok_or(AppError::NotFound("Report".into()))?;
+
+    Ok(Json(output.report.into()))
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct ResolveReportRequest {
+    #[serde(default)]
+    action: ReportAction,
+    note: Option<String>,
+}
+
+async fn resolve_report(
+    state: State<AppState>,
+    user: AuthUser,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<ResolveReportRequest>,
+) -> AppResult<Json<ReportResponse>> {
+    let resolve_report_use_case = ResolveReportUseCase::new(
+        state.report_repository.clone(),
+        state.post_repository.clone(),
+        state.post_comments_repository.clone(),
+        state.user_repository.clone(),
+    );
+
+    let output = resolve_report_use_case
+        .execute(ResolveReportInput {
+            report_id: id,
+            moderator_id: user.id,
+            moderator_role: user.role,
+            action: payload.action,
+            note: payload.note,
+        })
+        .await?
+        .ok_or(AppError::NotFound("Report".into()))?;
+
+    Ok(Json(output.report.into()))
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct DismissReportRequest {
+    note: Option<String>,
+}
+
+async fn dismiss_report(
+    state: State<AppState>,
+    user: AuthUser,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<DismissReportRequest>,
+) -> AppResult<Json<ReportResponse>> {
+    let dismiss_report_use_case = DismissReportUseCase::new(state.report_repository.clone());
+
+    let output = dismiss_report_use_case
+        .execute(DismissReportInput {
+            report_id: id,
+            moderator_id: user.id,
+            is_moderator: user.role.has_higher_privilege_than(&UserType::Regular),
+            note: payload.note,
+        })
+        .await?
+        .ok_or(AppError::NotFound("Report".into()))?;
+
+    Ok(Json(output.report.into()))
+}
+
+pub fn report_routes() -> axum::Router<crate::AppState> {
+    axum::Router::new()
+        .route("/", post(create_report))
+        .route("/queue", get(get_report_queue))
+        .route("/:id/claim", post(claim_report))
+        .route("/:id/resolve", post(resolve_report))
+        .route("/:id/dismiss", post(dismiss_report))
+}
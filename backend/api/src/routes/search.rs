@@ -99,6 +99,7 @@ async fn search(
                 user_type: user.user_type.to_string(),
                 wall_id: user.wall_id.into(),
                 is_blocked: user.is_blocked,
+                is_limited: None,
             })
             .collect(),
         groups: groups
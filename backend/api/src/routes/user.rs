@@ -7,27 +7,59 @@ use axum_extra::extract::{
     cookie::{Cookie, Expiration},
     CookieJar,
 };
+use chrono::{DateTime, Utc};
 use models::{
-    domain::user::UserType,
+    domain::{
+        notification::NotificationKind, user::UserType, user_relation::UserRelationKind,
+        user_suspension::UserSuspension,
+    },
     errors::{AppError, AppResult},
 };
-use repository::user_repository::UserRepository;
 use serde::{Deserialize, Serialize};
 use time::OffsetDateTime;
-use usecase::user::{
-    block_user::{BlockUserInput, BlockUserUseCase},
-    get_all_users::{GetAllUsersInput, GetAllUsersUseCase},
-    get_user::{GetUserInput, GetUserUseCase},
-    get_user_by_username::{GetUserByUsernameInput, GetUserByUsernameUseCase},
-    register_user::{RegisterUserInput, RegisterUserUseCase},
-    unblock_user::{UnblockUserInput, UnblockUserUseCase},
-    update_user::{UpdateUserInput, UpdateUserUseCase},
+use usecase::{
+    notification::{
+        get_notifications::{GetNotificationsInput, GetNotificationsUseCase},
+        mark_notifications_read::{MarkNotificationsReadInput, MarkNotificationsReadUseCase},
+    },
+    saved::{
+        create_collection::{CreateCollectionInput, CreateCollectionUseCase},
+        delete_collection::{DeleteCollectionInput, DeleteCollectionUseCase},
+        get_collections::{GetCollectionsInput, GetCollectionsUseCase},
+        get_saved_posts::{GetSavedPostsInput, GetSavedPostsUseCase},
+        rename_collection::{RenameCollectionInput, RenameCollectionUseCase},
+    },
+    user::{
+        block_user::{BlockUserInput, BlockUserUseCase},
+        delete_user::{DeleteUserInput, DeleteUserUseCase},
+        get_all_users::{GetAllUsersInput, GetAllUsersUseCase},
+        get_user::{GetUserInput, GetUserUseCase},
+        get_user_by_username::{GetUserByUsernameInput, GetUserByUsernameUseCase},
+        register_user::{RegisterUserInput, RegisterUserUseCase},
+        request_account_deletion::{RequestAccountDeletionInput, RequestAccountDeletionUseCase},
+        set_user_limited::{SetUserLimitedInput, SetUserLimitedUseCase},
+        unblock_user::{UnblockUserInput, UnblockUserUseCase},
+        update_user::{UpdateUserInput, UpdateUserUseCase},
+    },
+    user_relation::{
+        add_user_relation::{AddUserRelationInput, AddUserRelationUseCase},
+        get_user_relations::{GetUserRelationsInput, GetUserRelationsUseCase},
+        remove_user_relation::{RemoveUserRelationInput, RemoveUserRelationUseCase},
+    },
 };
 use uuid::Uuid;
 
 use crate::{
     auth::{cookie::create_cookie, jwt::blacklist_token},
-    extractors::{auth_extractor::AuthUser, json_extractor::Json},
+    extractors::{
+        auth_extractor::{AuthUser, OptionalAuthUser},
+        json_extractor::Json,
+    },
+    pagination::{self, PaginationParams},
+    routes::{
+        admin::AuditReasonQuery,
+        wall::{GetWallResponse, PostItem},
+    },
     AppState,
 };
 
@@ -55,8 +87,11 @@ async fn create_user(
         return Err(AppError::Unauthorized("You can't create a user".into()));
     }
 
-    let user_usercase =
-        RegisterUserUseCase::new(state.user_repository.clone(), state.wall_repository.clone());
+    let user_usercase = RegisterUserUseCase::new(
+        state.user_repository.clone(),
+        state.wall_repository.clone(),
+        state.content_filter_repository.clone(),
+    );
 
     let input = RegisterUserInput {
         username: payload.username,
@@ -82,6 +117,9 @@ pub struct GetUserResponse {
     pub user_type: String,
     pub wall_id: Uuid,
     pub is_blocked: bool,
+    /// Only shown to moderators, limited users should not notice it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_limited: Option<bool>,
 }
 
 async fn get_user_by_id(
@@ -102,6 +140,7 @@ async fn get_user_by_id(
             user_type: user.user_type.to_string(),
             wall_id: user.wall_id.id,
             is_blocked: user.is_blocked,
+            is_limited: None,
         }))
     } else {
         Err(AppError::NotFound("User".into()))
@@ -128,6 +167,7 @@ async fn get_user_by_username(
             user_type: user.user_type.to_string(),
             wall_id: user.wall_id.id,
             is_blocked: user.is_blocked,
+            is_limited: None,
         }))
     } else {
         Err(AppError::NotFound("User".into()))
@@ -149,17 +189,80 @@ async fn me(state: State<AppState>, user: AuthUser) -> AppResult<Json<GetUserRes
             user_type: user.user_type.to_string(),
             wall_id: user.wall_id.id,
             is_blocked: user.is_blocked,
+            is_limited: None,
         }))
     } else {
         Err(AppError::NotFound("User".into()))
     }
 }
 
+#[derive(Debug, Clone, Serialize)]
+struct DeleteMeResponse {
+    deletion_requested_at: Option<DateTime<Utc>>,
+}
+
+/// Deactivates the account right away, it's purged once the grace period is over
+/// unless the user signs in again before that.
+async fn delete_me(
+    state: State<AppState>,
+    jar: CookieJar,
+    actor: AuthUser,
+) -> AppResult<(CookieJar, Json<DeleteMeResponse>)> {
+    let user = RequestAccountDeletionUseCase::new(state.user_repository.clone())
+        .execute(RequestAccountDeletionInput { user_id: actor.id })
+        .await?;
+
+    if let Some(jwt) = jar.get("jwt") {
+        blacklist_token(&state.redis_client, jwt.value(), actor.exp)
+            .map_err(|e| AppError::Anyhow(anyhow!(e)))?;
+    }
+
+    Ok((
+        jar.remove(Cookie::from("jwt")),
+        Json(DeleteMeResponse {
+            deletion_requested_at: user.deletion_requested_at,
+        }),
+    ))
+}
+
+pub(crate) const DEFAULT_SUSPENSION_REASON: &str = "Blocked by a moderator";
+
+#[derive(Debug, Clone, Deserialize)]
+struct BlockUserQuery {
+    reason: Option<String>,
+    /// Without an end the user stays blocked until unblocked by a moderator
+    until: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct UserSuspensionResponse {
+    id: Uuid,
+    user_id: Uuid,
+    moderator_id: Option<Uuid>,
+    reason: String,
+    starts_at: DateTime<Utc>,
+    ends_at: Option<DateTime<Utc>>,
+}
+
+impl From<UserSuspension> for UserSuspensionResponse {
+    fn from(suspension: UserSuspension) -> Self {
+        Self {
+            id: suspension.id.into(),
+            user_id: suspension.user_id.into(),
+            moderator_id: suspension.moderator_id.map(|id| id.into()),
+            reason: suspension.reason,
+            starts_at: suspension.starts_at,
+            ends_at: suspension.ends_at,
+        }
+    }
+}
+
 async fn block_user(
     state: State<AppState>,
     actor: AuthUser,
     Path(id): Path<Uuid>,
-) -> AppResult<()> {
+    Query(query): Query<BlockUserQuery>,
+) -> AppResult<Json<UserSuspensionResponse>> {
     let get_user_usercase = GetUserUseCase::new(state.user_repository.clone());
     let block_user_usecase = BlockUserUseCase::new(state.user_repository.clone());
 
@@ -175,7 +278,7 @@ async fn block_user(
         return Err(AppError::BadRequest("User is already blocked".into()));
     }
 
-    let self_block = actor.id == user.id.into();
+    let self_block = actor.id == user.id.clone().into();
     if actor.role.is_regular()
         || actor.role.has_lower_or_same_privilege_as(&user.user_type)
         || self_block
@@ -185,11 +288,19 @@ async fn block_user(
         ));
     }
 
-    block_user_usecase
-        .execute(BlockUserInput { user_id: id })
+    let output = block_user_usecase
+        .execute(BlockUserInput {
+            user_id: id,
+            moderator_id: actor.id,
+            reason: query
+                .reason
+                .filter(|reason| !reason.trim().is_empty())
+                .unwrap_or_else(|| DEFAULT_SUSPENSION_REASON.into()),
+            ends_at: query.until,
+        })
         .await?;
 
-    Ok(())
+    Ok(Json(output.suspension.into()))
 }
 
 async fn unblock_user(
@@ -197,6 +308,7 @@ async fn unblock_user(
     mut jar: CookieJar,
     actor: AuthUser,
     Path(id): Path<Uuid>,
+    Query(audit): Query<AuditReasonQuery>,
 ) -> AppResult<()> {
     let get_user_usercase = GetUserUseCase::new(state.user_repository.clone());
     let block_user_usecase = UnblockUserUseCase::new(state.user_repository.clone());
@@ -213,7 +325,7 @@ async fn unblock_user(
         return Err(AppError::BadRequest("User is not blocked".into()));
     }
 
-    let self_block = actor.id == user.id.into();
+    let self_block = actor.id == user.id.clone().into();
     if actor.role.is_regular()
         || actor.role.has_lower_or_same_privilege_as(&user.user_type)
         || self_block
@@ -224,36 +336,100 @@ async fn unblock_user(
     }
 
     block_user_usecase
-        .execute(UnblockUserInput { user_id: id })
+        .execute(UnblockUserInput {
+            user_id: id,
+            lifted_by: actor.id,
+            reason: audit.reason,
+        })
         .await?;
 
     Ok(())
 }
 
-async fn delete_user(
+async fn limit_user(
     state: State<AppState>,
     actor: AuthUser,
     Path(id): Path<Uuid>,
+    Query(audit): Query<AuditReasonQuery>,
 ) -> AppResult<()> {
-    if actor.role != models::domain::user::UserType::Administrator {
-        return Err(AppError::Unauthorized("You can't delete this user".into()));
-    }
+    set_user_limited(state, actor, id, true, audit.reason).await
+}
+
+async fn unlimit_user(
+    state: State<AppState>,
+    actor: AuthUser,
+    Path(id): Path<Uuid>,
+    Query(audit): Query<AuditReasonQuery>,
+) -> AppResult<()> {
+    set_user_limited(state, actor, id, false, audit.reason).await
+}
 
+async fn set_user_limited(
+    state: State<AppState>,
+    actor: AuthUser,
+    id: Uuid,
+    is_limited: bool,
+    reason: Option<String>,
+) -> AppResult<()> {
     let get_user_usercase = GetUserUseCase::new(state.user_repository.clone());
+    let set_user_limited_usecase = SetUserLimitedUseCase::new(state.user_repository.clone());
 
-    let user = get_user_usercase.execute(GetUserInput { id }).await?;
+    let user = get_user_usercase
+        .execute(GetUserInput { id })
+        .await?
+        .ok_or_else(|| AppError::NotFound("User".into()))?;
+
+    if user.is_limited == is_limited {
+        return Err(AppError::BadRequest(if is_limited {
+            "User is already limited".into()
+        } else {
+            "User is not limited".into()
+        }));
+    }
 
-    if user.is_none() {
-        return Err(AppError::NotFound("User".into()));
+    let self_limit = actor.id == user.id.id;
+    if actor.role.is_regular()
+        || actor.role.has_lower_or_same_privilege_as(&user.user_type)
+        || self_limit
+    {
+        return Err(AppError::Unauthorized(
+            "You do not have sufficient privileges to limit this user.".into(),
+        ));
     }
 
-    let user = user.unwrap();
+    set_user_limited_usecase
+        .execute(SetUserLimitedInput {
+            user_id: id,
+            is_limited,
+            actor_id: actor.id,
+            reason,
+        })
+        .await?;
+
+    Ok(())
+}
+
+async fn delete_user(
+    state: State<AppState>,
+    actor: AuthUser,
+    Path(id): Path<Uuid>,
+    Query(audit): Query<AuditReasonQuery>,
+) -> AppResult<()> {
+    if actor.role != models::domain::user::UserType::Administrator {
+        return Err(AppError::Unauthorized("You can't delete this user".into()));
+    }
 
-    if actor.id == user.id.clone().into() {
+    if actor.id == id {
         return Err(AppError::Unauthorized("You can't delete yourself".into()));
     }
 
-    state.user_repository.delete(user.id).await?;
+    DeleteUserUseCase::new(state.user_repository.clone())
+        .execute(DeleteUserInput {
+            id,
+            actor_id: actor.id,
+            reason: audit.reason,
+        })
+        .await?;
 
     Ok(())
 }
@@ -267,6 +443,9 @@ struct UpdateUserRequest {
     avatar_url: Option<String>,
     password: Option<String>,
     user_type: UserType,
+    /// Recorded in the audit log when the user type changes
+    #[serde(default)]
+    reason: Option<String>,
 }
 
 async fn update_user(
@@ -306,6 +485,8 @@ async fn update_user(
             password: payload.password,
             description: payload.description,
             user: user.clone(),
+            actor_id: actor.id,
+            reason: payload.reason,
         })
         .await?;
 
@@ -339,19 +520,26 @@ async fn update_user(
 pub struct GetAllUsersRequest {
     role: Option<UserType>,
     is_blocked: Option<bool>,
+    is_limited: Option<bool>,
     username: Option<String>,
 }
 
 async fn get_all_users(
     state: State<AppState>,
+    actor: OptionalAuthUser,
     Query(filters): Query<GetAllUsersRequest>,
 ) -> AppResult<Json<Vec<GetUserResponse>>> {
     let user_usercase = GetAllUsersUseCase::new(state.user_repository.clone());
+    let is_moderator = actor
+        .0
+        .as_ref()
+        .is_some_and(|actor| actor.role.has_higher_privilege_than(&UserType::Regular));
 
     let users = user_usercase
         .execute(GetAllUsersInput {
             filter_role: filters.role,
             filter_is_blocked: filters.is_blocked,
+            filter_is_limited: filters.is_limited.filter(|_| is_moderator),
             filter_username: filters.username,
         })
         .await?;
@@ -366,6 +554,7 @@ async fn get_all_users(
             avatar_url: user.avatar_url,
             user_type: user.user_type.to_string(),
             is_blocked: user.is_blocked,
+            is_limited: is_moderator.then_some(user.is_limited),
             wall_id: user.wall_id.id,
         })
         .collect();
@@ -373,15 +562,443 @@ async fn get_all_users(
     anyhow::Result::Ok(Json(users))
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SavedPostsQuery {
+    collection_id: Option<Uuid>,
+}
+
+async fn get_saved_posts(
+    state: State<AppState>,
+    user: AuthUser,
+    Query(pagination): Query<PaginationParams>,
+    Query(query): Query<SavedPostsQuery>,
+) -> AppResult<Json<GetWallResponse>> {
+    let get_saved_posts_use_case = GetSavedPostsUseCase::new(
+        state.wall_repository.clone(),
+        state.saved_posts_repository.clone(),
+    );
+    let pagination = pagination::Pagination::from(pagination);
+
+    let output = get_saved_posts_use_case
+        .execute(GetSavedPostsInput {
+            user_id: user.id.into(),
+            collection_id: query.collection_id.map(|id| id.into()),
+            pagination: (pagination.offset, pagination.limit),
+        })
+        .await?;
+
+    anyhow::Result::Ok(Json(GetWallResponse {
+        posts: output.into_iter().map(PostItem::from).collect(),
+    }))
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CollectionResponse {
+    id: Uuid,
+    name: String,
+    post_count: i64,
+    created_at: DateTime<Utc>,
+}
+
+async fn get_collections(
+    state: State<AppState>,
+    user: AuthUser,
+) -> AppResult<Json<Vec<CollectionResponse>>> {
+    let get_collections_use_case = GetCollectionsUseCase::new(state.saved_posts_repository.clone());
+
+    let output = get_collections_use_case
+        .execute(GetCollectionsInput { user_id: user.id })
+        .await?;
+
+    anyhow::Result::Ok(Json(
+        output
+            .collections
+            .into_iter()
+            .map(|(collection, post_count)| CollectionResponse {
+                id: collection.id.into(),
+                name: collection.name,
+                post_count,
+                created_at: collection.created_at,
+            })
+            .collect(),
+    ))
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CollectionRequest {
+    name: String,
+}
+
+async fn create_collection(
+    state: State<AppState>,
+    user: AuthUser,
+    Json(payload): Json<CollectionRequest>,
+) -> AppResult<Json<CollectionResponse>> {
+    let create_collection_use_case =
+        CreateCollectionUseCase::new(state.saved_posts_repository.clone());
+
+    let output = create_collection_use_case
+        .execute(CreateCollectionInput {
+            user_id: user.id,
+            name: payload.name,
+        })
+        .await?;
+
+    anyhow::Result::Ok(Json(CollectionResponse {
+        id: output.collection.id.into(),
+        name: output.collection.name,
+        post_count: 0,
+        created_at: output.collection.created_at,
+    }))
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RenameCollectionResponse {
+    id: Uuid,
+    name: String,
+}
+
+async fn rename_collection(
+    state: State<AppState>,
+    user: AuthUser,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<CollectionRequest>,
+) -> AppResult<Json<RenameCollectionResponse>> {
+    let rename_collection_use_case =
+        RenameCollectionUseCase::new(state.saved_posts_repository.clone());
+
+    let output = rename_collection_use_case
+        .execute(RenameCollectionInput {
+            id,
+            user_id: user.id,
+            name: payload.name,
+        })
+        .await?;
+
+    match output {
+        Some(output) => anyhow::Result::Ok(Json(RenameCollectionResponse {
+            id: output.collection.id.into(),
+            name: output.collection.name,
+        })),
+        None => Err(AppError::NotFound("Collection".into())),
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct DeleteCollectionResponse {
+    success: bool,
+}
+
+async fn delete_collection(
+    state: State<AppState>,
+    user: AuthUser,
+    Path(id): Path<Uuid>,
+) -> AppResult<Json<DeleteCollectionResponse>> {
+    let delete_collection_use_case =
+        DeleteCollectionUseCase::new(state.saved_posts_repository.clone());
+
+    let output = delete_collection_use_case
+        .execute(DeleteCollectionInput {
+            id,
+            user_id: user.id,
+        })
+        .await?;
+
+    match output {
+        Some(_) => anyhow::Result::Ok(Json(DeleteCollectionResponse { success: true })),
+        None => Err(AppError::NotFound("Collection".into())),
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NotificationsQuery {
+    unread: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct NotificationActorResponse {
+    id: Uuid,
+    username: String,
+    avatar_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct NotificationResponse {
+    id: Uuid,
+    kind: NotificationKind,
+    actor: NotificationActorResponse,
+    post_id: Option<Uuid>,
+    comment_id: Option<Uuid>,
+    created_at: DateTime<Utc>,
+    read: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct GetNotificationsResponse {
+    notifications: Vec<NotificationResponse>,
+    unread_count: u64,
+}
+
+async fn get_notifications(
+    state: State<AppState>,
+    user: AuthUser,
+    Query(pagination): Query<PaginationParams>,
+    Query(query): Query<NotificationsQuery>,
+) -> AppResult<Json<GetNotificationsResponse>> {
+    let get_notifications_use_case =
+        GetNotificationsUseCase::new(state.notification_repository.clone());
+    let pagination = pagination::Pagination::from(pagination);
+
+    let output = get_notifications_use_case
+        .execute(GetNotificationsInput {
+            user_id: user.id,
+            unread_only: query.unread.unwrap_or_default(),
+            pagination: (pagination.offset, pagination.limit),
+        })
+        .await?;
+
+    anyhow::Result::Ok(Json(GetNotificationsResponse {
+        notifications: output
+            .notifications
+            .into_iter()
+            .map(|(notification, actor)| NotificationResponse {
+                read: notification.is_read(),
+                id: notification.id.into(),
+                kind: notification.kind,
+                actor: NotificationActorResponse {
+                    id: actor.id.into(),
+                    username: actor.username,
+                    avatar_url: actor.avatar_url,
+                },
+                post_id: notification.post_id.map(|id| id.into()),
+                comment_id: notification.comment_id.map(|id| id.into()),
+                created_at: notification.created_at,
+            })
+            .collect(),
+        unread_count: output.unread_count,
+    }))
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+struct MarkNotificationsReadRequest {
+    ids: Option<Vec<Uuid>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct MarkNotificationsReadResponse {
+    updated: u64,
+}
+
+async fn mark_notifications_read(
+    state: State<AppState>,
+    user: AuthUser,
+    payload: Option<Json<MarkNotificationsReadRequest>>,
+) -> AppResult<Json<MarkNotificationsReadResponse>> {
+    let mark_notifications_read_use_case =
+        MarkNotificationsReadUseCase::new(state.notification_repository.clone());
+    // Without a body every notification of the user is marked as read
+    let payload = payload.map(|Json(payload)| payload).unwrap_or_default();
+
+    let output = mark_notifications_read_use_case
+        .execute(MarkNotificationsReadInput {
+            user_id: user.id,
+            ids: payload.ids,
+        })
+        .await?;
+
+    anyhow::Result::Ok(Json(MarkNotificationsReadResponse {
+        updated: output.updated,
+    }))
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct UserRelationResponse {
+    id: Uuid,
+    username: String,
+    avatar_url: Option<String>,
+    created_at: DateTime<Utc>,
+}
+
+async fn get_user_relations(
+    state: &AppState,
+    user: AuthUser,
+    kind: UserRelationKind,
+) -> AppResult<Json<Vec<UserRelationResponse>>> {
+    let get_user_relations_use_case =
+        GetUserRelationsUseCase::new(state.user_relation_repository.clone());
+
+    let output = get_user_relations_use_case
+        .execute(GetUserRelationsInput {
+            user_id: user.id,
+            kind,
+        })
+        .await?;
+
+    anyhow::Result::Ok(Json(
+        output
+            .relations
+            .into_iter()
+            .map(|(relation, target)| UserRelationResponse {
+                id: target.id.into(),
+                username: target.username,
+                avatar_url: target.avatar_url,
+                created_at: relation.created_at,
+            })
+            .collect(),
+    ))
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct UserRelationChangeResponse {
+    success: bool,
+}
+
+async fn add_user_relation(
+    state: &AppState,
+    user: AuthUser,
+    target_id: Uuid,
+    kind: UserRelationKind,
+) -> AppResult<Json<UserRelationChangeResponse>> {
+    let add_user_relation_use_case = AddUserRelationUseCase::new(
+        state.user_relation_repository.clone(),
+        state.user_repository.clone(),
+    );
+
+    add_user_relation_use_case
+        .execute(AddUserRelationInput {
+            user_id: user.id,
+            target_id,
+            kind,
+        })
+        .await?;
+
+    anyhow::Result::Ok(Json(UserRelationChangeResponse { success: true }))
+}
+
+async fn remove_user_relation(
+    state: &AppState,
+    user: AuthUser,
+    target_id: Uuid,
+    kind: UserRelationKind,
+) -> AppResult<Json<UserRelationChangeResponse>> {
+    let remove_user_relation_use_case =
+        RemoveUserRelationUseCase::new(state.user_relation_repository.clone());
+
+    let output = remove_user_relation_use_case
+        .execute(RemoveUserRelationInput {
+            user_id: user.id,
+            target_id,
+            kind,
+        })
+        .await?;
+
+    if !output.success {
+        return Err(AppError::NotFound("User".into()));
+    }
+
+    anyhow::Result::Ok(Json(UserRelationChangeResponse { success: true }))
+}
+
+/// Users blocked by the current user, they can not see or interact with the user's content
+async fn get_blocked_users(
+    state: State<AppState>,
+    user: AuthUser,
+) -> AppResult<Json<Vec<UserRelationResponse>>> {
+    get_user_relations(&state, user, UserRelationKind::Block).await
+}
+
+async fn add_blocked_user(
+    state: State<AppState>,
+    user: AuthUser,
+    Path(id): Path<Uuid>,
+) -> AppResult<Json<UserRelationChangeResponse>> {
+    add_user_relation(&state, user, id, UserRelationKind::Block).await
+}
+
+async fn remove_blocked_user(
+    state: State<AppState>,
+    user: AuthUser,
+    Path(id): Path<Uuid>,
+) -> AppResult<Json<UserRelationChangeResponse>> {
+    remove_user_relation(&state, user, id, UserRelationKind::Block).await
+}
+
+/// Users muted by the current user, their posts are left out of the user's feeds
+async fn get_muted_users(
+    state: State<AppState>,
+    user: AuthUser,
+) -> AppResult<Json<Vec<UserRelationResponse>>> {
+    get_user_relations(&state, user, UserRelationKind::Mute).await
+}
+
+async fn add_muted_user(
+    state: State<AppState>,
+    user: AuthUser,
+    Path(id): Path<Uuid>,
+) -> AppResult<Json<UserRelationChangeResponse>> {
+    add_user_relation(&state, user, id, UserRelationKind::Mute).await
+}
+
+async fn remove_muted_user(
+    state: State<AppState>,
+    user: AuthUser,
+    Path(id): Path<Uuid>,
+) -> AppResult<Json<UserRelationChangeResponse>> {
+    remove_user_relation(&state, user, id, UserRelationKind::Mute).await
+}
+
+/// Users followed by the current user, a post may limit its comments to the author's followers
+async fn get_followed_users(
+    state: State<AppState>,
+    user: AuthUser,
+) -> AppResult<Json<Vec<UserRelationResponse>>> {
+    get_user_relations(&state, user, UserRelationKind::Follow).await
+}
+
+async fn follow_user(
+    state: State<AppState>,
+    user: AuthUser,
+    Path(id): Path<Uuid>,
+) -> AppResult<Json<UserRelationChangeResponse>> {
+    add_user_relation(&state, user, id, UserRelationKind::Follow).await
+}
+
+async fn unfollow_user(
+    state: State<AppState>,
+    user: AuthUser,
+    Path(id): Path<Uuid>,
+) -> AppResult<Json<UserRelationChangeResponse>> {
+    remove_user_relation(&state, user, id, UserRelationKind::Follow).await
+}
+
 pub fn user_routes() -> axum::Router<crate::AppState> {
     axum::Router::new()
         .route("/", get(get_all_users))
         .route("/", post(create_user))
         .route("/me", get(me))
+        .route("/me", delete(delete_me))
+        .route("/me/saved", get(get_saved_posts))
+        .route("/me/notifications", get(get_notifications))
+        .route("/me/notifications/read", post(mark_notifications_read))
+        .route("/me/collections", get(get_collections))
+        .route("/me/collections", post(create_collection))
+        .route("/me/collections/:id", put(rename_collection))
+        .route("/me/collections/:id", delete(delete_collection))
+        .route("/me/blocked", get(get_blocked_users))
+        .route("/me/blocked/:id", post(add_blocked_user))
+        .route("/me/blocked/:id", delete(remove_blocked_user))
+        .route("/me/muted", get(get_muted_users))
+        .route("/me/muted/:id", post(add_muted_user))
+        .route("/me/muted/:id", delete(remove_muted_user))
+        .route("/me/following", get(get_followed_users))
+        .route("/me/following/:id", post(follow_user))
+        .route("/me/following/:id", delete(unfollow_user))
         .route("/:username", get(get_user_by_username))
         .route("/id/:id", get(get_user_by_id))
         .route("/id/:id", delete(delete_user))
         .route("/id/:id", put(update_user))
         .route("/id/:id/block", post(block_user))
         .route("/id/:id/unblock", post(unblock_user))
+        .route("/id/:id/limit", post(limit_user))
+        .route("/id/:id/unlimit", post(unlimit_user))
 }
@@ -5,11 +5,15 @@ use axum::{
 use chrono::{DateTime, Utc};
 use models::{
     domain::{
+        image_variant::ImageVariant,
+        mention::{stored_mention_spans, MentionSpan},
         post::{PostType, PostVisibilityType},
-        user::UserType,
+        reaction::{count_reactions, ReactionCount},
+        user::{User, UserType},
     },
     errors::{AppError, AppResult},
 };
+use repository::wall_repository::WallPostTuple;
 use serde::{Deserialize, Serialize};
 use usecase::{
     group::{
@@ -20,7 +24,7 @@ use usecase::{
         get_feed_posts::{GetFeedPostsInput, GetFeedPostsUseCase},
         get_tag_posts::{GetTagPostsInput, GetTagPostsUseCase},
         get_wall_posts::{GetWallPostsInput, GetWallPostsUseCase},
-        types::SortBy,
+        types::{FeedMode, SortBy},
     },
 };
 use uuid::Uuid;
@@ -45,8 +49,11 @@ pub struct GetPostResponse {
     pub id: Uuid,
     pub title: String,
     pub description: String,
+    pub mentions: Vec<MentionSpan>,
     pub post_type: PostType,
     pub content_url: String,
+    /// Resized variants of the image, empty for posts from before image processing
+    pub srcset: Vec<ImageVariant>,
     pub visibility: PostVisibilityType,
     pub created_at: DateTime<Utc>,
 }
@@ -57,13 +64,19 @@ pub struct GetPostCommentResponse {
     pub post_id: Uuid,
     pub user: GetAuthorResponse,
     pub content: String,
+    pub mentions: Vec<MentionSpan>,
     pub parent_id: Option<Uuid>,
+    pub like_count: i64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: Option<DateTime<Utc>>,
+    pub edited: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GetPostLikeResponse {
     post_id: Uuid,
     user: GetAuthorResponse,
+    kind: String,
     created_at: DateTime<Utc>,
 }
 
@@ -73,12 +86,72 @@ pub struct PostItem {
     author: GetAuthorResponse,
     comments: Vec<GetPostCommentResponse>,
     likes: Vec<GetPostLikeResponse>,
+    reactions: Vec<ReactionCount>,
     tags: Vec<String>,
 }
 
+impl From<User> for GetAuthorResponse {
+    fn from(user: User) -> Self {
+        Self {
+            id: user.id.into(),
+            description: user.description,
+            username: user.username,
+            avatar_url: user.avatar_url,
+            user_type: user.user_type,
+        }
+    }
+}
+
+impl From<WallPostTuple> for PostItem {
+    fn from((post, author, comments, likes, tags, mentions): WallPostTuple) -> Self {
+        let reactions = count_reactions(likes.iter().map(|(like, _)| like.kind.as_str()));
+
+        Self {
+            post: GetPostResponse {
+                id: post.id.into(),
+                title: post.title,
+                mentions: stored_mention_spans(&post.description, None, &mentions),
+                description: post.description,
+                post_type: post.post_type,
+                content_url: post.content_url,
+                srcset: post.image_variants,
+                visibility: post.visibility,
+                created_at: post.created_at,
+            },
+            author: author.into(),
+            comments: comments
+                .into_iter()
+                .map(|(comment, user, like_count)| GetPostCommentResponse {
+                    id: comment.id.clone().into(),
+                    post_id: comment.post_id.clone().into(),
+                    user: user.into(),
+                    parent_id: comment.parent_id.clone().map(|id| id.into()),
+                    like_count,
+                    created_at: comment.created_at,
+                    updated_at: comment.updated_at,
+                    edited: comment.is_edited(),
+                    mentions: stored_mention_spans(&comment.content, Some(&comment.id), &mentions),
+                    content: comment.content,
+                })
+                .collect(),
+            likes: likes
+                .into_iter()
+                .map(|(like, user)| GetPostLikeResponse {
+                    post_id: like.post_id.into(),
+                    user: user.into(),
+                    kind: like.kind,
+                    created_at: like.created_at,
+                })
+                .collect(),
+            reactions,
+            tags,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GetWallResponse {
-    posts: Vec<PostItem>,
+    pub posts: Vec<PostItem>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -86,6 +159,11 @@ pub struct SortQuery {
     sort_by: Option<SortBy>,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FeedModeQuery {
+    mode: Option<FeedMode>,
+}
+
 pub async fn get_wall(
     state: State<AppState>,
     OptionalAuthUser(_user): OptionalAuthUser,
@@ -136,58 +214,7 @@ pub async fn get_wall(
     let output = get_wall_posts_usecase.execute(input).await?;
 
     Ok(Json(GetWallResponse {
-        posts: output
-            .into_iter()
-            .map(|(post, author, comments, likes, tags)| PostItem {
-                post: GetPostResponse {
-                    id: post.id.into(),
-                    title: post.title,
-                    description: post.description,
-                    post_type: post.post_type,
-                    content_url: post.content_url,
-                    visibility: post.visibility,
-                    created_at: post.created_at,
-                },
-                author: GetAuthorResponse {
-                    id: author.id.into(),
-                    description: author.description,
-                    username: author.username,
-                    avatar_url: author.avatar_url,
-                    user_type: author.user_type,
-                },
-                comments: comments
-                    .into_iter()
-                    .map(|(comment, user)| GetPostCommentResponse {
-                        id: comment.id.into(),
-                        post_id: comment.post_id.into(),
-                        content: comment.content,
-                        user: GetAuthorResponse {
-                            id: user.id.into(),
-                            description: user.description,
-                            username: user.username,
-                            avatar_url: user.avatar_url,
-                            user_type: user.user_type,
-                        },
-                        parent_id: comment.parent_id.map(|id| id.into()),
-                    })
-                    .collect(),
-                likes: likes
-                    .into_iter()
-                    .map(|(like, user)| GetPostLikeResponse {
-                        post_id: like.post_id.into(),
-                        user: GetAuthorResponse {
-                            id: user.id.into(),
-                            description: user.description,
-                            username: user.username,
-                            avatar_url: user.avatar_url,
-                            user_type: user.user_type,
-                        },
-                        created_at: like.created_at,
-                    })
-                    .collect(),
-                tags,
-            })
-            .collect(),
+        posts: output.into_iter().map(PostItem::from).collect(),
     }))
 }
 
@@ -196,6 +223,7 @@ pub async fn get_feed(
     OptionalAuthUser(user): OptionalAuthUser,
     Query(pagination): Query<PaginationParams>,
     Query(sort_by): Query<SortQuery>,
+    Query(mode): Query<FeedModeQuery>,
 ) -> AppResult<Json<GetWallResponse>> {
     let get_feed_usecase = GetFeedPostsUseCase::new(state.wall_repository.clone());
     let pagination = pagination::Pagination::from(pagination);
@@ -208,63 +236,13 @@ pub async fn get_feed(
         },
         pagination: (pagination.offset, pagination.limit),
         sort_by: sort_by.sort_by.unwrap_or_default(),
+        mode: mode.mode.unwrap_or_default(),
     };
 
     let output = get_feed_usecase.execute(input).await?;
 
     Ok(Json(GetWallResponse {
-        posts: output
-            .into_iter()
-            .map(|(post, author, comments, likes, tags)| PostItem {
-                post: GetPostResponse {
-                    id: post.id.into(),
-                    title: post.title,
-                    description: post.description,
-                    post_type: post.post_type,
-                    content_url: post.content_url,
-                    visibility: post.visibility,
-                    created_at: post.created_at,
-                },
-                author: GetAuthorResponse {
-                    id: author.id.into(),
-                    description: author.description,
-                    username: author.username,
-                    avatar_url: author.avatar_url,
-                    user_type: author.user_type,
-                },
-                comments: comments
-                    .into_iter()
-                    .map(|(comment, user)| GetPostCommentResponse {
-                        id: comment.id.into(),
-                        post_id: comment.post_id.into(),
-                        content: comment.content,
-                        user: GetAuthorResponse {
-                            id: user.id.into(),
-                            description: user.description,
-                            username: user.username,
-                            avatar_url: user.avatar_url,
-                            user_type: user.user_type,
-                        },
-                        parent_id: comment.parent_id.map(|id| id.into()),
-                    })
-                    .collect(),
-                likes: likes
-                    .into_iter()
-                    .map(|(like, user)| GetPostLikeResponse {
-                        post_id: like.post_id.into(),
-                        user: GetAuthorResponse {
-                            id: user.id.into(),
-                            description: user.description,
-                            username: user.username,
-                            avatar_url: user.avatar_url,
-                            user_type: user.user_type,
-                        },
-                        created_at: like.created_at,
-                    })
-                    .collect(),
-                tags,
-            })
-            .collect(),
+        posts: output.into_iter().map(PostItem::from).collect(),
     }))
 }
 
@@ -297,58 +275,7 @@ pub async fn get_wall_by_tag(
     let output = get_tag_usecase.execute(input).await?;
 
     Ok(Json(GetWallResponse {
-        posts: output
-            .into_iter()
-            .map(|(post, author, comments, likes, tags)| PostItem {
-                post: GetPostResponse {
-                    id: post.id.into(),
-                    title: post.title,
-                    description: post.description,
-                    post_type: post.post_type,
-                    content_url: post.content_url,
-                    visibility: post.visibility,
-                    created_at: post.created_at,
-                },
-                author: GetAuthorResponse {
-                    id: author.id.into(),
-                    description: author.description,
-                    username: author.username,
-                    avatar_url: author.avatar_url,
-                    user_type: author.user_type,
-                },
-                comments: comments
-                    .into_iter()
-                    .map(|(comment, user)| GetPostCommentResponse {
-                        id: comment.id.into(),
-                        post_id: comment.post_id.into(),
-                        content: comment.content,
-                        user: GetAuthorResponse {
-                            id: user.id.into(),
-                            description: user.description,
-                            username: user.username,
-                            avatar_url: user.avatar_url,
-                            user_type: user.user_type,
-                        },
-                        parent_id: comment.parent_id.map(|id| id.into()),
-                    })
-                    .collect(),
-                likes: likes
-                    .into_iter()
-                    .map(|(like, user)| GetPostLikeResponse {
-                        post_id: like.post_id.into(),
-                        user: GetAuthorResponse {
-                            id: user.id.into(),
-                            description: user.description,
-                            username: user.username,
-                            avatar_url: user.avatar_url,
-                            user_type: user.user_type,
-                        },
-                        created_at: like.created_at,
-                    })
-                    .collect(),
-                tags,
-            })
-            .collect(),
+        posts: output.into_iter().map(PostItem::from).collect(),
     }))
 }
 
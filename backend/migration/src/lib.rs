@@ -14,6 +14,26 @@ mod m20241110_225527_group_join_request;
 mod m20241121_111845_user_make_fields_unique;
 mod m20241124_004127_visibility_tables;
 mod m20241125_001304_add_description_user;
+mod m20241201_153012_soft_delete_posts;
+mod m20241203_101544_comment_timestamps_and_likes;
+mod m20241205_184210_post_like_reaction_kind;
+mod m20241207_120318_saved_posts;
+mod m20241209_090412_mentions_and_notifications;
+mod m20241211_153027_tag_follow;
+mod m20241213_101544_location_geo_index;
+mod m20241215_142233_post_image_variants;
+mod m20241217_093145_report;
+mod m20241219_161207_audit_log;
+mod m20241221_104512_user_suspension;
+mod m20241223_091530_user_is_limited;
+mod m20241225_103020_content_filter;
+mod m20241227_140215_user_created_at;
+mod m20241229_093410_group_created_at;
+mod m20241231_101245_user_relation;
+mod m20250102_094512_comment_controls;
+mod m20250104_110230_user_deletion_requested;
+mod m20250106_093015_normalize_post_tags;
+mod m20250108_091045_held_content;
 
 pub struct Migrator;
 
@@ -35,6 +55,26 @@ impl MigratorTrait for Migrator {
             Box::new(m20241121_111845_user_make_fields_unique::Migration),
             Box::new(m20241124_004127_visibility_tables::Migration),
             Box::new(m20241125_001304_add_description_user::Migration),
+            Box::new(m20241201_153012_soft_delete_posts::Migration),
+            Box::new(m20241203_101544_comment_timestamps_and_likes::Migration),
+            Box::new(m20241205_184210_post_like_reaction_kind::Migration),
+            Box::new(m20241207_120318_saved_posts::Migration),
+            Box::new(m20241209_090412_mentions_and_notifications::Migration),
+            Box::new(m20241211_153027_tag_follow::Migration),
+            Box::new(m20241213_101544_location_geo_index::Migration),
+            Box::new(m20241215_142233_post_image_variants::Migration),
+            Box::new(m20241217_093145_report::Migration),
+            Box::new(m20241219_161207_audit_log::Migration),
+            Box::new(m20241221_104512_user_suspension::Migration),
+            Box::new(m20241223_091530_user_is_limited::Migration),
+            Box::new(m20241225_103020_content_filter::Migration),
+            Box::new(m20241227_140215_user_created_at::Migration),
+            Box::new(m20241229_093410_group_created_at::Migration),
+            Box::new(m20241231_101245_user_relation::Migration),
+            Box::new(m20250102_094512_comment_controls::Migration),
+            Box::new(m20250104_110230_user_deletion_requested::Migration),
+            Box::new(m20250106_093015_normalize_post_tags::Migration),
+            Box::new(m20250108_091045_held_content::Migration),
         ]
     }
 }
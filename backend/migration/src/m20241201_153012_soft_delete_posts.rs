@@ -0,0 +1,125 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{m20220101_000001_create_user_table::User, m20241009_204559_create_post_table::Post};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                sea_query::Table::alter()
+                    .table(Post::Table)
+                    .add_column(ColumnDef::new(SoftDelete::DeletedAt).null().date_time())
+                    .add_column(ColumnDef::new(SoftDelete::DeletedBy).null().uuid())
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk_post_deleted_by")
+                            .from_tbl(Post::Table)
+                            .from_col(SoftDelete::DeletedBy)
+                            .to_tbl(User::Table)
+                            .to_col(User::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                sea_query::Table::alter()
+                    .table(PostComment::Table)
+                    .add_column(ColumnDef::new(SoftDelete::DeletedAt).null().date_time())
+                    .add_column(ColumnDef::new(SoftDelete::DeletedBy).null().uuid())
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk_post_comment_deleted_by")
+                            .from_tbl(PostComment::Table)
+                            .from_col(SoftDelete::DeletedBy)
+                            .to_tbl(User::Table)
+                            .to_col(User::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // The retention job scans for rows deleted before a cutoff
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_post_deleted_at")
+                    .table(Post::Table)
+                    .col(SoftDelete::DeletedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_post_comment_deleted_at")
+                    .table(PostComment::Table)
+                    .col(SoftDelete::DeletedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_post_comment_deleted_at")
+                    .table(PostComment::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_post_deleted_at")
+                    .table(Post::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                sea_query::Table::alter()
+                    .table(PostComment::Table)
+                    .drop_foreign_key(Alias::new("fk_post_comment_deleted_by"))
+                    .drop_column(SoftDelete::DeletedAt)
+                    .drop_column(SoftDelete::DeletedBy)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                sea_query::Table::alter()
+                    .table(Post::Table)
+                    .drop_foreign_key(Alias::new("fk_post_deleted_by"))
+                    .drop_column(SoftDelete::DeletedAt)
+                    .drop_column(SoftDelete::DeletedBy)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum PostComment {
+    Table,
+}
+
+#[derive(DeriveIden)]
+enum SoftDelete {
+    DeletedAt,
+    DeletedBy,
+}
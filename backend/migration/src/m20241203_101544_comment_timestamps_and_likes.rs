@@ -0,0 +1,106 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20220101_000001_create_user_table::User;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                sea_query::Table::alter()
+                    .table(PostComment::Table)
+                    .add_column(
+                        ColumnDef::new(PostComment::CreatedAt)
+                            .not_null()
+                            .date_time()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .add_column(ColumnDef::new(PostComment::UpdatedAt).null().date_time())
+                    .to_owned(),
+            )
+            .await?;
+
+        // Comments written before this migration have no recorded creation time. They get the
+        // creation time of their post instead of the time it ran, so old threads keep their
+        // order relative to new comments and don't all count as written today.
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+UPDATE post_comment
+SET created_at = post.created_at
+FROM post
+WHERE post.id = post_comment.post_id
+"#,
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(CommentLike::Table)
+                    .if_not_exists()
+                    .col(uuid(CommentLike::CommentId))
+                    .col(uuid(CommentLike::UserId))
+                    .col(date_time(CommentLike::CreatedAt))
+                    .primary_key(
+                        Index::create()
+                            .col(CommentLike::CommentId)
+                            .col(CommentLike::UserId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_comment_like_comment")
+                            .from(CommentLike::Table, CommentLike::CommentId)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .to(PostComment::Table, PostComment::Id),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_comment_like_user")
+                            .from(CommentLike::Table, CommentLike::UserId)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .to(User::Table, User::Id),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(CommentLike::Table).to_owned())
+            .await?;
+
+        manager
+            .alter_table(
+                sea_query::Table::alter()
+                    .table(PostComment::Table)
+                    .drop_column(PostComment::CreatedAt)
+                    .drop_column(PostComment::UpdatedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum PostComment {
+    Table,
+    Id,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum CommentLike {
+    Table,
+    CommentId,
+    UserId,
+    CreatedAt,
+}
@@ -0,0 +1,62 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Every existing like becomes a reaction of the default kind
+        manager
+            .alter_table(
+                sea_query::Table::alter()
+                    .table(PostLike::Table)
+                    .add_column(
+                        ColumnDef::new(PostLike::Kind)
+                            .not_null()
+                            .string()
+                            .default("like"),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_post_like_post_kind")
+                    .table(PostLike::Table)
+                    .col(PostLike::PostId)
+                    .col(PostLike::Kind)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_post_like_post_kind")
+                    .table(PostLike::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                sea_query::Table::alter()
+                    .table(PostLike::Table)
+                    .drop_column(PostLike::Kind)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum PostLike {
+    Table,
+    PostId,
+    Kind,
+}
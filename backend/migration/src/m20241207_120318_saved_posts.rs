@@ -0,0 +1,103 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::{m20220101_000001_create_user_table::User, m20241009_204559_create_post_table::Post};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SavedCollection::Table)
+                    .if_not_exists()
+                    .col(uuid(SavedCollection::Id).primary_key())
+                    .col(uuid(SavedCollection::UserId))
+                    .col(string(SavedCollection::Name))
+                    .col(date_time(SavedCollection::CreatedAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_saved_collection_user")
+                            .from(SavedCollection::Table, SavedCollection::UserId)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .to(User::Table, User::Id),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Deleting a collection keeps its posts saved, they just are not filed anywhere
+        manager
+            .create_table(
+                Table::create()
+                    .table(SavedPost::Table)
+                    .if_not_exists()
+                    .col(uuid(SavedPost::UserId))
+                    .col(uuid(SavedPost::PostId))
+                    .col(uuid_null(SavedPost::CollectionId))
+                    .col(date_time(SavedPost::CreatedAt))
+                    .primary_key(
+                        Index::create()
+                            .col(SavedPost::UserId)
+                            .col(SavedPost::PostId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_saved_post_user")
+                            .from(SavedPost::Table, SavedPost::UserId)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .to(User::Table, User::Id),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_saved_post_post")
+                            .from(SavedPost::Table, SavedPost::PostId)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .to(Post::Table, Post::Id),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_saved_post_collection")
+                            .from(SavedPost::Table, SavedPost::CollectionId)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .to(SavedCollection::Table, SavedCollection::Id),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SavedPost::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(SavedCollection::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum SavedCollection {
+    Table,
+    Id,
+    UserId,
+    Name,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum SavedPost {
+    Table,
+    UserId,
+    PostId,
+    CollectionId,
+    CreatedAt,
+}
@@ -0,0 +1,168 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::{m20220101_000001_create_user_table::User, m20241009_204559_create_post_table::Post};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Mention::Table)
+                    .if_not_exists()
+                    .col(uuid(Mention::Id).primary_key())
+                    .col(uuid(Mention::PostId))
+                    .col(uuid_null(Mention::CommentId))
+                    .col(uuid(Mention::UserId))
+                    .col(uuid(Mention::AuthorId))
+                    .col(date_time(Mention::CreatedAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_mention_post")
+                            .from(Mention::Table, Mention::PostId)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .to(Post::Table, Post::Id),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_mention_comment")
+                            .from(Mention::Table, Mention::CommentId)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .to(PostComment::Table, PostComment::Id),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_mention_user")
+                            .from(Mention::Table, Mention::UserId)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .to(User::Table, User::Id),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_mention_author")
+                            .from(Mention::Table, Mention::AuthorId)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .to(User::Table, User::Id),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_mention_post")
+                    .table(Mention::Table)
+                    .col(Mention::PostId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(Notification::Table)
+                    .if_not_exists()
+                    .col(uuid(Notification::Id).primary_key())
+                    .col(uuid(Notification::UserId))
+                    .col(uuid(Notification::ActorId))
+                    .col(string(Notification::Kind))
+                    .col(uuid_null(Notification::PostId))
+                    .col(uuid_null(Notification::CommentId))
+                    .col(date_time(Notification::CreatedAt))
+                    .col(date_time_null(Notification::ReadAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_notification_user")
+                            .from(Notification::Table, Notification::UserId)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .to(User::Table, User::Id),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_notification_actor")
+                            .from(Notification::Table, Notification::ActorId)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .to(User::Table, User::Id),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_notification_post")
+                            .from(Notification::Table, Notification::PostId)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .to(Post::Table, Post::Id),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_notification_comment")
+                            .from(Notification::Table, Notification::CommentId)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .to(PostComment::Table, PostComment::Id),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_notification_user_created_at")
+                    .table(Notification::Table)
+                    .col(Notification::UserId)
+                    .col(Notification::CreatedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Notification::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(Mention::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum PostComment {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Mention {
+    Table,
+    Id,
+    PostId,
+    CommentId,
+    UserId,
+    AuthorId,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Notification {
+    Table,
+    Id,
+    UserId,
+    ActorId,
+    Kind,
+    PostId,
+    CommentId,
+    CreatedAt,
+    ReadAt,
+}
@@ -0,0 +1,47 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20220101_000001_create_user_table::User;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Tags are plain strings on posts, so a followed tag does not have to be in use yet
+        manager
+            .create_table(
+                Table::create()
+                    .table(TagFollow::Table)
+                    .if_not_exists()
+                    .col(uuid(TagFollow::UserId))
+                    .col(string(TagFollow::Tag))
+                    .col(date_time(TagFollow::CreatedAt))
+                    .primary_key(Index::create().col(TagFollow::UserId).col(TagFollow::Tag))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_tag_follow_user")
+                            .from(TagFollow::Table, TagFollow::UserId)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .to(User::Table, User::Id),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(TagFollow::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum TagFollow {
+    Table,
+    UserId,
+    Tag,
+    CreatedAt,
+}
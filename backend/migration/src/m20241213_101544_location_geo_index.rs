@@ -0,0 +1,64 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Nearby and map queries first narrow locations down to a latitude band or a box
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_location_latitude_longitude")
+                    .table(Location::Table)
+                    .col(Location::Latitude)
+                    .col(Location::Longitude)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_post_location_id")
+                    .table(Post::Table)
+                    .col(Post::LocationId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_post_location_id")
+                    .table(Post::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_location_latitude_longitude")
+                    .table(Location::Table)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Location {
+    Table,
+    Latitude,
+    Longitude,
+}
+
+#[derive(DeriveIden)]
+enum Post {
+    Table,
+    LocationId,
+}
@@ -0,0 +1,41 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Posts created before image processing only have the uploaded image itself
+        manager
+            .alter_table(
+                sea_query::Table::alter()
+                    .table(Post::Table)
+                    .add_column(
+                        ColumnDef::new(Post::ImageVariants)
+                            .not_null()
+                            .json_binary()
+                            .default(Expr::cust("'[]'::jsonb")),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                sea_query::Table::alter()
+                    .table(Post::Table)
+                    .drop_column(Post::ImageVariants)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Post {
+    Table,
+    ImageVariants,
+}
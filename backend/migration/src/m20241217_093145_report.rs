@@ -0,0 +1,97 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20220101_000001_create_user_table::User;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // The target is a post, comment, user or group, so it can not have a foreign key
+        manager
+            .create_table(
+                Table::create()
+                    .table(Report::Table)
+                    .if_not_exists()
+                    .col(uuid(Report::Id).primary_key())
+                    .col(uuid(Report::ReporterId))
+                    .col(string(Report::TargetType))
+                    .col(uuid(Report::TargetId))
+                    .col(string(Report::Reason))
+                    .col(string_len(Report::Details, 1000).default(""))
+                    .col(string(Report::Status).default("open"))
+                    .col(uuid_null(Report::ModeratorId))
+                    .col(string_null(Report::Outcome))
+                    .col(string_len_null(Report::ResolutionNote, 1000))
+                    .col(date_time(Report::CreatedAt))
+                    .col(date_time_null(Report::ClaimedAt))
+                    .col(date_time_null(Report::ClosedAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_report_reporter")
+                            .from(Report::Table, Report::ReporterId)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .to(User::Table, User::Id),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_report_moderator")
+                            .from(Report::Table, Report::ModeratorId)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .to(User::Table, User::Id),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // The queue lists reports by status, oldest first
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_report_status_created_at")
+                    .table(Report::Table)
+                    .col(Report::Status)
+                    .col(Report::CreatedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_report_target")
+                    .table(Report::Table)
+                    .col(Report::TargetType)
+                    .col(Report::TargetId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Report::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Report {
+    Table,
+    Id,
+    ReporterId,
+    TargetType,
+    TargetId,
+    Reason,
+    Details,
+    Status,
+    ModeratorId,
+    Outcome,
+    ResolutionNote,
+    CreatedAt,
+    ClaimedAt,
+    ClosedAt,
+}
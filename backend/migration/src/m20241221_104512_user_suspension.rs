@@ -0,0 +1,95 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20220101_000001_create_user_table::User;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(UserSuspension::Table)
+                    .if_not_exists()
+                    .col(uuid(UserSuspension::Id).primary_key())
+                    .col(uuid(UserSuspension::UserId))
+                    .col(uuid_null(UserSuspension::ModeratorId))
+                    .col(string_len(UserSuspension::Reason, 500))
+                    .col(date_time(UserSuspension::StartsAt))
+                    .col(date_time_null(UserSuspension::EndsAt))
+                    .col(date_time_null(UserSuspension::LiftedAt))
+                    .col(uuid_null(UserSuspension::LiftedBy))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_user_suspension_user")
+                            .from(UserSuspension::Table, UserSuspension::UserId)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .to(User::Table, User::Id),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_user_suspension_moderator")
+                            .from(UserSuspension::Table, UserSuspension::ModeratorId)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .to(User::Table, User::Id),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_user_suspension_lifted_by")
+                            .from(UserSuspension::Table, UserSuspension::LiftedBy)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .to(User::Table, User::Id),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Every authenticated request looks up the active suspension of the user
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_user_suspension_user_id")
+                    .table(UserSuspension::Table)
+                    .col(UserSuspension::UserId)
+                    .col(UserSuspension::LiftedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        // Users blocked before suspensions existed stay suspended indefinitely
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"INSERT INTO user_suspension (id, user_id, reason, starts_at)
+                SELECT gen_random_uuid(), id, 'Blocked by a moderator', now()
+                FROM "user" WHERE is_blocked"#,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(UserSuspension::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum UserSuspension {
+    Table,
+    Id,
+    UserId,
+    ModeratorId,
+    Reason,
+    StartsAt,
+    EndsAt,
+    LiftedAt,
+    LiftedBy,
+}
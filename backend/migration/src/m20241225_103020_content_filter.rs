@@ -0,0 +1,81 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20220101_000001_create_user_table::User;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ContentFilter::Table)
+                    .if_not_exists()
+                    .col(uuid(ContentFilter::Id).primary_key())
+                    .col(string_len(ContentFilter::Pattern, 200))
+                    .col(string(ContentFilter::Kind))
+                    .col(string(ContentFilter::Action))
+                    .col(uuid_null(ContentFilter::CreatedBy))
+                    .col(date_time(ContentFilter::CreatedAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_content_filter_created_by")
+                            .from(ContentFilter::Table, ContentFilter::CreatedBy)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .to(User::Table, User::Id),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Content caught by the filter is reported by nobody
+        manager
+            .alter_table(
+                sea_query::Table::alter()
+                    .table(Report::Table)
+                    .modify_column(ColumnDef::new(Report::ReporterId).uuid().null())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared("DELETE FROM report WHERE reporter_id IS NULL")
+            .await?;
+
+        manager
+            .alter_table(
+                sea_query::Table::alter()
+                    .table(Report::Table)
+                    .modify_column(ColumnDef::new(Report::ReporterId).uuid().not_null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(ContentFilter::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum ContentFilter {
+    Table,
+    Id,
+    Pattern,
+    Kind,
+    Action,
+    CreatedBy,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Report {
+    Table,
+    ReporterId,
+}
@@ -0,0 +1,83 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                sea_query::Table::alter()
+                    .table(User::Table)
+                    .add_column(
+                        ColumnDef::new(User::CreatedAt)
+                            .not_null()
+                            .date_time()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Users registered before this migration get the time of their first post, comment,
+        // like or group membership instead of the time it ran, so they don't all land in one
+        // bucket of the signup series. Users who never did anything keep the migration time.
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+UPDATE "user"
+SET created_at = first_activity.at
+FROM (
+    SELECT user_id, MIN(at) AS at
+    FROM (
+        SELECT author_id AS user_id, created_at AS at FROM post
+        UNION ALL SELECT user_id, created_at FROM post_comment
+        UNION ALL SELECT user_id, created_at FROM post_like
+        UNION ALL SELECT user_id, joined_at FROM group_member
+    ) activity
+    GROUP BY user_id
+) first_activity
+WHERE first_activity.user_id = "user".id AND first_activity.at < "user".created_at
+"#,
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_user_created_at")
+                    .table(User::Table)
+                    .col(User::CreatedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_user_created_at")
+                    .table(User::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                sea_query::Table::alter()
+                    .table(User::Table)
+                    .drop_column(User::CreatedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum User {
+    Table,
+    CreatedAt,
+}
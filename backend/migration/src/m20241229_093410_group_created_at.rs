@@ -0,0 +1,85 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                sea_query::Table::alter()
+                    .table(Group::Table)
+                    .add_column(
+                        ColumnDef::new(Group::CreatedAt)
+                            .not_null()
+                            .date_time()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Groups created before this migration get the time their first member joined or the
+        // first post on their wall instead of the time it ran, so they don't all land in one
+        // bucket of the group series. Groups without either keep the migration time.
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+UPDATE "group"
+SET created_at = first_activity.at
+FROM (
+    SELECT group_id, MIN(at) AS at
+    FROM (
+        SELECT group_id, joined_at AS at FROM group_member
+        UNION ALL
+        SELECT "group".id, post.created_at
+        FROM "group"
+        JOIN wall_post ON wall_post.wall_id = "group".wall_id
+        JOIN post ON post.id = wall_post.post_id
+    ) activity
+    GROUP BY group_id
+) first_activity
+WHERE first_activity.group_id = "group".id AND first_activity.at < "group".created_at
+"#,
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_group_created_at")
+                    .table(Group::Table)
+                    .col(Group::CreatedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_group_created_at")
+                    .table(Group::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                sea_query::Table::alter()
+                    .table(Group::Table)
+                    .drop_column(Group::CreatedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Group {
+    Table,
+    CreatedAt,
+}
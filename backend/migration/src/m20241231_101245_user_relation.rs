@@ -0,0 +1,74 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20220101_000001_create_user_table::User;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Blocks and mutes between two users, `user_id` is the one who blocked or muted `target_id`
+        manager
+            .create_table(
+                Table::create()
+                    .table(UserRelation::Table)
+                    .if_not_exists()
+                    .col(uuid(UserRelation::UserId))
+                    .col(uuid(UserRelation::TargetId))
+                    .col(string(UserRelation::Kind))
+                    .col(date_time(UserRelation::CreatedAt))
+                    .primary_key(
+                        Index::create()
+                            .col(UserRelation::UserId)
+                            .col(UserRelation::TargetId)
+                            .col(UserRelation::Kind),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_user_relation_user")
+                            .from(UserRelation::Table, UserRelation::UserId)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .to(User::Table, User::Id),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_user_relation_target")
+                            .from(UserRelation::Table, UserRelation::TargetId)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .to(User::Table, User::Id),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Visibility checks look relations up by the blocked or muted user
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_user_relation_target")
+                    .table(UserRelation::Table)
+                    .col(UserRelation::TargetId)
+                    .col(UserRelation::Kind)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(UserRelation::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum UserRelation {
+    Table,
+    UserId,
+    TargetId,
+    Kind,
+    CreatedAt,
+}
@@ -0,0 +1,65 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Existing posts stay open to comments from everyone who can see them
+        manager
+            .alter_table(
+                sea_query::Table::alter()
+                    .table(Post::Table)
+                    .add_column(
+                        ColumnDef::new(Post::CommentPolicy)
+                            .not_null()
+                            .string()
+                            .default("everyone"),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                sea_query::Table::alter()
+                    .table(PostComment::Table)
+                    .add_column(ColumnDef::new(PostComment::HiddenAt).null().date_time())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                sea_query::Table::alter()
+                    .table(PostComment::Table)
+                    .drop_column(PostComment::HiddenAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                sea_query::Table::alter()
+                    .table(Post::Table)
+                    .drop_column(Post::CommentPolicy)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Post {
+    Table,
+    CommentPolicy,
+}
+
+#[derive(DeriveIden)]
+enum PostComment {
+    Table,
+    HiddenAt,
+}
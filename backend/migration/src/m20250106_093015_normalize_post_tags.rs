@@ -0,0 +1,48 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// The same normalization as `normalize_tag`: trimmed, without a leading `#`, lowercased and in NFC
+const NORMALIZED_TAG: &str = "normalize(lower(regexp_replace(btrim(tag), '^#', '')), NFC)";
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let connection = manager.get_connection();
+
+        // Variants of the same tag on one post collapse into a single row, the rest is dropped
+        // so the primary key still holds after the update
+        connection
+            .execute_unprepared(&format!(
+                r#"
+DELETE FROM post_tag
+WHERE (post_id, tag) IN (
+    SELECT post_id, tag
+    FROM (
+        SELECT
+            post_id,
+            tag,
+            ROW_NUMBER() OVER (PARTITION BY post_id, {NORMALIZED_TAG} ORDER BY tag) AS position
+        FROM post_tag
+    ) variants
+    WHERE position > 1
+)
+"#
+            ))
+            .await?;
+
+        connection
+            .execute_unprepared(&format!(
+                "UPDATE post_tag SET tag = {NORMALIZED_TAG} WHERE tag <> {NORMALIZED_TAG}"
+            ))
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        // The original spelling of the tags is gone
+        Ok(())
+    }
+}
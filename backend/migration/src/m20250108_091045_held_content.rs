@@ -0,0 +1,84 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                sea_query::Table::alter()
+                    .table(Post::Table)
+                    .add_column(
+                        ColumnDef::new(Post::Held)
+                            .not_null()
+                            .boolean()
+                            .default(false),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                sea_query::Table::alter()
+                    .table(PostComment::Table)
+                    .add_column(
+                        ColumnDef::new(PostComment::Held)
+                            .not_null()
+                            .boolean()
+                            .default(false),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Content held by the filters was trashed without a deleter, it stays held
+        // rather than being purged before a moderator looks at it
+        let db = manager.get_connection();
+
+        db.execute_unprepared(
+            "UPDATE post SET held = TRUE WHERE deleted_at IS NOT NULL AND deleted_by IS NULL",
+        )
+        .await?;
+        db.execute_unprepared(
+            "UPDATE post_comment SET held = TRUE WHERE deleted_at IS NOT NULL AND deleted_by IS NULL",
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                sea_query::Table::alter()
+                    .table(PostComment::Table)
+                    .drop_column(PostComment::Held)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                sea_query::Table::alter()
+                    .table(Post::Table)
+                    .drop_column(Post::Held)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Post {
+    Table,
+    Held,
+}
+
+#[derive(DeriveIden)]
+enum PostComment {
+    Table,
+    Held,
+}
@@ -0,0 +1,205 @@
+use std::fmt::Display;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use uuid::Uuid;
+use validator::{Validate, ValidationErrors};
+
+use crate::schema;
+
+use super::{post::Post, post_comment::PostComment, user::User, Id};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AuditAction {
+    BlockUser,
+    UnblockUser,
+    LimitUser,
+    UnlimitUser,
+    DeleteUser,
+    ChangeRole,
+    DeletePost,
+    DeleteComment,
+}
+
+impl AuditAction {
+    pub fn target_type(&self) -> AuditTargetType {
+        match self {
+            Self::BlockUser
+            | Self::UnblockUser
+            | Self::LimitUser
+            | Self::UnlimitUser
+            | Self::DeleteUser
+            | Self::ChangeRole => AuditTargetType::User,
+            Self::DeletePost => AuditTargetType::Post,
+            Self::DeleteComment => AuditTargetType::Comment,
+        }
+    }
+}
+
+impl Display for AuditAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::BlockUser => write!(f, "blockUser"),
+            Self::UnblockUser => write!(f, "unblockUser"),
+            Self::LimitUser => write!(f, "limitUser"),
+            Self::UnlimitUser => write!(f, "unlimitUser"),
+            Self::DeleteUser => write!(f, "deleteUser"),
+            Self::ChangeRole => write!(f, "changeRole"),
+            Self::DeletePost => write!(f, "deletePost"),
+            Self::DeleteComment => write!(f, "deleteComment"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AuditTargetType {
+    User,
+    Post,
+    Comment,
+}
+
+impl Display for AuditTargetType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::User => write!(f, "user"),
+            Self::Post => write!(f, "post"),
+            Self::Comment => write!(f, "comment"),
+        }
+    }
+}
+
+/// A record of a moderation action, entries are never changed once written.
+#[derive(Clone, Debug, PartialEq, Validate, Deserialize, Serialize)]
+pub struct AuditLogEntry {
+    pub id: Id<AuditLogEntry>,
+    pub actor_id: Id<User>,
+    pub action: AuditAction,
+    pub target_type: AuditTargetType,
+    pub target_id: Uuid,
+    #[validate(length(max = 1000, message = "Reason must be at most 1000 characters"))]
+    pub reason: Option<String>,
+    /// State of the target before the action, `None` when there was nothing before
+    pub before: Option<Value>,
+    /// State of the target after the action, `None` when it was deleted
+    pub after: Option<Value>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl AuditLogEntry {
+    pub fn new(
+        actor_id: Id<User>,
+        action: AuditAction,
+        target_id: Uuid,
+        reason: Option<String>,
+        before: Option<Value>,
+        after: Option<Value>,
+    ) -> Result<Self, ValidationErrors> {
+        let entry = Self {
+            id: Id::gen(),
+            actor_id,
+            action,
+            target_type: action.target_type(),
+            target_id,
+            reason: reason
+                .map(|reason| reason.trim().to_string())
+                .filter(|reason| !reason.is_empty()),
+            before,
+            after,
+            created_at: Utc::now(),
+        };
+
+        entry.validate()?;
+
+        Ok(entry)
+    }
+}
+
+/// Snapshot of the user for the audit log, without the password hash.
+pub fn user_snapshot(user: &User) -> Value {
+    json!({
+        "id": user.id.id,
+        "username": user.username,
+        "email": user.email,
+        "description": user.description,
+        "avatar_url": user.avatar_url,
+        "user_type": user.user_type,
+        "is_blocked": user.is_blocked,
+        "is_limited": user.is_limited,
+    })
+}
+
+pub fn post_snapshot(post: &Post) -> Value {
+    json!({
+        "id": post.id.id,
+        "author_id": post.author_id.id,
+        "title": post.title,
+        "description": post.description,
+        "content_url": post.content_url,
+        "visibility": post.visibility,
+        "location_id": post.location_id.as_ref().map(|id| id.id),
+        "created_at": post.created_at,
+        "deleted_at": post.deleted_at,
+    })
+}
+
+pub fn comment_snapshot(comment: &PostComment) -> Value {
+    json!({
+        "id": comment.id.id,
+        "post_id": comment.post_id.id,
+        "user_id": comment.user_id.id,
+        "content": comment.content,
+        "parent_id": comment.parent_id.as_ref().map(|id| id.id),
+        "created_at": comment.created_at,
+        "deleted_at": comment.deleted_at,
+    })
+}
+
+impl From<schema::audit_log::Model> for AuditLogEntry {
+    fn from(model: schema::audit_log::Model) -> Self {
+        Self {
+            id: Id::new(model.id),
+            actor_id: Id::new(model.actor_id),
+            action: match model.action.as_str() {
+                "blockUser" => AuditAction::BlockUser,
+                "unblockUser" => AuditAction::UnblockUser,
+                "limitUser" => AuditAction::LimitUser,
+                "unlimitUser" => AuditAction::UnlimitUser,
+                "deleteUser" => AuditAction::DeleteUser,
+                "changeRole" => AuditAction::ChangeRole,
+                "deletePost" => AuditAction::DeletePost,
+                "deleteComment" => AuditAction::DeleteComment,
+                _ => unreachable!("Invalid audit action received from database"),
+            },
+            target_type: match model.target_type.as_str() {
+                "user" => AuditTargetType::User,
+                "post" => AuditTargetType::Post,
+                "comment" => AuditTargetType::Comment,
+                _ => unreachable!("Invalid audit target type received from database"),
+            },
+            target_id: model.target_id,
+            reason: model.reason,
+            before: model.before,
+            after: model.after,
+            created_at: model.created_at.and_utc(),
+        }
+    }
+}
+
+impl From<AuditLogEntry> for schema::audit_log::Model {
+    fn from(entry: AuditLogEntry) -> Self {
+        Self {
+            id: entry.id.id,
+            actor_id: entry.actor_id.id,
+            action: entry.action.to_string(),
+            target_type: entry.target_type.to_string(),
+            target_id: entry.target_id,
+            reason: entry.reason,
+            before: entry.before,
+            after: entry.after,
+            created_at: entry.created_at.naive_utc(),
+        }
+    }
+}
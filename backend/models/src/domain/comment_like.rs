@@ -0,0 +1,43 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::schema;
+
+use super::{post_comment::PostComment, user::User, Id};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CommentLike {
+    pub comment_id: Id<PostComment>,
+    pub user_id: Id<User>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl CommentLike {
+    pub fn new(comment_id: Id<PostComment>, user_id: Id<User>) -> Self {
+        Self {
+            comment_id,
+            user_id,
+            created_at: Utc::now(),
+        }
+    }
+}
+
+impl From<schema::comment_like::Model> for CommentLike {
+    fn from(model: schema::comment_like::Model) -> Self {
+        Self {
+            comment_id: Id::new(model.comment_id),
+            user_id: Id::new(model.user_id),
+            created_at: model.created_at.and_utc(),
+        }
+    }
+}
+
+impl From<CommentLike> for schema::comment_like::Model {
+    fn from(value: CommentLike) -> Self {
+        Self {
+            comment_id: value.comment_id.id,
+            user_id: value.user_id.id,
+            created_at: value.created_at.naive_utc(),
+        }
+    }
+}
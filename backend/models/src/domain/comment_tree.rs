@@ -0,0 +1,105 @@
+use std::collections::HashMap;
+
+use uuid::Uuid;
+
+use super::{post_comment::PostComment, user::User, Id};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CommentNode {
+    pub comment: PostComment,
+    pub author: User,
+    pub like_count: i64,
+    pub reply_count: usize,
+    pub replies: Vec<CommentNode>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CommentTreeOptions {
+    pub offset: usize,
+    pub limit: usize,
+    /// How many levels of replies below the requested level are expanded
+    pub max_depth: usize,
+    /// How many replies are expanded for every nested comment, the rest is loaded on demand
+    pub replies_limit: usize,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CommentTree {
+    pub comments: Vec<CommentNode>,
+    pub total: usize,
+}
+
+/// Builds a nested tree out of a flat list of comments of a single post.
+///
+/// With `root` set to `None` the top level of the tree are the comments without a parent,
+/// otherwise it is made of the direct replies to `root`. Only the top level is paginated by
+/// `offset` and `limit`, deeper levels are cut at `replies_limit` and `max_depth`, clients can
+/// fetch the rest through the replies of the given comment. Replies whose parent is missing
+/// (e.g. it was deleted) are not reachable and are left out together with their subtree.
+pub fn build_comment_tree(
+    comments: Vec<(PostComment, User, i64)>,
+    root: Option<&Id<PostComment>>,
+    options: &CommentTreeOptions,
+) -> CommentTree {
+    let mut children: HashMap<Option<Uuid>, Vec<(PostComment, User, i64)>> = HashMap::new();
+
+    for (comment, author, like_count) in comments {
+        children
+            .entry(comment.parent_id.as_ref().map(|id| id.id))
+            .or_default()
+            .push((comment, author, like_count));
+    }
+
+    let top_level = children.remove(&root.map(|id| id.id)).unwrap_or_default();
+    let total = top_level.len();
+
+    let comments = top_level
+        .into_iter()
+        .skip(options.offset)
+        .take(options.limit)
+        .map(|(comment, author, like_count)| {
+            build_node(comment, author, like_count, &mut children, 0, options)
+        })
+        .collect();
+
+    CommentTree { comments, total }
+}
+
+fn build_node(
+    comment: PostComment,
+    author: User,
+    like_count: i64,
+    children: &mut HashMap<Option<Uuid>, Vec<(PostComment, User, i64)>>,
+    depth: usize,
+    options: &CommentTreeOptions,
+) -> CommentNode {
+    let replies = children.remove(&Some(comment.id.id)).unwrap_or_default();
+    let reply_count = replies.len();
+
+    let replies = if depth < options.max_depth {
+        replies
+            .into_iter()
+            .take(options.replies_limit)
+            .map(|(reply, reply_author, reply_like_count)| {
+                build_node(
+                    reply,
+                    reply_author,
+                    reply_like_count,
+                    children,
+                    depth + 1,
+                    options,
+                )
+            })
+            .collect()
+    } else {
+        vec![]
+    };
+
+    CommentNode {
+        comment,
+        author,
+        like_count,
+        reply_count,
+        replies,
+    }
+}
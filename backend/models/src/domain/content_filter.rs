@@ -0,0 +1,243 @@
+use std::fmt::Display;
+
+use chrono::{DateTime, Utc};
+use regex::{Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
+use validator::{Validate, ValidationError, ValidationErrors};
+
+use crate::schema;
+
+use super::{user::User, Id};
+
+/// What happens to content matching a filter, from the mildest to the strongest.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ContentFilterAction {
+    /// The match is replaced by asterisks and the content is published
+    Mask,
+    /// The content is kept out of sight until a moderator restores it
+    Hold,
+    /// The content is refused outright
+    Reject,
+}
+
+impl Display for ContentFilterAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Mask => write!(f, "mask"),
+            Self::Hold => write!(f, "hold"),
+            Self::Reject => write!(f, "reject"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ContentFilterKind {
+    /// A literal word or phrase, matched case-insensitively on word boundaries
+    Term,
+    /// A regular expression, matched case-insensitively
+    Pattern,
+}
+
+impl Display for ContentFilterKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Term => write!(f, "term"),
+            Self::Pattern => write!(f, "pattern"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Validate, Deserialize, Serialize)]
+pub struct ContentFilter {
+    pub id: Id<ContentFilter>,
+    #[validate(length(
+        min = 1,
+        max = 200,
+        message = "Pattern must be between 1 and 200 characters"
+    ))]
+    pub pattern: String,
+    pub kind: ContentFilterKind,
+    pub action: ContentFilterAction,
+    pub created_by: Option<Id<User>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ContentFilter {
+    pub fn new(
+        pattern: String,
+        kind: ContentFilterKind,
+        action: ContentFilterAction,
+        created_by: Id<User>,
+    ) -> Result<Self, ValidationErrors> {
+        let filter = Self {
+            id: Id::gen(),
+            pattern: pattern.trim().to_string(),
+            kind,
+            action,
+            created_by: Some(created_by),
+            created_at: Utc::now(),
+        };
+
+        filter.validate()?;
+
+        if filter.regex().is_none() {
+            let mut errors = ValidationErrors::new();
+            errors.add(
+                "pattern",
+                ValidationError::new("Pattern is not a valid regex"),
+            );
+            return Err(errors);
+        }
+
+        Ok(filter)
+    }
+
+    /// Compiled form of the filter, `None` when the pattern is not a valid regex.
+    pub fn regex(&self) -> Option<Regex> {
+        let source = match self.kind {
+            ContentFilterKind::Term => {
+                let escaped = regex::escape(&self.pattern);
+                // Word boundaries only make sense next to word characters,
+                // a term like "c++" would otherwise never match
+                let start = if starts_with_word_char(&self.pattern) {
+                    r"\b"
+                } else {
+                    ""
+                };
+                let end = if ends_with_word_char(&self.pattern) {
+                    r"\b"
+                } else {
+                    ""
+                };
+                format!("{start}{escaped}{end}")
+            }
+            ContentFilterKind::Pattern => self.pattern.clone(),
+        };
+
+        RegexBuilder::new(&source)
+            .case_insensitive(true)
+            .size_limit(1 << 20)
+            .build()
+            .ok()
+    }
+}
+
+fn starts_with_word_char(text: &str) -> bool {
+    text.chars()
+        .next()
+        .is_some_and(|c| c.is_alphanumeric() || c == '_')
+}
+
+fn ends_with_word_char(text: &str) -> bool {
+    text.chars()
+        .last()
+        .is_some_and(|c| c.is_alphanumeric() || c == '_')
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContentFilterHit {
+    pub filter_id: Id<ContentFilter>,
+    pub action: ContentFilterAction,
+    pub matched: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct FilteredText {
+    /// The text with every match of a masking filter replaced by asterisks
+    pub text: String,
+    pub hits: Vec<ContentFilterHit>,
+}
+
+impl FilteredText {
+    /// The strongest action among the hits, `None` when nothing matched.
+    pub fn action(&self) -> Option<ContentFilterAction> {
+        self.hits.iter().map(|hit| hit.action).max()
+    }
+}
+
+/// Filters with their patterns compiled, built once per set of filters and reused
+/// for every text checked against it.
+#[derive(Clone, Debug)]
+pub struct CompiledContentFilters {
+    filters: Vec<(ContentFilter, Regex)>,
+}
+
+impl CompiledContentFilters {
+    /// Compiles the filters, skipping any whose pattern is not a valid regex.
+    pub fn new(filters: Vec<ContentFilter>) -> Self {
+        Self {
+            filters: filters
+                .into_iter()
+                .filter_map(|filter| filter.regex().map(|regex| (filter, regex)))
+                .collect(),
+        }
+    }
+
+    /// Runs the text through every filter. Only masking filters change the text,
+    /// holding and rejecting are left to the caller.
+    pub fn apply(&self, text: &str) -> FilteredText {
+        let mut masked = text.to_string();
+        let mut hits = Vec::new();
+
+        for (filter, regex) in &self.filters {
+            for found in regex.find_iter(text) {
+                if found.as_str().is_empty() {
+                    continue;
+                }
+
+                hits.push(ContentFilterHit {
+                    filter_id: filter.id.clone(),
+                    action: filter.action,
+                    matched: found.as_str().to_string(),
+                });
+            }
+
+            if filter.action == ContentFilterAction::Mask {
+                masked = regex
+                    .replace_all(&masked, |captures: &regex::Captures| {
+                        "*".repeat(captures[0].chars().count())
+                    })
+                    .into_owned();
+            }
+        }
+
+        FilteredText { text: masked, hits }
+    }
+}
+
+impl From<schema::content_filter::Model> for ContentFilter {
+    fn from(model: schema::content_filter::Model) -> Self {
+        Self {
+            id: Id::new(model.id),
+            pattern: model.pattern,
+            kind: match model.kind.as_str() {
+                "term" => ContentFilterKind::Term,
+                "pattern" => ContentFilterKind::Pattern,
+                _ => unreachable!("Invalid content filter kind received from database"),
+            },
+            action: match model.action.as_str() {
+                "mask" => ContentFilterAction::Mask,
+                "hold" => ContentFilterAction::Hold,
+                "reject" => ContentFilterAction::Reject,
+                _ => unreachable!("Invalid content filter action received from database"),
+            },
+            created_by: model.created_by.map(Id::new),
+            created_at: model.created_at.and_utc(),
+        }
+    }
+}
+
+impl From<ContentFilter> for schema::content_filter::Model {
+    fn from(filter: ContentFilter) -> Self {
+        Self {
+            id: filter.id.id,
+            pattern: filter.pattern,
+            kind: filter.kind.to_string(),
+            action: filter.action.to_string(),
+            created_by: filter.created_by.map(|id| id.id),
+            created_at: filter.created_at.naive_utc(),
+        }
+    }
+}
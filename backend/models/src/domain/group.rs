@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use once_cell::sync::Lazy;
 use regex::Regex;
 use validator::{Validate, ValidationErrors};
@@ -19,6 +20,7 @@ pub struct Group {
     pub name: String,
     pub admin_id: Id<User>,
     pub wall_id: Id<Wall>,
+    pub created_at: DateTime<Utc>,
 }
 
 impl Group {
@@ -32,6 +34,7 @@ impl Group {
             name,
             admin_id,
             wall_id,
+            created_at: Utc::now(),
         };
 
         model.validate()?;
@@ -47,6 +50,7 @@ impl From<schema::group::Model> for Group {
             name: model.name,
             admin_id: Id::new(model.admin_id),
             wall_id: Id::new(model.wall_id),
+            created_at: model.created_at.and_utc(),
         }
     }
 }
@@ -58,6 +62,7 @@ impl From<Group> for schema::group::Model {
             name: model.name,
             admin_id: model.admin_id.id,
             wall_id: model.wall_id.id,
+            created_at: model.created_at.naive_utc(),
         }
     }
 }
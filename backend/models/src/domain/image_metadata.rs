@@ -0,0 +1,254 @@
+use std::io::Cursor;
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use chrono::NaiveDateTime;
+use exif::{Exif, In, Reader, Tag, Value};
+use validator::{ValidationError, ValidationErrors};
+
+use super::location::GeoPoint;
+
+const EXIF_MIME_TYPES: [&str; 4] = ["image/jpeg", "image/jpg", "image/heic", "image/heif"];
+const HEIF_MIME_TYPES: [&str; 2] = ["image/heic", "image/heif"];
+
+const JPEG_SOI: [u8; 2] = [0xFF, 0xD8];
+const JPEG_APP1: u8 = 0xE1;
+const JPEG_APP13: u8 = 0xED;
+const JPEG_COM: u8 = 0xFE;
+const JPEG_SOS: u8 = 0xDA;
+const JPEG_EOI: u8 = 0xD9;
+
+/// Image uploaded by a client as a `data:<mime type>;base64,<data>` URL.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DataUrlImage {
+    pub mime_type: String,
+    pub bytes: Vec<u8>,
+}
+
+impl DataUrlImage {
+    pub fn parse(data_url: &str) -> Result<Self, ValidationErrors> {
+        let parsed = data_url
+            .strip_prefix("data:")
+            .and_then(|rest| rest.split_once(";base64,"))
+            .and_then(|(mime_type, data)| {
+                STANDARD
+                    .decode(data.trim())
+                    .ok()
+                    .map(|bytes| (mime_type.to_lowercase(), bytes))
+            });
+
+        match parsed {
+            Some((mime_type, bytes)) if mime_type.starts_with("image/") => {
+                Ok(Self { mime_type, bytes })
+            }
+            _ => {
+                let mut errors = ValidationErrors::new();
+                errors.add("image", ValidationError::new("Invalid image data URL"));
+                Err(errors)
+            }
+        }
+    }
+
+    /// Whether the image is of a format we read EXIF from, that is JPEG or HEIC.
+    pub fn has_exif_format(&self) -> bool {
+        EXIF_MIME_TYPES.contains(&self.mime_type.as_str())
+    }
+
+    pub fn is_heif(&self) -> bool {
+        HEIF_MIME_TYPES.contains(&self.mime_type.as_str())
+    }
+}
+
+/// Details read from the EXIF of an image.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ImageMetadata {
+    /// Local time of the camera, EXIF does not need to say which time zone it was in
+    pub captured_at: Option<NaiveDateTime>,
+    pub location: Option<GeoPoint>,
+    /// EXIF orientation, 1 to 8
+    pub orientation: Option<u16>,
+}
+
+impl ImageMetadata {
+    /// Reads the metadata of the image, images without readable EXIF have none.
+    pub fn read(bytes: &[u8]) -> Self {
+        let Some(exif) = read_exif(bytes) else {
+            return Self::default();
+        };
+
+        let captured_at = [Tag::DateTimeOriginal, Tag::DateTime]
+            .into_iter()
+            .find_map(|tag| parse_date_time(&exif, tag));
+
+        let latitude = parse_gps_coordinate(&exif, Tag::GPSLatitude, Tag::GPSLatitudeRef, b'S');
+        let longitude = parse_gps_coordinate(&exif, Tag::GPSLongitude, Tag::GPSLongitudeRef, b'W');
+        let location = latitude
+            .zip(longitude)
+            .and_then(|(latitude, longitude)| GeoPoint::new(latitude, longitude).ok());
+
+        let orientation = exif
+            .get_field(Tag::Orientation, In::PRIMARY)
+            .and_then(|field| field.value.get_uint(0))
+            .and_then(|orientation| u16::try_from(orientation).ok())
+            .filter(|orientation| (1..=8).contains(orientation));
+
+        Self {
+            captured_at,
+            location,
+            orientation,
+        }
+    }
+}
+
+/// Removes EXIF, XMP, IPTC and comments from the image, they may contain the GPS position,
+/// camera serial numbers or the author. Only the orientation is kept, so the image is still
+/// displayed the right way up.
+///
+/// JPEG segments are dropped altogether. Other containers (HEIC) have their EXIF overwritten
+/// in place, because its size is referenced from elsewhere in the file.
+pub fn strip_identifying_metadata(
+    bytes: &[u8],
+    orientation: Option<u16>,
+) -> Result<Vec<u8>, ValidationErrors> {
+    if bytes.starts_with(&JPEG_SOI) {
+        return strip_jpeg_metadata(bytes, orientation).ok_or_else(|| {
+            let mut errors = ValidationErrors::new();
+            errors.add("image", ValidationError::new("Invalid JPEG image"));
+            errors
+        });
+    }
+
+    let Some(exif) = read_exif(bytes) else {
+        return Ok(bytes.to_vec());
+    };
+
+    let tiff = exif.buf();
+    let mut stripped = bytes.to_vec();
+
+    if let Some(start) = bytes.windows(tiff.len()).position(|window| window == tiff) {
+        let mut replacement = orientation_tiff(orientation);
+        replacement.resize(tiff.len(), 0);
+
+        stripped[start..start + tiff.len()].copy_from_slice(&replacement[..tiff.len()]);
+    }
+
+    Ok(stripped)
+}
+
+fn strip_jpeg_metadata(bytes: &[u8], orientation: Option<u16>) -> Option<Vec<u8>> {
+    let mut stripped = JPEG_SOI.to_vec();
+
+    if orientation.is_some() {
+        let tiff = orientation_tiff(orientation);
+        let length = u16::try_from(2 + 6 + tiff.len()).ok()?;
+
+        stripped.extend([0xFF, JPEG_APP1]);
+        stripped.extend(length.to_be_bytes());
+        stripped.extend(b"Exif\0\0");
+        stripped.extend(tiff);
+    }
+
+    let mut position = JPEG_SOI.len();
+
+    loop {
+        if bytes.get(position) != Some(&0xFF) {
+            return None;
+        }
+
+        let marker = *bytes.get(position + 1)?;
+
+        // Markers may be preceded by any number of fill bytes
+        if marker == 0xFF {
+            position += 1;
+            continue;
+        }
+
+        // Metadata only comes before the image data, the rest is copied as it is
+        if marker == JPEG_SOS || marker == JPEG_EOI {
+            stripped.extend(&bytes[position..]);
+            return Some(stripped);
+        }
+
+        let length = u16::from_be_bytes([*bytes.get(position + 2)?, *bytes.get(position + 3)?]);
+        let end = position + 2 + usize::from(length);
+        let segment = bytes.get(position..end)?;
+
+        if !matches!(marker, JPEG_APP1 | JPEG_APP13 | JPEG_COM) {
+            stripped.extend(segment);
+        }
+
+        position = end;
+    }
+}
+
+/// Little endian TIFF structure with a single IFD holding just the orientation.
+fn orientation_tiff(orientation: Option<u16>) -> Vec<u8> {
+    let Some(orientation) = orientation else {
+        return vec![];
+    };
+
+    let mut tiff = b"II\x2A\x00".to_vec();
+    tiff.extend(8u32.to_le_bytes());
+    tiff.extend(1u16.to_le_bytes());
+    // Orientation tag, SHORT type, a single value stored inline
+    tiff.extend(0x0112u16.to_le_bytes());
+    tiff.extend(3u16.to_le_bytes());
+    tiff.extend(1u32.to_le_bytes());
+    tiff.extend(orientation.to_le_bytes());
+    tiff.extend([0, 0]);
+    // No further IFDs
+    tiff.extend(0u32.to_le_bytes());
+
+    tiff
+}
+
+fn read_exif(bytes: &[u8]) -> Option<Exif> {
+    let result = Reader::new()
+        .continue_on_error(true)
+        .read_from_container(&mut Cursor::new(bytes));
+
+    match result {
+        Ok(exif) => Some(exif),
+        Err(exif::Error::PartialResult(partial)) => Some(partial.into_inner().0),
+        Err(_) => None,
+    }
+}
+
+fn parse_date_time(exif: &Exif, tag: Tag) -> Option<NaiveDateTime> {
+    let field = exif.get_field(tag, In::PRIMARY)?;
+
+    let Value::Ascii(ref values) = field.value else {
+        return None;
+    };
+
+    let value = std::str::from_utf8(values.first()?).ok()?;
+
+    NaiveDateTime::parse_from_str(value.trim_end_matches('\0'), "%Y:%m:%d %H:%M:%S").ok()
+}
+
+fn parse_gps_coordinate(exif: &Exif, tag: Tag, ref_tag: Tag, negative_ref: u8) -> Option<f64> {
+    let field = exif.get_field(tag, In::PRIMARY)?;
+
+    let Value::Rational(ref parts) = field.value else {
+        return None;
+    };
+
+    let [degrees, minutes, seconds] = parts.get(..3)? else {
+        return None;
+    };
+
+    let coordinate = degrees.to_f64() + minutes.to_f64() / 60.0 + seconds.to_f64() / 3600.0;
+
+    if !coordinate.is_finite() {
+        return None;
+    }
+
+    let is_negative = exif
+        .get_field(ref_tag, In::PRIMARY)
+        .and_then(|field| match field.value {
+            Value::Ascii(ref values) => values.first().and_then(|value| value.first().copied()),
+            _ => None,
+        })
+        .is_some_and(|reference| reference == negative_ref);
+
+    Some(if is_negative { -coordinate } else { coordinate })
+}
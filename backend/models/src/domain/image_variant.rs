@@ -0,0 +1,208 @@
+use std::io::Cursor;
+
+use image::{imageops::FilterType, metadata::Orientation, DynamicImage, ImageReader};
+use serde::{Deserialize, Serialize};
+use validator::{Validate, ValidationError, ValidationErrors};
+
+pub const DEFAULT_MAX_IMAGE_BYTES: usize = 10 * 1024 * 1024;
+pub const DEFAULT_MAX_IMAGE_PIXELS: u64 = 40_000_000;
+
+const WEBP_QUALITY: f32 = 80.0;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImageVariantKind {
+    Thumbnail,
+    Feed,
+    Full,
+}
+
+impl ImageVariantKind {
+    /// From the largest to the smallest, each variant is resized from the previous one
+    pub const ALL: [Self; 3] = [Self::Full, Self::Feed, Self::Thumbnail];
+
+    /// Longest side of the variant in pixels, smaller images are never upscaled
+    pub fn max_dimension(&self) -> u32 {
+        match self {
+            Self::Thumbnail => 320,
+            Self::Feed => 1080,
+            Self::Full => 2048,
+        }
+    }
+}
+
+/// A resized copy of a post image, together they make up the `srcset` of the image.
+#[derive(Clone, Debug, PartialEq, Validate, Deserialize, Serialize)]
+pub struct ImageVariant {
+    pub kind: ImageVariantKind,
+    #[validate(range(min = 1, message = "Width must be positive"))]
+    pub width: u32,
+    #[validate(range(min = 1, message = "Height must be positive"))]
+    pub height: u32,
+    #[validate(url)]
+    pub url: String,
+}
+
+impl ImageVariant {
+    pub fn new(
+        kind: ImageVariantKind,
+        width: u32,
+        height: u32,
+        url: String,
+    ) -> Result<Self, ValidationErrors> {
+        let variant = Self {
+            kind,
+            width,
+            height,
+            url,
+        };
+
+        variant.validate()?;
+
+        Ok(variant)
+    }
+}
+
+/// Uploads above either of the limits are rejected before they are decoded.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ImageLimits {
+    pub max_bytes: usize,
+    pub max_pixels: u64,
+}
+
+impl Default for ImageLimits {
+    fn default() -> Self {
+        Self {
+            max_bytes: DEFAULT_MAX_IMAGE_BYTES,
+            max_pixels: DEFAULT_MAX_IMAGE_PIXELS,
+        }
+    }
+}
+
+impl ImageLimits {
+    pub fn check_size(&self, bytes: usize) -> Result<(), ValidationErrors> {
+        if bytes > self.max_bytes {
+            return Err(image_error("Image is too large"));
+        }
+
+        Ok(())
+    }
+
+    pub fn check_dimensions(&self, width: u32, height: u32) -> Result<(), ValidationErrors> {
+        if u64::from(width) * u64::from(height) > self.max_pixels {
+            return Err(image_error("Image has too many pixels"));
+        }
+
+        Ok(())
+    }
+}
+
+/// A variant encoded as WebP, not stored anywhere yet.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProcessedImage {
+    pub kind: ImageVariantKind,
+    pub width: u32,
+    pub height: u32,
+    pub bytes: Vec<u8>,
+}
+
+impl ProcessedImage {
+    pub const CONTENT_TYPE: &'static str = "image/webp";
+}
+
+/// Size of the image scaled down so that its longest side fits into `max_dimension`.
+pub fn variant_dimensions(width: u32, height: u32, max_dimension: u32) -> (u32, u32) {
+    let longest = width.max(height);
+
+    if longest <= max_dimension {
+        return (width, height);
+    }
+
+    let scale = |side: u32| {
+        ((u64::from(side) * u64::from(max_dimension) + u64::from(longest) / 2) / u64::from(longest))
+            .max(1) as u32
+    };
+
+    (scale(width), scale(height))
+}
+
+/// Whether the image is in a format `process_image` can decode. Others (HEIC) are stored
+/// as they were uploaded, without variants.
+pub fn can_process_image(bytes: &[u8]) -> bool {
+    image_reader(bytes)
+        .ok()
+        .and_then(|reader| reader.format())
+        .is_some_and(|format| format.reading_enabled())
+}
+
+/// Decodes the image, turns it the right way up by its EXIF orientation and encodes every
+/// variant as WebP. The work is CPU heavy, async callers should run it on a blocking thread.
+pub fn process_image(
+    bytes: &[u8],
+    orientation: Option<u16>,
+    limits: &ImageLimits,
+) -> Result<Vec<ProcessedImage>, ValidationErrors> {
+    limits.check_size(bytes.len())?;
+
+    // Only the header is read here, so huge images are not decoded at all
+    let (width, height) = image_reader(bytes)?
+        .into_dimensions()
+        .map_err(|_| image_error("Invalid image"))?;
+
+    limits.check_dimensions(width, height)?;
+
+    let mut image = image_reader(bytes)?
+        .decode()
+        .map_err(|_| image_error("Invalid image"))?;
+
+    if let Some(orientation) = orientation
+        .and_then(|orientation| u8::try_from(orientation).ok())
+        .and_then(Orientation::from_exif)
+    {
+        image.apply_orientation(orientation);
+    }
+
+    let mut variants = Vec::with_capacity(ImageVariantKind::ALL.len());
+
+    for kind in ImageVariantKind::ALL {
+        let (width, height) =
+            variant_dimensions(image.width(), image.height(), kind.max_dimension());
+
+        if (width, height) != (image.width(), image.height()) {
+            image = image.resize_exact(width, height, FilterType::Lanczos3);
+        }
+
+        variants.push(ProcessedImage {
+            kind,
+            width,
+            height,
+            bytes: encode_webp(&image),
+        });
+    }
+
+    Ok(variants)
+}
+
+fn encode_webp(image: &DynamicImage) -> Vec<u8> {
+    let encoded = if image.color().has_alpha() {
+        let rgba = image.to_rgba8();
+        webp::Encoder::from_rgba(&rgba, image.width(), image.height()).encode(WEBP_QUALITY)
+    } else {
+        let rgb = image.to_rgb8();
+        webp::Encoder::from_rgb(&rgb, image.width(), image.height()).encode(WEBP_QUALITY)
+    };
+
+    encoded.to_vec()
+}
+
+fn image_reader(bytes: &[u8]) -> Result<ImageReader<Cursor<&[u8]>>, ValidationErrors> {
+    ImageReader::new(Cursor::new(bytes))
+        .with_guessed_format()
+        .map_err(|_| image_error("Unsupported image format"))
+}
+
+fn image_error(message: &'static str) -> ValidationErrors {
+    let mut errors = ValidationErrors::new();
+    errors.add("image", ValidationError::new(message));
+    errors
+}
@@ -1,4 +1,5 @@
-use validator::{Validate, ValidationErrors};
+use unicode_normalization::{char::is_combining_mark, UnicodeNormalization};
+use validator::{Validate, ValidationError, ValidationErrors};
 
 use crate::schema;
 
@@ -10,7 +11,17 @@ pub struct Location {
     #[validate(url)]
     pub picture_url: Option<String>,
     pub name: String,
+    #[validate(range(
+        min = -90.0,
+        max = 90.0,
+        message = "Latitude must be between -90 and 90 degrees"
+    ))]
     pub latitude: f64,
+    #[validate(range(
+        min = -180.0,
+        max = 180.0,
+        message = "Longitude must be between -180 and 180 degrees"
+    ))]
     pub longitude: f64,
 }
 
@@ -33,6 +44,179 @@ impl Location {
 
         Ok(location)
     }
+
+    pub fn point(&self) -> GeoPoint {
+        GeoPoint {
+            latitude: self.latitude,
+            longitude: self.longitude,
+        }
+    }
+
+    /// Whether both locations most likely describe the same place, e.g. "Brno" and "Brno, CZ"
+    /// a few metres apart.
+    pub fn is_likely_duplicate_of(&self, other: &Location) -> bool {
+        name_similarity(&self.name, &other.name) >= DUPLICATE_NAME_SIMILARITY
+            && self.point().distance_km(&other.point()) <= DUPLICATE_DISTANCE_KM
+    }
+}
+
+/// Minimal [`name_similarity`] of two locations to be reported as duplicates.
+pub const DUPLICATE_NAME_SIMILARITY: f64 = 0.8;
+/// Maximal distance of two locations to be reported as duplicates.
+pub const DUPLICATE_DISTANCE_KM: f64 = 1.0;
+
+/// Similarity of names which differ only by trailing words, e.g. "Brno" and "Brno, CZ".
+const NAME_PREFIX_SIMILARITY: f64 = 0.9;
+
+/// Lowercases the name, strips diacritics and punctuation and collapses whitespace,
+/// so "Brno, CZ" becomes "brno cz".
+pub fn normalize_location_name(name: &str) -> String {
+    let name: String = name
+        .nfd()
+        .filter(|c| !is_combining_mark(*c))
+        .flat_map(char::to_lowercase)
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .collect();
+
+    name.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Similarity of two location names between 0 and 1, names are compared normalized
+/// (see [`normalize_location_name`]) by their edit distance.
+pub fn name_similarity(a: &str, b: &str) -> f64 {
+    let a = normalize_location_name(a);
+    let b = normalize_location_name(b);
+
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+
+    if a == b {
+        return 1.0;
+    }
+
+    let (shorter, longer) = if a.len() <= b.len() {
+        (&a, &b)
+    } else {
+        (&b, &a)
+    };
+
+    if longer.starts_with(&format!("{} ", shorter)) {
+        return NAME_PREFIX_SIMILARITY;
+    }
+
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    1.0 - levenshtein(&a, &b) as f64 / a.len().max(b.len()) as f64
+}
+
+fn levenshtein(a: &[char], b: &[char]) -> usize {
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, a_char) in a.iter().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != b_char);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
+/// Mean radius of the Earth used for great-circle distances.
+pub const EARTH_RADIUS_KM: f64 = 6371.0;
+
+#[derive(Clone, Copy, Debug, PartialEq, Validate)]
+pub struct GeoPoint {
+    #[validate(range(
+        min = -90.0,
+        max = 90.0,
+        message = "Latitude must be between -90 and 90 degrees"
+    ))]
+    pub latitude: f64,
+    #[validate(range(
+        min = -180.0,
+        max = 180.0,
+        message = "Longitude must be between -180 and 180 degrees"
+    ))]
+    pub longitude: f64,
+}
+
+impl GeoPoint {
+    pub fn new(latitude: f64, longitude: f64) -> Result<Self, ValidationErrors> {
+        let point = Self {
+            latitude,
+            longitude,
+        };
+
+        point.validate()?;
+
+        Ok(point)
+    }
+
+    /// Great-circle distance to the other point computed by the haversine formula.
+    pub fn distance_km(&self, other: &GeoPoint) -> f64 {
+        let delta_latitude = (other.latitude - self.latitude).to_radians();
+        let delta_longitude = (other.longitude - self.longitude).to_radians();
+
+        let a = (delta_latitude / 2.0).sin().powi(2)
+            + self.latitude.to_radians().cos()
+                * other.latitude.to_radians().cos()
+                * (delta_longitude / 2.0).sin().powi(2);
+
+        2.0 * EARTH_RADIUS_KM * a.sqrt().asin()
+    }
+}
+
+/// Area of a map view. When `west` is greater than `east` the box crosses the antimeridian.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BoundingBox {
+    pub south: f64,
+    pub west: f64,
+    pub north: f64,
+    pub east: f64,
+}
+
+impl BoundingBox {
+    pub fn new(south: f64, west: f64, north: f64, east: f64) -> Result<Self, ValidationErrors> {
+        GeoPoint::new(south, west)?;
+        GeoPoint::new(north, east)?;
+
+        if south > north {
+            let mut errors = ValidationErrors::new();
+            errors.add(
+                "south",
+                ValidationError::new("South edge must not be above the north edge"),
+            );
+            return Err(errors);
+        }
+
+        Ok(Self {
+            south,
+            west,
+            north,
+            east,
+        })
+    }
+
+    pub fn crosses_antimeridian(&self) -> bool {
+        self.west > self.east
+    }
+
+    pub fn contains(&self, point: &GeoPoint) -> bool {
+        let within_longitude = if self.crosses_antimeridian() {
+            point.longitude >= self.west || point.longitude <= self.east
+        } else {
+            point.longitude >= self.west && point.longitude <= self.east
+        };
+
+        point.latitude >= self.south && point.latitude <= self.north && within_longitude
+    }
 }
 
 impl From<schema::location::Model> for Location {
@@ -0,0 +1,150 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::schema;
+
+use super::{post::Post, post_comment::PostComment, user::User, Id};
+
+const MIN_USERNAME_LENGTH: usize = 3;
+const MAX_USERNAME_LENGTH: usize = 15;
+
+/// A `@username` occurrence in a text. `start` and `end` are character offsets (not bytes),
+/// `start` points at the `@` and `end` is exclusive.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MentionSpan {
+    pub username: String,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Finds all `@username` mentions in a text.
+///
+/// The `@` has to be at the start of the text or follow a character which can't be part of
+/// a username, so e-mail addresses are not mentions. Names not matching the username rules
+/// (alphanumeric or underscore, 3 to 15 characters) are ignored.
+pub fn parse_mentions(text: &str) -> Vec<MentionSpan> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut mentions = vec![];
+    let mut i = 0;
+
+    while i < chars.len() {
+        let starts_mention =
+            chars[i] == '@' && (i == 0 || !is_username_char(chars[i - 1]) && chars[i - 1] != '@');
+
+        if !starts_mention {
+            i += 1;
+            continue;
+        }
+
+        let end = chars[i + 1..]
+            .iter()
+            .position(|c| !is_username_char(*c))
+            .map(|len| i + 1 + len)
+            .unwrap_or(chars.len());
+        let length = end - i - 1;
+
+        if (MIN_USERNAME_LENGTH..=MAX_USERNAME_LENGTH).contains(&length) {
+            mentions.push(MentionSpan {
+                username: chars[i + 1..end].iter().collect(),
+                start: i,
+                end,
+            });
+        }
+
+        i = end.max(i + 1);
+    }
+
+    mentions
+}
+
+/// Usernames mentioned in a text, every username only once in the order of the first mention.
+pub fn mentioned_usernames(text: &str) -> Vec<String> {
+    let mut usernames: Vec<String> = vec![];
+
+    for mention in parse_mentions(text) {
+        if !usernames.contains(&mention.username) {
+            usernames.push(mention.username);
+        }
+    }
+
+    usernames
+}
+
+/// Spans of the mentions in a text which were stored for the comment, or for the post
+/// description when `comment_id` is `None`. Unknown usernames and users the mention was
+/// dropped for are not highlighted.
+pub fn stored_mention_spans(
+    text: &str,
+    comment_id: Option<&Id<PostComment>>,
+    mentions: &[(Mention, User)],
+) -> Vec<MentionSpan> {
+    let usernames = mentions
+        .iter()
+        .filter(|(mention, _)| mention.comment_id.as_ref() == comment_id)
+        .map(|(_, user)| user.username.as_str())
+        .collect::<Vec<_>>();
+
+    parse_mentions(text)
+        .into_iter()
+        .filter(|span| usernames.contains(&span.username.as_str()))
+        .collect()
+}
+
+fn is_username_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+/// A user mentioned in a post description or, with `comment_id` set, in a comment.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Mention {
+    pub id: Id<Mention>,
+    pub post_id: Id<Post>,
+    pub comment_id: Option<Id<PostComment>>,
+    pub user_id: Id<User>,
+    pub author_id: Id<User>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Mention {
+    pub fn new(
+        post_id: Id<Post>,
+        comment_id: Option<Id<PostComment>>,
+        user_id: Id<User>,
+        author_id: Id<User>,
+    ) -> Self {
+        Self {
+            id: Id::gen(),
+            post_id,
+            comment_id,
+            user_id,
+            author_id,
+            created_at: Utc::now(),
+        }
+    }
+}
+
+impl From<schema::mention::Model> for Mention {
+    fn from(model: schema::mention::Model) -> Self {
+        Self {
+            id: Id::new(model.id),
+            post_id: Id::new(model.post_id),
+            comment_id: model.comment_id.map(Id::new),
+            user_id: Id::new(model.user_id),
+            author_id: Id::new(model.author_id),
+            created_at: model.created_at.and_utc(),
+        }
+    }
+}
+
+impl From<Mention> for schema::mention::Model {
+    fn from(value: Mention) -> Self {
+        Self {
+            id: value.id.id,
+            post_id: value.post_id.id,
+            comment_id: value.comment_id.map(|id| id.id),
+            user_id: value.user_id.id,
+            author_id: value.author_id.id,
+            created_at: value.created_at.naive_utc(),
+        }
+    }
+}
@@ -4,18 +4,34 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use validator::{ValidationError, ValidationErrors};
 
+pub mod audit_log;
+pub mod comment_like;
+pub mod comment_tree;
+pub mod content_filter;
 pub mod email;
 pub mod group;
 pub mod group_join_request;
 pub mod group_member;
+pub mod image_metadata;
+pub mod image_variant;
 pub mod location;
+pub mod mention;
+pub mod notification;
 pub mod post;
 pub mod post_comment;
 pub mod post_group_visibility;
 pub mod post_like;
 pub mod post_tag;
 pub mod post_user_visibility;
+pub mod reaction;
+pub mod report;
+pub mod saved_collection;
+pub mod saved_post;
+pub mod stats;
+pub mod tag_follow;
 pub mod user;
+pub mod user_relation;
+pub mod user_suspension;
 pub mod wall;
 pub mod wall_post;
 
@@ -0,0 +1,92 @@
+use std::fmt::Display;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::schema;
+
+use super::{post::Post, post_comment::PostComment, user::User, Id};
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub enum NotificationKind {
+    #[serde(rename = "mention")]
+    Mention,
+}
+
+impl Display for NotificationKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NotificationKind::Mention => write!(f, "mention"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Notification {
+    pub id: Id<Notification>,
+    pub user_id: Id<User>,
+    pub actor_id: Id<User>,
+    pub kind: NotificationKind,
+    pub post_id: Option<Id<Post>>,
+    pub comment_id: Option<Id<PostComment>>,
+    pub created_at: DateTime<Utc>,
+    pub read_at: Option<DateTime<Utc>>,
+}
+
+impl Notification {
+    pub fn new(
+        user_id: Id<User>,
+        actor_id: Id<User>,
+        kind: NotificationKind,
+        post_id: Option<Id<Post>>,
+        comment_id: Option<Id<PostComment>>,
+    ) -> Self {
+        Self {
+            id: Id::gen(),
+            user_id,
+            actor_id,
+            kind,
+            post_id,
+            comment_id,
+            created_at: Utc::now(),
+            read_at: None,
+        }
+    }
+
+    pub fn is_read(&self) -> bool {
+        self.read_at.is_some()
+    }
+}
+
+impl From<schema::notification::Model> for Notification {
+    fn from(model: schema::notification::Model) -> Self {
+        Self {
+            id: Id::new(model.id),
+            user_id: Id::new(model.user_id),
+            actor_id: Id::new(model.actor_id),
+            kind: match model.kind.as_str() {
+                "mention" => NotificationKind::Mention,
+                _ => unreachable!("Invalid notification kind received from database"),
+            },
+            post_id: model.post_id.map(Id::new),
+            comment_id: model.comment_id.map(Id::new),
+            created_at: model.created_at.and_utc(),
+            read_at: model.read_at.map(|date| date.and_utc()),
+        }
+    }
+}
+
+impl From<Notification> for schema::notification::Model {
+    fn from(value: Notification) -> Self {
+        Self {
+            id: value.id.id,
+            user_id: value.user_id.id,
+            actor_id: value.actor_id.id,
+            kind: value.kind.to_string(),
+            post_id: value.post_id.map(|id| id.id),
+            comment_id: value.comment_id.map(|id| id.id),
+            created_at: value.created_at.naive_utc(),
+            read_at: value.read_at.map(|date| date.naive_utc()),
+        }
+    }
+}
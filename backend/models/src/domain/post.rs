@@ -8,7 +8,7 @@ use validator::{Validate, ValidationErrors};
 
 use crate::schema;
 
-use super::{user::User, wall::Wall, Id};
+use super::{image_variant::ImageVariant, user::User, wall::Wall, Id};
 
 static RE_TITLE: Lazy<Regex> = Lazy::new(|| Regex::new(r"^[a-zA-Z0-9_]+$").unwrap());
 
@@ -62,6 +62,28 @@ impl From<String> for PostVisibilityType {
     }
 }
 
+/// Who may comment on a post, among the users who can see it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CommentPolicy {
+    #[default]
+    Everyone,
+    /// Users following the author, and the author
+    Followers,
+    /// Comments are turned off
+    Nobody,
+}
+
+impl Display for CommentPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Everyone => write!(f, "everyone"),
+            Self::Followers => write!(f, "followers"),
+            Self::Nobody => write!(f, "nobody"),
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Validate, Deserialize, Serialize)]
 pub struct Post {
     pub id: Id<Post>,
@@ -84,6 +106,16 @@ pub struct Post {
     pub visibility: PostVisibilityType,
     pub location_id: Option<Id<Wall>>,
     pub created_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+    pub deleted_by: Option<Id<User>>,
+    #[validate(
+        nested,
+        length(max = 3, message = "A post has at most 3 image variants")
+    )]
+    pub image_variants: Vec<ImageVariant>,
+    pub comment_policy: CommentPolicy,
+    /// Set while the post waits in the trash for a moderator after hitting a content filter
+    pub held: bool,
 }
 
 impl Post {
@@ -106,12 +138,44 @@ impl Post {
             visibility,
             location_id,
             created_at: Utc::now(),
+            deleted_at: None,
+            deleted_by: None,
+            image_variants: vec![],
+            comment_policy: CommentPolicy::default(),
+            held: false,
         };
 
         model.validate()?;
 
         Ok(model)
     }
+
+    pub fn with_image_variants(
+        mut self,
+        image_variants: Vec<ImageVariant>,
+    ) -> Result<Self, ValidationErrors> {
+        self.image_variants = image_variants;
+        self.validate()?;
+
+        Ok(self)
+    }
+
+    /// Whether the user may comment under the post's comment policy,
+    /// `is_follower` tells whether the user follows the author.
+    pub fn accepts_comments_from(&self, user_id: &Id<User>, is_follower: bool) -> bool {
+        match self.comment_policy {
+            CommentPolicy::Everyone => true,
+            CommentPolicy::Followers => &self.author_id == user_id || is_follower,
+            CommentPolicy::Nobody => false,
+        }
+    }
+
+    /// Moves the post to the trash without a deleter, so only a moderator can restore it.
+    pub fn hold_for_review(&mut self) {
+        self.deleted_at = Some(Utc::now());
+        self.deleted_by = None;
+        self.held = true;
+    }
 }
 
 impl From<schema::post::Model> for Post {
@@ -133,6 +197,16 @@ impl From<schema::post::Model> for Post {
             },
             location_id: model.location_id.map(|id| Id::new(id)),
             created_at: model.created_at.and_utc(),
+            deleted_at: model.deleted_at.map(|date| date.and_utc()),
+            deleted_by: model.deleted_by.map(Id::new),
+            image_variants: serde_json::from_value(model.image_variants).unwrap_or_default(),
+            comment_policy: match model.comment_policy.as_str() {
+                "everyone" => CommentPolicy::Everyone,
+                "followers" => CommentPolicy::Followers,
+                "nobody" => CommentPolicy::Nobody,
+                _ => unreachable!("Invalid comment policy received from database"),
+            },
+            held: model.held,
         }
     }
 }
@@ -154,6 +228,12 @@ impl From<Post> for schema::post::Model {
             },
             location_id: value.location_id.map(|id| id.id),
             created_at: value.created_at.naive_utc(),
+            deleted_at: value.deleted_at.map(|date| date.naive_utc()),
+            deleted_by: value.deleted_by.map(|id| id.id),
+            image_variants: serde_json::to_value(value.image_variants)
+                .unwrap_or_else(|_| serde_json::json!([])),
+            comment_policy: value.comment_policy.to_string(),
+            held: value.held,
         }
     }
 }
@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use validator::{Validate, ValidationErrors};
 
@@ -5,6 +6,9 @@ use crate::schema::{self};
 
 use super::{post::Post, user::User, Id};
 
+/// How many levels replies nest below a top-level comment
+pub const MAX_COMMENT_DEPTH: usize = 10;
+
 #[derive(Clone, Debug, PartialEq, Validate, Deserialize, Serialize)]
 pub struct PostComment {
     pub id: Id<PostComment>,
@@ -17,6 +21,14 @@ pub struct PostComment {
     ))]
     pub content: String,
     pub parent_id: Option<Id<PostComment>>,
+    pub deleted_at: Option<DateTime<Utc>>,
+    pub deleted_by: Option<Id<User>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: Option<DateTime<Utc>>,
+    /// Set when the author of the post hid the comment, it stays out of sight without being deleted
+    pub hidden_at: Option<DateTime<Utc>>,
+    /// Set while the comment waits in the trash for a moderator after hitting a content filter
+    pub held: bool,
 }
 
 impl PostComment {
@@ -32,12 +44,61 @@ impl PostComment {
             user_id,
             content,
             parent_id,
+            deleted_at: None,
+            deleted_by: None,
+            created_at: Utc::now(),
+            updated_at: None,
+            hidden_at: None,
+            held: false,
         };
 
         post_comment.validate()?;
 
         Ok(post_comment)
     }
+
+    pub fn edit(&mut self, content: String) -> Result<(), ValidationErrors> {
+        self.content = content;
+        self.updated_at = Some(Utc::now());
+
+        self.validate()
+    }
+
+    pub fn is_edited(&self) -> bool {
+        self.updated_at.is_some()
+    }
+
+    pub fn is_hidden(&self) -> bool {
+        self.hidden_at.is_some()
+    }
+
+    pub fn hide(&mut self) {
+        self.hidden_at = Some(Utc::now());
+    }
+
+    pub fn unhide(&mut self) {
+        self.hidden_at = None;
+    }
+
+    /// Hidden comments are only shown to their author, the author of the post and moderators.
+    pub fn is_visible_to(
+        &self,
+        viewer_id: Option<&Id<User>>,
+        post_author_id: &Id<User>,
+        is_moderator: bool,
+    ) -> bool {
+        !self.is_hidden()
+            || is_moderator
+            || viewer_id
+                .is_some_and(|viewer_id| viewer_id == &self.user_id || viewer_id == post_author_id)
+    }
+
+    /// Moves the comment to the trash without a deleter, so only a moderator can restore it.
+    pub fn hold_for_review(&mut self) {
+        self.deleted_at = Some(Utc::now());
+        self.deleted_by = None;
+        self.held = true;
+    }
 }
 
 impl From<schema::post_comment::Model> for PostComment {
@@ -48,6 +109,12 @@ impl From<schema::post_comment::Model> for PostComment {
             user_id: Id::new(model.user_id),
             content: model.content,
             parent_id: model.parent_id.map(Id::new),
+            deleted_at: model.deleted_at.map(|date| date.and_utc()),
+            deleted_by: model.deleted_by.map(Id::new),
+            created_at: model.created_at.and_utc(),
+            updated_at: model.updated_at.map(|date| date.and_utc()),
+            hidden_at: model.hidden_at.map(|date| date.and_utc()),
+            held: model.held,
         }
     }
 }
@@ -60,6 +127,12 @@ impl From<PostComment> for schema::post_comment::Model {
             user_id: model.user_id.id,
             content: model.content,
             parent_id: model.parent_id.map(|id| id.id),
+            deleted_at: model.deleted_at.map(|date| date.naive_utc()),
+            deleted_by: model.deleted_by.map(|id| id.id),
+            created_at: model.created_at.naive_utc(),
+            updated_at: model.updated_at.map(|date| date.naive_utc()),
+            hidden_at: model.hidden_at.map(|date| date.naive_utc()),
+            held: model.held,
         }
     }
 }
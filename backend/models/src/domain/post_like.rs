@@ -4,21 +4,27 @@ use serde::{Deserialize, Serialize};
 
 use crate::schema;
 
-use super::{post::Post, user::User, Id};
+use super::{post::Post, reaction::DEFAULT_REACTION_KIND, user::User, Id};
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct PostLike {
     pub post_id: Id<Post>,
     pub user_id: Id<User>,
     pub created_at: DateTime<Utc>,
+    pub kind: String,
 }
 
 impl PostLike {
     pub fn new(post_id: Id<Post>, user_id: Id<User>) -> Self {
+        Self::with_kind(post_id, user_id, DEFAULT_REACTION_KIND.to_string())
+    }
+
+    pub fn with_kind(post_id: Id<Post>, user_id: Id<User>, kind: String) -> Self {
         Self {
             post_id,
             user_id,
             created_at: Utc::now(),
+            kind,
         }
     }
 }
@@ -29,6 +35,7 @@ impl From<schema::post_like::Model> for PostLike {
             post_id: Id::new(model.post_id),
             user_id: Id::new(model.user_id),
             created_at: model.created_at.and_utc(),
+            kind: model.kind,
         }
     }
 }
@@ -39,6 +46,7 @@ impl From<PostLike> for schema::post_like::ActiveModel {
             post_id: ActiveValue::Set(like.post_id.id),
             user_id: ActiveValue::Set(like.user_id.id),
             created_at: ActiveValue::Set(like.created_at.naive_utc()),
+            kind: ActiveValue::Set(like.kind),
         }
     }
 }
@@ -49,6 +57,7 @@ impl From<PostLike> for schema::post_like::Model {
             post_id: value.post_id.id,
             user_id: value.user_id.id,
             created_at: value.created_at.naive_utc(),
+            kind: value.kind,
         }
     }
 }
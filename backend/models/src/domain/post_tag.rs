@@ -1,26 +1,134 @@
+use chrono::{DateTime, Utc};
 use once_cell::sync::Lazy;
 use regex::Regex;
-use validator::Validate;
+use serde::{Deserialize, Serialize};
+use unicode_normalization::UnicodeNormalization;
+use validator::{Validate, ValidationErrors};
 
 use crate::schema;
 
 use super::{post::Post, Id};
 
-static RE_TAG: Lazy<Regex> = Lazy::new(|| Regex::new(r"^[a-zA-Z0-9]+$").unwrap());
+/// Length limits of a tag in characters, after normalization
+pub const MIN_TAG_LENGTH: u64 = 3;
+pub const MAX_TAG_LENGTH: u64 = 30;
+
+pub(crate) static RE_TAG: Lazy<Regex> = Lazy::new(|| Regex::new(r"^[\p{L}\p{M}\p{N}_]+$").unwrap());
+static RE_HASHTAG: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?:^|[^\p{L}\p{M}\p{N}_#])#([\p{L}\p{M}\p{N}_]+)").unwrap());
 
 #[derive(Clone, Debug, PartialEq, Validate)]
 pub struct PostTag {
     pub post_id: Id<Post>,
-    #[validate(length(min = 3, max = 10), regex(path = *RE_TAG, message = "Invalid tag, only alphanumeric characters are allowed",))]
+    #[validate(
+        length(
+            min = MIN_TAG_LENGTH,
+            max = MAX_TAG_LENGTH,
+            message = "Tag must be between 3 and 30 characters"
+        ),
+        regex(
+            path = *RE_TAG,
+            message = "Invalid tag, only letters, digits and underscores are allowed"
+        )
+    )]
     pub tag: String,
 }
 
 impl PostTag {
-    pub fn new(post_id: Id<Post>, tag: String) -> Self {
-        Self { post_id, tag }
+    /// Creates a tag of the post, the tag is normalized first (see [`normalize_tag`]).
+    pub fn new(post_id: Id<Post>, tag: String) -> Result<Self, ValidationErrors> {
+        let post_tag = Self {
+            post_id,
+            tag: normalize_tag(&tag),
+        };
+
+        post_tag.validate()?;
+
+        Ok(post_tag)
+    }
+}
+
+/// Lowercases the tag and brings it to the Unicode NFC form, so differently typed or
+/// composed variants of the same word end up as a single tag. A leading `#` is dropped.
+pub fn normalize_tag(tag: &str) -> String {
+    let tag = tag.trim();
+
+    tag.strip_prefix('#')
+        .unwrap_or(tag)
+        .to_lowercase()
+        .nfc()
+        .collect()
+}
+
+/// Normalized `#hashtags` of a text, every tag only once in the order of its first occurrence.
+/// Hashtags which would not make a valid tag are skipped.
+pub fn extract_hashtags(text: &str) -> Vec<String> {
+    let text: String = text.nfc().collect();
+    let mut tags: Vec<String> = vec![];
+
+    for captures in RE_HASHTAG.captures_iter(&text) {
+        let tag = normalize_tag(&captures[1]);
+        let length = tag.chars().count() as u64;
+
+        if (MIN_TAG_LENGTH..=MAX_TAG_LENGTH).contains(&length) && !tags.contains(&tag) {
+            tags.push(tag);
+        }
+    }
+
+    tags
+}
+
+/// Weight of a single like on a tagged post when ranking trending tags, a new post counts as 1.
+pub const TRENDING_LIKE_WEIGHT: f64 = 0.5;
+/// Weight of a single comment on a tagged post when ranking trending tags.
+pub const TRENDING_COMMENT_WEIGHT: f64 = 1.0;
+
+/// Activity of a tag within a time window.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TrendingTag {
+    pub tag: String,
+    pub post_count: i64,
+    pub like_count: i64,
+    pub comment_count: i64,
+    pub score: f64,
+}
+
+impl TrendingTag {
+    pub fn new(tag: String, post_count: i64, like_count: i64, comment_count: i64) -> Self {
+        Self {
+            tag,
+            post_count,
+            like_count,
+            comment_count,
+            score: trending_score(post_count, like_count, comment_count),
+        }
     }
 }
 
+/// Every post using the tag counts once, likes and comments on those posts add to it
+/// with [`TRENDING_LIKE_WEIGHT`] and [`TRENDING_COMMENT_WEIGHT`].
+pub fn trending_score(post_count: i64, like_count: i64, comment_count: i64) -> f64 {
+    post_count as f64
+        + like_count as f64 * TRENDING_LIKE_WEIGHT
+        + comment_count as f64 * TRENDING_COMMENT_WEIGHT
+}
+
+/// A tag used together with another one, `post_count` is the number of posts having both.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RelatedTag {
+    pub tag: String,
+    pub post_count: i64,
+}
+
+/// Overview of a tag as seen by a single user, only posts visible to them are counted.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TagDetail {
+    pub tag: String,
+    pub post_count: i64,
+    pub first_used_at: DateTime<Utc>,
+    pub related_tags: Vec<RelatedTag>,
+}
+
 impl From<schema::post_tag::Model> for PostTag {
     fn from(model: schema::post_tag::Model) -> Self {
         Self {
@@ -0,0 +1,75 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+pub const DEFAULT_REACTION_KIND: &str = "like";
+
+const DEFAULT_REACTION_KINDS: [&str; 6] = ["like", "love", "haha", "wow", "sad", "angry"];
+
+/// The fixed set of reactions users can pick from.
+///
+/// The default kind is always part of the set, plain likes from before reactions existed are
+/// stored with it.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ReactionKinds {
+    kinds: Vec<String>,
+}
+
+impl ReactionKinds {
+    /// Parses a comma separated list of kinds, e.g. `like,love,haha`.
+    /// Kinds are trimmed and lowercased, empty entries and duplicates are skipped.
+    pub fn parse(value: &str) -> Self {
+        let mut kinds = vec![DEFAULT_REACTION_KIND.to_string()];
+
+        for kind in value.split(',') {
+            let kind = kind.trim().to_lowercase();
+
+            if !kind.is_empty() && !kinds.contains(&kind) {
+                kinds.push(kind);
+            }
+        }
+
+        Self { kinds }
+    }
+
+    pub fn contains(&self, kind: &str) -> bool {
+        self.kinds.iter().any(|k| k == kind)
+    }
+
+    pub fn kinds(&self) -> &[String] {
+        &self.kinds
+    }
+}
+
+impl Default for ReactionKinds {
+    fn default() -> Self {
+        Self::parse(&DEFAULT_REACTION_KINDS.join(","))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ReactionCount {
+    pub kind: String,
+    pub count: i64,
+}
+
+/// Aggregates reactions into counts per kind, ordered by the most used kind first.
+pub fn count_reactions<'a>(kinds: impl IntoIterator<Item = &'a str>) -> Vec<ReactionCount> {
+    let mut counts: BTreeMap<&str, i64> = BTreeMap::new();
+
+    for kind in kinds {
+        *counts.entry(kind).or_default() += 1;
+    }
+
+    let mut counts: Vec<ReactionCount> = counts
+        .into_iter()
+        .map(|(kind, count)| ReactionCount {
+            kind: kind.to_string(),
+            count,
+        })
+        .collect();
+
+    counts.sort_by(|a, b| b.count.cmp(&a.count));
+
+    counts
+}
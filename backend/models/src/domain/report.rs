@@ -0,0 +1,333 @@
+use std::fmt::Display;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::{Validate, ValidationError, ValidationErrors};
+
+use crate::schema;
+
+use super::{user::User, Id};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ReportTargetType {
+    Post,
+    Comment,
+    User,
+    Group,
+}
+
+impl Display for ReportTargetType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Post => write!(f, "post"),
+            Self::Comment => write!(f, "comment"),
+            Self::User => write!(f, "user"),
+            Self::Group => write!(f, "group"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ReportReason {
+    Spam,
+    Harassment,
+    HateSpeech,
+    Violence,
+    Nudity,
+    Misinformation,
+    Other,
+    /// Raised by the content filter rather than by a user.
+    FilteredContent,
+}
+
+impl Display for ReportReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Spam => write!(f, "spam"),
+            Self::Harassment => write!(f, "harassment"),
+            Self::HateSpeech => write!(f, "hateSpeech"),
+            Self::Violence => write!(f, "violence"),
+            Self::Nudity => write!(f, "nudity"),
+            Self::Misinformation => write!(f, "misinformation"),
+            Self::Other => write!(f, "other"),
+            Self::FilteredContent => write!(f, "filteredContent"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ReportStatus {
+    Open,
+    Claimed,
+    Resolved,
+    Dismissed,
+}
+
+impl Display for ReportStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Open => write!(f, "open"),
+            Self::Claimed => write!(f, "claimed"),
+            Self::Resolved => write!(f, "resolved"),
+            Self::Dismissed => write!(f, "dismissed"),
+        }
+    }
+}
+
+/// What a moderator did about a closed report.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ReportOutcome {
+    NoAction,
+    PostDeleted,
+    UserBlocked,
+}
+
+impl Display for ReportOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NoAction => write!(f, "noAction"),
+            Self::PostDeleted => write!(f, "postDeleted"),
+            Self::UserBlocked => write!(f, "userBlocked"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Validate, Deserialize, Serialize)]
+pub struct Report {
+    pub id: Id<Report>,
+    pub reporter_id: Option<Id<User>>,
+    pub target_type: ReportTargetType,
+    pub target_id: Uuid,
+    pub reason: ReportReason,
+    #[validate(length(max = 1000, message = "Details must be at most 1000 characters"))]
+    pub details: String,
+    pub status: ReportStatus,
+    pub moderator_id: Option<Id<User>>,
+    pub outcome: Option<ReportOutcome>,
+    #[validate(length(max = 1000, message = "Note must be at most 1000 characters"))]
+    pub resolution_note: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub claimed_at: Option<DateTime<Utc>>,
+    pub closed_at: Option<DateTime<Utc>>,
+}
+
+impl Report {
+    pub fn new(
+        reporter_id: Id<User>,
+        target_type: ReportTargetType,
+        target_id: Uuid,
+        reason: ReportReason,
+        details: String,
+    ) -> Result<Self, ValidationErrors> {
+        let details = details.trim().to_string();
+
+        if reason == ReportReason::FilteredContent {
+            return Err(report_error(
+                "reason",
+                "This reason is reserved for the content filter",
+            ));
+        }
+
+        // Without a category the moderator has nothing else to go by
+        if reason == ReportReason::Other && details.is_empty() {
+            return Err(report_error(
+                "details",
+                "Details are required for reports of other reasons",
+            ));
+        }
+
+        let report = Self {
+            id: Id::gen(),
+            reporter_id: Some(reporter_id),
+            target_type,
+            target_id,
+            reason,
+            details,
+            status: ReportStatus::Open,
+            moderator_id: None,
+            outcome: None,
+            resolution_note: None,
+            created_at: Utc::now(),
+            claimed_at: None,
+            closed_at: None,
+        };
+
+        report.validate()?;
+
+        Ok(report)
+    }
+
+    /// Report filed by the content filter on behalf of nobody in particular.
+    pub fn flag(target_type: ReportTargetType, target_id: Uuid, details: String) -> Self {
+        Self {
+            id: Id::gen(),
+            reporter_id: None,
+            target_type,
+            target_id,
+            reason: ReportReason::FilteredContent,
+            details: details.chars().take(1000).collect(),
+            status: ReportStatus::Open,
+            moderator_id: None,
+            outcome: None,
+            resolution_note: None,
+            created_at: Utc::now(),
+            claimed_at: None,
+            closed_at: None,
+        }
+    }
+
+    pub fn is_closed(&self) -> bool {
+        matches!(
+            self.status,
+            ReportStatus::Resolved | ReportStatus::Dismissed
+        )
+    }
+
+    /// Claiming a report tells the other moderators someone is already looking into it.
+    /// Claiming it again by the same moderator is a no-op.
+    pub fn claim(&mut self, moderator_id: Id<User>) -> Result<(), ValidationErrors> {
+        self.check_open_for(&moderator_id)?;
+
+        if self.status == ReportStatus::Open {
+            self.status = ReportStatus::Claimed;
+            self.moderator_id = Some(moderator_id);
+            self.claimed_at = Some(Utc::now());
+        }
+
+        Ok(())
+    }
+
+    pub fn resolve(
+        &mut self,
+        moderator_id: Id<User>,
+        outcome: ReportOutcome,
+        note: Option<String>,
+    ) -> Result<(), ValidationErrors> {
+        self.close(moderator_id, ReportStatus::Resolved, outcome, note)
+    }
+
+    pub fn dismiss(
+        &mut self,
+        moderator_id: Id<User>,
+        note: Option<String>,
+    ) -> Result<(), ValidationErrors> {
+        self.close(
+            moderator_id,
+            ReportStatus::Dismissed,
+            ReportOutcome::NoAction,
+            note,
+        )
+    }
+
+    fn close(
+        &mut self,
+        moderator_id: Id<User>,
+        status: ReportStatus,
+        outcome: ReportOutcome,
+        note: Option<String>,
+    ) -> Result<(), ValidationErrors> {
+        self.check_open_for(&moderator_id)?;
+
+        self.status = status;
+        self.moderator_id = Some(moderator_id);
+        self.outcome = Some(outcome);
+        self.resolution_note = note
+            .map(|note| note.trim().to_string())
+            .filter(|note| !note.is_empty());
+        self.closed_at = Some(Utc::now());
+
+        self.validate()
+    }
+
+    fn check_open_for(&self, moderator_id: &Id<User>) -> Result<(), ValidationErrors> {
+        if self.is_closed() {
+            return Err(report_error("status", "Report is already closed"));
+        }
+
+        if self.status == ReportStatus::Claimed && self.moderator_id.as_ref() != Some(moderator_id)
+        {
+            return Err(report_error(
+                "status",
+                "Report is claimed by another moderator",
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+fn report_error(field: &'static str, message: &'static str) -> ValidationErrors {
+    let mut errors = ValidationErrors::new();
+    errors.add(field, ValidationError::new(message));
+    errors
+}
+
+impl From<schema::report::Model> for Report {
+    fn from(model: schema::report::Model) -> Self {
+        Self {
+            id: Id::new(model.id),
+            reporter_id: model.reporter_id.map(Id::new),
+            target_type: match model.target_type.as_str() {
+                "post" => ReportTargetType::Post,
+                "comment" => ReportTargetType::Comment,
+                "user" => ReportTargetType::User,
+                "group" => ReportTargetType::Group,
+                _ => unreachable!("Invalid report target type received from database"),
+            },
+            target_id: model.target_id,
+            reason: match model.reason.as_str() {
+                "spam" => ReportReason::Spam,
+                "harassment" => ReportReason::Harassment,
+                "hateSpeech" => ReportReason::HateSpeech,
+                "violence" => ReportReason::Violence,
+                "nudity" => ReportReason::Nudity,
+                "misinformation" => ReportReason::Misinformation,
+                "filteredContent" => ReportReason::FilteredContent,
+                _ => ReportReason::Other,
+            },
+            details: model.details,
+            status: match model.status.as_str() {
+                "open" => ReportStatus::Open,
+                "claimed" => ReportStatus::Claimed,
+                "resolved" => ReportStatus::Resolved,
+                "dismissed" => ReportStatus::Dismissed,
+                _ => unreachable!("Invalid report status received from database"),
+            },
+            moderator_id: model.moderator_id.map(Id::new),
+            outcome: model.outcome.map(|outcome| match outcome.as_str() {
+                "postDeleted" => ReportOutcome::PostDeleted,
+                "userBlocked" => ReportOutcome::UserBlocked,
+                _ => ReportOutcome::NoAction,
+            }),
+            resolution_note: model.resolution_note,
+            created_at: model.created_at.and_utc(),
+            claimed_at: model.claimed_at.map(|date| date.and_utc()),
+            closed_at: model.closed_at.map(|date| date.and_utc()),
+        }
+    }
+}
+
+impl From<Report> for schema::report::Model {
+    fn from(report: Report) -> Self {
+        Self {
+            id: report.id.id,
+            reporter_id: report.reporter_id.map(|id| id.id),
+            target_type: report.target_type.to_string(),
+            target_id: report.target_id,
+            reason: report.reason.to_string(),
+            details: report.details,
+            status: report.status.to_string(),
+            moderator_id: report.moderator_id.map(|id| id.id),
+            outcome: report.outcome.map(|outcome| outcome.to_string()),
+            resolution_note: report.resolution_note,
+            created_at: report.created_at.naive_utc(),
+            claimed_at: report.claimed_at.map(|date| date.naive_utc()),
+            closed_at: report.closed_at.map(|date| date.naive_utc()),
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use validator::{Validate, ValidationErrors};
+
+use crate::schema;
+
+use super::{user::User, Id};
+
+#[derive(Clone, Debug, PartialEq, Validate, Deserialize, Serialize)]
+pub struct SavedCollection {
+    pub id: Id<SavedCollection>,
+    pub user_id: Id<User>,
+    #[validate(length(
+        min = 1,
+        max = 50,
+        message = "Name must be between 1 and 50 characters"
+    ))]
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl SavedCollection {
+    pub fn new(user_id: Id<User>, name: String) -> Result<Self, ValidationErrors> {
+        let collection = Self {
+            id: Id::gen(),
+            user_id,
+            name: name.trim().to_string(),
+            created_at: Utc::now(),
+        };
+
+        collection.validate()?;
+
+        Ok(collection)
+    }
+
+    pub fn rename(&mut self, name: String) -> Result<(), ValidationErrors> {
+        let mut renamed = self.clone();
+        renamed.name = name.trim().to_string();
+        renamed.validate()?;
+
+        *self = renamed;
+
+        Ok(())
+    }
+}
+
+impl From<schema::saved_collection::Model> for SavedCollection {
+    fn from(model: schema::saved_collection::Model) -> Self {
+        Self {
+            id: Id::new(model.id),
+            user_id: Id::new(model.user_id),
+            name: model.name,
+            created_at: model.created_at.and_utc(),
+        }
+    }
+}
+
+impl From<SavedCollection> for schema::saved_collection::Model {
+    fn from(value: SavedCollection) -> Self {
+        Self {
+            id: value.id.id,
+            user_id: value.user_id.id,
+            name: value.name,
+            created_at: value.created_at.naive_utc(),
+        }
+    }
+}
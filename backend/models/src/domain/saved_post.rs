@@ -0,0 +1,51 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::schema;
+
+use super::{post::Post, saved_collection::SavedCollection, user::User, Id};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SavedPost {
+    pub user_id: Id<User>,
+    pub post_id: Id<Post>,
+    pub collection_id: Option<Id<SavedCollection>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl SavedPost {
+    pub fn new(
+        user_id: Id<User>,
+        post_id: Id<Post>,
+        collection_id: Option<Id<SavedCollection>>,
+    ) -> Self {
+        Self {
+            user_id,
+            post_id,
+            collection_id,
+            created_at: Utc::now(),
+        }
+    }
+}
+
+impl From<schema::saved_post::Model> for SavedPost {
+    fn from(model: schema::saved_post::Model) -> Self {
+        Self {
+            user_id: Id::new(model.user_id),
+            post_id: Id::new(model.post_id),
+            collection_id: model.collection_id.map(Id::new),
+            created_at: model.created_at.and_utc(),
+        }
+    }
+}
+
+impl From<SavedPost> for schema::saved_post::Model {
+    fn from(value: SavedPost) -> Self {
+        Self {
+            user_id: value.user_id.id,
+            post_id: value.post_id.id,
+            collection_id: value.collection_id.map(|id| id.id),
+            created_at: value.created_at.naive_utc(),
+        }
+    }
+}
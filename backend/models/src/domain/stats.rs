@@ -0,0 +1,123 @@
+use std::fmt::Display;
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use validator::{ValidationError, ValidationErrors};
+
+/// Longest series a single request may ask for, in buckets
+pub const MAX_STATS_BUCKETS: i64 = 366;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum StatsBucket {
+    #[default]
+    Day,
+    /// Weeks start on Monday, as in Postgres `date_trunc('week', ..)`
+    Week,
+}
+
+impl StatsBucket {
+    pub fn days(&self) -> i64 {
+        match self {
+            Self::Day => 1,
+            Self::Week => 7,
+        }
+    }
+}
+
+impl Display for StatsBucket {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Day => write!(f, "day"),
+            Self::Week => write!(f, "week"),
+        }
+    }
+}
+
+/// Whole days from `from` to `to`, both included, widened to whole buckets.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StatsRange {
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub bucket: StatsBucket,
+}
+
+impl StatsRange {
+    pub fn new(
+        from: NaiveDate,
+        to: NaiveDate,
+        bucket: StatsBucket,
+    ) -> Result<Self, ValidationErrors> {
+        if to < from {
+            return Err(stats_error(
+                "to",
+                "End of the range must not precede its start",
+            ));
+        }
+
+        let (from, to) = match bucket {
+            StatsBucket::Day => (from, to),
+            StatsBucket::Week => {
+                let from = from - Duration::days(from.weekday().num_days_from_monday() as i64);
+                let to = to + Duration::days(6 - to.weekday().num_days_from_monday() as i64);
+                (from, to)
+            }
+        };
+
+        let range = Self { from, to, bucket };
+
+        if range.bucket_count() > MAX_STATS_BUCKETS {
+            return Err(stats_error("from", "Range contains too many buckets"));
+        }
+
+        Ok(range)
+    }
+
+    /// Range of `days` days ending with `today`.
+    pub fn last_days(
+        today: NaiveDate,
+        days: i64,
+        bucket: StatsBucket,
+    ) -> Result<Self, ValidationErrors> {
+        Self::new(today - Duration::days(days - 1), today, bucket)
+    }
+
+    pub fn start(&self) -> DateTime<Utc> {
+        self.from.and_time(Default::default()).and_utc()
+    }
+
+    /// First moment after the range
+    pub fn end(&self) -> DateTime<Utc> {
+        (self.to + Duration::days(1))
+            .and_time(Default::default())
+            .and_utc()
+    }
+
+    pub fn bucket_count(&self) -> i64 {
+        ((self.to - self.from).num_days() + 1) / self.bucket.days()
+    }
+}
+
+fn stats_error(field: &'static str, message: &'static str) -> ValidationErrors {
+    let mut errors = ValidationErrors::new();
+    errors.add(field, ValidationError::new(message));
+    errors
+}
+
+/// Number of events in the bucket starting at `bucket_start`
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct StatsPoint {
+    pub bucket_start: DateTime<Utc>,
+    pub count: i64,
+}
+
+/// Current size of the site, regardless of the requested range
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct StatsTotals {
+    pub users: i64,
+    pub posts: i64,
+    pub comments: i64,
+    pub likes: i64,
+    pub groups: i64,
+    pub pending_join_requests: i64,
+}
@@ -0,0 +1,60 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use validator::{Validate, ValidationErrors};
+
+use crate::schema;
+
+use super::{
+    post_tag::{normalize_tag, RE_TAG},
+    user::User,
+    Id,
+};
+
+#[derive(Clone, Debug, PartialEq, Validate, Serialize, Deserialize)]
+pub struct TagFollow {
+    pub user_id: Id<User>,
+    #[validate(
+        length(min = 3, max = 30, message = "Tag must be between 3 and 30 characters"),
+        regex(
+            path = *RE_TAG,
+            message = "Invalid tag, only letters, digits and underscores are allowed"
+        )
+    )]
+    pub tag: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl TagFollow {
+    /// Follows the tag, normalized the same way as tags of posts so both always match.
+    pub fn new(user_id: Id<User>, tag: &str) -> Result<Self, ValidationErrors> {
+        let follow = Self {
+            user_id,
+            tag: normalize_tag(tag),
+            created_at: Utc::now(),
+        };
+
+        follow.validate()?;
+
+        Ok(follow)
+    }
+}
+
+impl From<schema::tag_follow::Model> for TagFollow {
+    fn from(model: schema::tag_follow::Model) -> Self {
+        Self {
+            user_id: Id::new(model.user_id),
+            tag: model.tag,
+            created_at: model.created_at.and_utc(),
+        }
+    }
+}
+
+impl From<TagFollow> for schema::tag_follow::Model {
+    fn from(value: TagFollow) -> Self {
+        Self {
+            user_id: value.user_id.id,
+            tag: value.tag,
+            created_at: value.created_at.naive_utc(),
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use once_cell::sync::Lazy;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
@@ -105,7 +106,12 @@ pub struct User {
     pub user_type: UserType,
     pub wall_id: Id<Wall>,
     pub is_blocked: bool,
+    /// Content of limited users is only shown to themselves and moderators
+    pub is_limited: bool,
     pub password_hash: String,
+    pub created_at: DateTime<Utc>,
+    /// Set while the account is deactivated and waiting to be purged
+    pub deletion_requested_at: Option<DateTime<Utc>>,
 }
 
 impl User {
@@ -127,7 +133,10 @@ impl User {
             user_type,
             wall_id,
             is_blocked: false,
+            is_limited: false,
             password_hash,
+            created_at: Utc::now(),
+            deletion_requested_at: None,
         };
 
         model.validate()?;
@@ -142,6 +151,36 @@ impl User {
     pub fn unblock(&mut self) {
         self.is_blocked = false;
     }
+
+    pub fn set_limited(&mut self, is_limited: bool) {
+        self.is_limited = is_limited;
+    }
+
+    pub fn request_deletion(&mut self) {
+        self.deletion_requested_at = Some(Utc::now());
+    }
+
+    pub fn cancel_deletion(&mut self) {
+        self.deletion_requested_at = None;
+    }
+
+    pub fn is_deactivated(&self) -> bool {
+        self.deletion_requested_at.is_some()
+    }
+
+    /// Whether the actor may block, unblock or change the role of this user.
+    /// Nobody manages themselves and only users ranked strictly higher manage others.
+    pub fn is_manageable_by(&self, actor_id: &Id<User>, actor_role: &UserType) -> bool {
+        &self.id != actor_id
+            && !actor_role.is_regular()
+            && actor_role.has_higher_privilege_than(&self.user_type)
+    }
+
+    /// Whether posts and comments of this user are shown to the viewer.
+    /// Content of limited and deactivated users is only shown to themselves and moderators.
+    pub fn is_content_visible_to(&self, viewer_id: Option<&Id<User>>, is_moderator: bool) -> bool {
+        (!self.is_limited && !self.is_deactivated()) || is_moderator || viewer_id == Some(&self.id)
+    }
 }
 
 impl From<schema::user::Model> for User {
@@ -155,7 +194,10 @@ impl From<schema::user::Model> for User {
             user_type: model.user_type.into(),
             wall_id: Id::new(model.wall_id),
             is_blocked: model.is_blocked,
+            is_limited: model.is_limited,
             password_hash: model.password_hash,
+            created_at: model.created_at.and_utc(),
+            deletion_requested_at: model.deletion_requested_at.map(|at| at.and_utc()),
         }
     }
 }
@@ -171,7 +213,10 @@ impl From<User> for schema::user::Model {
             user_type: user.user_type.into(),
             wall_id: user.wall_id.id,
             is_blocked: user.is_blocked,
+            is_limited: user.is_limited,
             password_hash: user.password_hash,
+            created_at: user.created_at.naive_utc(),
+            deletion_requested_at: user.deletion_requested_at.map(|at| at.naive_utc()),
         }
     }
 }
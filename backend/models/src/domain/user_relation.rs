@@ -0,0 +1,90 @@
+use std::fmt::Display;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use validator::{ValidationError, ValidationErrors};
+
+use crate::schema;
+
+use super::{user::User, Id};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum UserRelationKind {
+    /// The target can not see, comment on, like or mention the user's content
+    Block,
+    /// Posts of the target are left out of the user's feeds
+    Mute,
+    /// The user follows the target, which may open the target's comments to them
+    Follow,
+}
+
+impl Display for UserRelationKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Block => write!(f, "block"),
+            Self::Mute => write!(f, "mute"),
+            Self::Follow => write!(f, "follow"),
+        }
+    }
+}
+
+/// A block, mute or follow of `target_id` set up by `user_id`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct UserRelation {
+    pub user_id: Id<User>,
+    pub target_id: Id<User>,
+    pub kind: UserRelationKind,
+    pub created_at: DateTime<Utc>,
+}
+
+impl UserRelation {
+    pub fn new(
+        user_id: Id<User>,
+        target_id: Id<User>,
+        kind: UserRelationKind,
+    ) -> Result<Self, ValidationErrors> {
+        if user_id == target_id {
+            let mut errors = ValidationErrors::new();
+            errors.add(
+                "target_id",
+                ValidationError::new("Users can not block, mute or follow themselves"),
+            );
+            return Err(errors);
+        }
+
+        Ok(Self {
+            user_id,
+            target_id,
+            kind,
+            created_at: Utc::now(),
+        })
+    }
+}
+
+impl From<schema::user_relation::Model> for UserRelation {
+    fn from(model: schema::user_relation::Model) -> Self {
+        Self {
+            user_id: Id::new(model.user_id),
+            target_id: Id::new(model.target_id),
+            kind: match model.kind.as_str() {
+                "block" => UserRelationKind::Block,
+                "mute" => UserRelationKind::Mute,
+                "follow" => UserRelationKind::Follow,
+                _ => unreachable!("Invalid user relation kind received from database"),
+            },
+            created_at: model.created_at.and_utc(),
+        }
+    }
+}
+
+impl From<UserRelation> for schema::user_relation::Model {
+    fn from(relation: UserRelation) -> Self {
+        Self {
+            user_id: relation.user_id.id,
+            target_id: relation.target_id.id,
+            kind: relation.kind.to_string(),
+            created_at: relation.created_at.naive_utc(),
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use validator::{Validate, ValidationError, ValidationErrors};
+
+use crate::schema;
+
+use super::{user::User, Id};
+
+/// A period in which the user can not sign in or use their existing sessions.
+/// Suspensions without an end last until a moderator lifts them.
+#[derive(Clone, Debug, PartialEq, Validate, Deserialize, Serialize)]
+pub struct UserSuspension {
+    pub id: Id<UserSuspension>,
+    pub user_id: Id<User>,
+    pub moderator_id: Option<Id<User>>,
+    #[validate(length(
+        min = 1,
+        max = 500,
+        message = "Reason must be between 1 and 500 characters"
+    ))]
+    pub reason: String,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: Option<DateTime<Utc>>,
+    pub lifted_at: Option<DateTime<Utc>>,
+    /// `None` when the suspension was lifted on expiry
+    pub lifted_by: Option<Id<User>>,
+}
+
+impl UserSuspension {
+    pub fn new(
+        user_id: Id<User>,
+        moderator_id: Option<Id<User>>,
+        reason: String,
+        ends_at: Option<DateTime<Utc>>,
+    ) -> Result<Self, ValidationErrors> {
+        let starts_at = Utc::now();
+
+        if ends_at.is_some_and(|ends_at| ends_at <= starts_at) {
+            let mut errors = ValidationErrors::new();
+            errors.add(
+                "ends_at",
+                ValidationError::new("Suspension must end in the future"),
+            );
+            return Err(errors);
+        }
+
+        let suspension = Self {
+            id: Id::gen(),
+            user_id,
+            moderator_id,
+            reason: reason.trim().to_string(),
+            starts_at,
+            ends_at,
+            lifted_at: None,
+            lifted_by: None,
+        };
+
+        suspension.validate()?;
+
+        Ok(suspension)
+    }
+
+    /// Lifting an already lifted suspension keeps the original record.
+    pub fn lift(&mut self, lifted_by: Option<Id<User>>) {
+        if self.lifted_at.is_none() {
+            self.lifted_at = Some(Utc::now());
+            self.lifted_by = lifted_by;
+        }
+    }
+}
+
+impl From<schema::user_suspension::Model> for UserSuspension {
+    fn from(model: schema::user_suspension::Model) -> Self {
+        Self {
+            id: Id::new(model.id),
+            user_id: Id::new(model.user_id),
+            moderator_id: model.moderator_id.map(Id::new),
+            reason: model.reason,
+            starts_at: model.starts_at.and_utc(),
+            ends_at: model.ends_at.map(|date| date.and_utc()),
+            lifted_at: model.lifted_at.map(|date| date.and_utc()),
+            lifted_by: model.lifted_by.map(Id::new),
+        }
+    }
+}
+
+impl From<UserSuspension> for schema::user_suspension::Model {
+    fn from(suspension: UserSuspension) -> Self {
+        Self {
+            id: suspension.id.id,
+            user_id: suspension.user_id.id,
+            moderator_id: suspension.moderator_id.map(|id| id.id),
+            reason: suspension.reason,
+            starts_at: suspension.starts_at.naive_utc(),
+            ends_at: suspension.ends_at.map(|date| date.naive_utc()),
+            lifted_at: suspension.lifted_at.map(|date| date.naive_utc()),
+            lifted_by: suspension.lifted_by.map(|id| id.id),
+        }
+    }
+}
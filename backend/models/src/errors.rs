@@ -3,6 +3,7 @@ use axum::{
     response::{IntoResponse, Response},
     Json,
 };
+use chrono::{DateTime, Utc};
 use sea_orm::DbErr;
 use serde_json::json;
 use thiserror::Error;
@@ -23,6 +24,11 @@ pub enum AppError {
     Conflict(String),
     #[error("Bad request: {0}")]
     BadRequest(String),
+    #[error("Account suspended: {reason}")]
+    Suspended {
+        reason: String,
+        until: Option<DateTime<Utc>>,
+    },
 }
 
 impl IntoResponse for AppError {
@@ -45,6 +51,10 @@ impl IntoResponse for AppError {
             Self::Unauthorized(err) => (StatusCode::UNAUTHORIZED, Json(json!({ "error": err }))),
             Self::Conflict(err) => (StatusCode::CONFLICT, Json(json!({ "error": err }))),
             Self::BadRequest(err) => (StatusCode::BAD_REQUEST, Json(json!({ "error": err }))),
+            Self::Suspended { reason, until } => (
+                StatusCode::FORBIDDEN,
+                Json(json!({ "error": "Account is suspended", "reason": reason, "until": until })),
+            ),
         }
         .into_response()
     }
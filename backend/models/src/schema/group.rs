@@ -11,6 +11,7 @@ pub struct Model {
     pub name: String,
     pub admin_id: Uuid,
     pub wall_id: Uuid,
+    pub created_at: DateTime,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -16,10 +16,22 @@ pub struct Model {
     pub content_type: String,
     pub visibility: String,
     pub content_url: String,
+    pub deleted_at: Option<DateTime>,
+    pub deleted_by: Option<Uuid>,
+    #[sea_orm(column_type = "JsonBinary")]
+    pub image_variants: Json,
+    pub comment_policy: String,
+    pub held: bool,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(has_many = "super::notification::Entity")]
+    Notification,
+    #[sea_orm(has_many = "super::mention::Entity")]
+    Mention,
+    #[sea_orm(has_many = "super::saved_post::Entity")]
+    SavedPost,
     #[sea_orm(
         belongs_to = "super::location::Entity",
         from = "Column::LocationId",
@@ -116,4 +128,22 @@ impl Related<super::wall::Entity> for Entity {
     }
 }
 
+impl Related<super::saved_post::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::SavedPost.def()
+    }
+}
+
+impl Related<super::mention::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Mention.def()
+    }
+}
+
+impl Related<super::notification::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Notification.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
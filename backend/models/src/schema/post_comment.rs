@@ -12,10 +12,22 @@ pub struct Model {
     pub user_id: Uuid,
     pub content: String,
     pub parent_id: Option<Uuid>,
+    pub deleted_at: Option<DateTime>,
+    pub deleted_by: Option<Uuid>,
+    pub created_at: DateTime,
+    pub updated_at: Option<DateTime>,
+    pub hidden_at: Option<DateTime>,
+    pub held: bool,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(has_many = "super::notification::Entity")]
+    Notification,
+    #[sea_orm(has_many = "super::mention::Entity")]
+    Mention,
+    #[sea_orm(has_many = "super::comment_like::Entity")]
+    CommentLike,
     #[sea_orm(
         belongs_to = "super::post::Entity",
         from = "Column::PostId",
@@ -54,4 +66,22 @@ impl Related<super::user::Entity> for Entity {
     }
 }
 
+impl Related<super::comment_like::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::CommentLike.def()
+    }
+}
+
+impl Related<super::mention::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Mention.def()
+    }
+}
+
+impl Related<super::notification::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Notification.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
@@ -18,11 +18,22 @@ pub struct Model {
     pub description: Option<String>,
     pub wall_id: Uuid,
     pub is_blocked: bool,
+    pub is_limited: bool,
     pub password_hash: String,
+    pub created_at: DateTime,
+    pub deletion_requested_at: Option<DateTime>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(has_many = "super::tag_follow::Entity")]
+    TagFollow,
+    #[sea_orm(has_many = "super::saved_post::Entity")]
+    SavedPost,
+    #[sea_orm(has_many = "super::saved_collection::Entity")]
+    SavedCollection,
+    #[sea_orm(has_many = "super::comment_like::Entity")]
+    CommentLike,
     #[sea_orm(has_many = "super::group::Entity")]
     Group,
     #[sea_orm(has_many = "super::group_join_request::Entity")]
@@ -98,4 +109,28 @@ impl Related<super::group::Entity> for Entity {
     }
 }
 
+impl Related<super::comment_like::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::CommentLike.def()
+    }
+}
+
+impl Related<super::saved_collection::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::SavedCollection.def()
+    }
+}
+
+impl Related<super::saved_post::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::SavedPost.def()
+    }
+}
+
+impl Related<super::tag_follow::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TagFollow.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
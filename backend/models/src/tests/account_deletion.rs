@@ -0,0 +1,103 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        domain::{
+            user::{User, UserType},
+            Id,
+        },
+        schema,
+    };
+
+    #[test]
+    fn new_user_is_active() {
+        let user = User::new(
+            "leaving".into(),
+            None,
+            None,
+            None,
+            UserType::Regular,
+            Id::gen(),
+            "password_hash".into(),
+        )
+        .unwrap();
+
+        assert!(!user.is_deactivated());
+        assert!(user.deletion_requested_at.is_none());
+    }
+
+    #[test]
+    fn requesting_deletion_deactivates_the_account() {
+        let mut user = User::new(
+            "leaving".into(),
+            None,
+            None,
+            None,
+            UserType::Regular,
+            Id::gen(),
+            "password_hash".into(),
+        )
+        .unwrap();
+        user.request_deletion();
+
+        assert!(user.is_deactivated());
+        assert!(user.deletion_requested_at.is_some());
+    }
+
+    #[test]
+    fn cancelling_deletion_reactivates_the_account() {
+        let mut user = User::new(
+            "leaving".into(),
+            None,
+            None,
+            None,
+            UserType::Regular,
+            Id::gen(),
+            "password_hash".into(),
+        )
+        .unwrap();
+        user.request_deletion();
+        user.cancel_deletion();
+
+        assert!(!user.is_deactivated());
+    }
+
+    #[test]
+    fn content_of_deactivated_user_is_only_visible_to_themselves_and_moderators() {
+        let mut user = User::new(
+            "leaving".into(),
+            None,
+            None,
+            None,
+            UserType::Regular,
+            Id::gen(),
+            "password_hash".into(),
+        )
+        .unwrap();
+        user.request_deletion();
+
+        assert!(!user.is_content_visible_to(None, false));
+        assert!(!user.is_content_visible_to(Some(&Id::gen()), false));
+        assert!(user.is_content_visible_to(Some(&user.id), false));
+        assert!(user.is_content_visible_to(Some(&Id::gen()), true));
+    }
+
+    #[test]
+    fn deletion_request_survives_conversion_to_model_and_back() {
+        let mut user = User::new(
+            "leaving".into(),
+            None,
+            None,
+            None,
+            UserType::Regular,
+            Id::gen(),
+            "password_hash".into(),
+        )
+        .unwrap();
+        user.request_deletion();
+
+        let model: schema::user::Model = user.clone().into();
+        let restored = User::from(model);
+
+        assert!(restored.is_deactivated());
+    }
+}
@@ -0,0 +1,91 @@
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use uuid::Uuid;
+
+    use crate::domain::{
+        audit_log::{user_snapshot, AuditAction, AuditLogEntry, AuditTargetType},
+        user::{User, UserType},
+        Id,
+    };
+
+    #[test]
+    fn entry_target_type_follows_action() {
+        let entry = AuditLogEntry::new(
+            Id::gen(),
+            AuditAction::DeleteComment,
+            Uuid::new_v4(),
+            None,
+            Some(json!({ "content": "spam" })),
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(entry.target_type, AuditTargetType::Comment);
+        assert_eq!(AuditAction::ChangeRole.target_type(), AuditTargetType::User);
+        assert_eq!(AuditAction::DeletePost.target_type(), AuditTargetType::Post);
+    }
+
+    #[test]
+    fn entry_reason_is_trimmed() {
+        let entry = AuditLogEntry::new(
+            Id::gen(),
+            AuditAction::BlockUser,
+            Uuid::new_v4(),
+            Some("  Repeated spam  ".into()),
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(entry.reason.as_deref(), Some("Repeated spam"));
+
+        let entry = AuditLogEntry::new(
+            Id::gen(),
+            AuditAction::BlockUser,
+            Uuid::new_v4(),
+            Some("   ".into()),
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert!(entry.reason.is_none());
+    }
+
+    #[test]
+    fn entry_reason_too_long() {
+        let entry = AuditLogEntry::new(
+            Id::gen(),
+            AuditAction::DeleteUser,
+            Uuid::new_v4(),
+            Some("a".repeat(1001)),
+            None,
+            None,
+        );
+
+        assert!(entry.is_err());
+    }
+
+    #[test]
+    fn user_snapshot_leaves_out_password() {
+        let mut user = User::new(
+            "moderated".into(),
+            Some("moderated@example.com".into()),
+            None,
+            None,
+            UserType::Regular,
+            Id::gen(),
+            "secret-hash".into(),
+        )
+        .unwrap();
+        let before = user_snapshot(&user);
+        user.block();
+        let after = user_snapshot(&user);
+
+        assert!(before.get("password_hash").is_none());
+        assert_eq!(before["username"], "moderated");
+        assert_eq!(before["is_blocked"], false);
+        assert_eq!(after["is_blocked"], true);
+    }
+}
@@ -0,0 +1,132 @@
+#[cfg(test)]
+mod tests {
+    use crate::domain::{
+        comment_tree::{build_comment_tree, CommentTreeOptions},
+        post::Post,
+        post_comment::PostComment,
+        user::{User, UserType},
+        Id,
+    };
+
+    fn comment(post_id: &Id<Post>, parent: Option<&PostComment>) -> (PostComment, User, i64) {
+        let comment = PostComment::new(
+            post_id.clone(),
+            Id::gen(),
+            "ahoj".into(),
+            parent.map(|parent| parent.id.clone()),
+        )
+        .unwrap();
+        let author = User::new(
+            "krejzac".into(),
+            None,
+            None,
+            None,
+            UserType::Regular,
+            Id::gen(),
+            "hash".into(),
+        )
+        .unwrap();
+
+        (comment, author, 0)
+    }
+
+    fn options(max_depth: usize, replies_limit: usize) -> CommentTreeOptions {
+        CommentTreeOptions {
+            offset: 0,
+            limit: 10,
+            max_depth,
+            replies_limit,
+        }
+    }
+
+    #[test]
+    fn comment_tree_nests_replies() {
+        let post_id = Id::gen();
+        let root = comment(&post_id, None);
+        let reply = comment(&post_id, Some(&root.0));
+        let nested_reply = comment(&post_id, Some(&reply.0));
+
+        let tree = build_comment_tree(
+            vec![nested_reply.clone(), root.clone(), reply.clone()],
+            None,
+            &options(5, 5),
+        );
+
+        assert!(tree.total == 1);
+        assert!(tree.comments[0].comment == root.0);
+        assert!(tree.comments[0].reply_count == 1);
+        assert!(tree.comments[0].replies[0].comment == reply.0);
+        assert!(tree.comments[0].replies[0].replies[0].comment == nested_reply.0);
+    }
+
+    #[test]
+    fn comment_tree_respects_depth_limit() {
+        let post_id = Id::gen();
+        let root = comment(&post_id, None);
+        let reply = comment(&post_id, Some(&root.0));
+        let nested_reply = comment(&post_id, Some(&reply.0));
+
+        let tree = build_comment_tree(vec![root, reply, nested_reply], None, &options(1, 5));
+
+        let reply_node = &tree.comments[0].replies[0];
+        assert!(reply_node.reply_count == 1);
+        assert!(reply_node.replies.is_empty());
+    }
+
+    #[test]
+    fn comment_tree_limits_replies() {
+        let post_id = Id::gen();
+        let root = comment(&post_id, None);
+        let replies = (0..5)
+            .map(|_| comment(&post_id, Some(&root.0)))
+            .collect::<Vec<_>>();
+
+        let mut comments = vec![root];
+        comments.extend(replies);
+
+        let tree = build_comment_tree(comments, None, &options(3, 2));
+
+        assert!(tree.comments[0].reply_count == 5);
+        assert!(tree.comments[0].replies.len() == 2);
+    }
+
+    #[test]
+    fn comment_tree_paginates_replies_of_comment() {
+        let post_id = Id::gen();
+        let root = comment(&post_id, None);
+        let replies = (0..5)
+            .map(|_| comment(&post_id, Some(&root.0)))
+            .collect::<Vec<_>>();
+
+        let mut comments = vec![root.clone()];
+        comments.extend(replies.clone());
+
+        let tree = build_comment_tree(
+            comments,
+            Some(&root.0.id),
+            &CommentTreeOptions {
+                offset: 2,
+                limit: 2,
+                max_depth: 3,
+                replies_limit: 3,
+            },
+        );
+
+        assert!(tree.total == 5);
+        assert!(tree.comments.len() == 2);
+        assert!(tree.comments[0].comment == replies[2].0);
+    }
+
+    #[test]
+    fn comment_tree_skips_orphaned_replies() {
+        let post_id = Id::gen();
+        let root = comment(&post_id, None);
+        let deleted = comment(&post_id, None);
+        let orphan = comment(&post_id, Some(&deleted.0));
+
+        let tree = build_comment_tree(vec![root, orphan], None, &options(3, 3));
+
+        assert!(tree.total == 1);
+        assert!(tree.comments[0].reply_count == 0);
+    }
+}
@@ -0,0 +1,156 @@
+#[cfg(test)]
+mod tests {
+    use crate::domain::{
+        content_filter::{
+            CompiledContentFilters, ContentFilter, ContentFilterAction, ContentFilterKind,
+        },
+        Id,
+    };
+
+    fn filter(
+        pattern: &str,
+        kind: ContentFilterKind,
+        action: ContentFilterAction,
+    ) -> ContentFilter {
+        ContentFilter::new(pattern.into(), kind, action, Id::gen()).unwrap()
+    }
+
+    #[test]
+    fn new_filter_trims_pattern() {
+        let filter = filter(
+            "  spam  ",
+            ContentFilterKind::Term,
+            ContentFilterAction::Mask,
+        );
+
+        assert_eq!(filter.pattern, "spam");
+    }
+
+    #[test]
+    fn new_filter_empty_pattern() {
+        let filter = ContentFilter::new(
+            "   ".into(),
+            ContentFilterKind::Term,
+            ContentFilterAction::Mask,
+            Id::gen(),
+        );
+
+        assert!(filter.is_err());
+    }
+
+    #[test]
+    fn new_filter_invalid_regex() {
+        let filter = ContentFilter::new(
+            "(unclosed".into(),
+            ContentFilterKind::Pattern,
+            ContentFilterAction::Reject,
+            Id::gen(),
+        );
+
+        assert!(filter.is_err());
+    }
+
+    #[test]
+    fn term_with_regex_characters_is_literal() {
+        let filter = filter(
+            "(unclosed",
+            ContentFilterKind::Term,
+            ContentFilterAction::Hold,
+        );
+        let filtered = CompiledContentFilters::new(vec![filter]).apply("an (unclosed paren");
+
+        assert_eq!(filtered.hits.len(), 1);
+    }
+
+    #[test]
+    fn term_matches_whole_words_ignoring_case() {
+        let filter = filter("spam", ContentFilterKind::Term, ContentFilterAction::Mask);
+        let filtered =
+            CompiledContentFilters::new(vec![filter]).apply("No SPAM, only spammers and spam.");
+
+        assert_eq!(filtered.text, "No ****, only spammers and ****.");
+        assert_eq!(filtered.hits.len(), 2);
+        assert_eq!(filtered.hits[0].matched, "SPAM");
+    }
+
+    #[test]
+    fn term_ending_with_symbol_matches() {
+        let filter = filter("c++", ContentFilterKind::Term, ContentFilterAction::Mask);
+        let filtered = CompiledContentFilters::new(vec![filter]).apply("I write c++ daily");
+
+        assert_eq!(filtered.text, "I write *** daily");
+    }
+
+    #[test]
+    fn mask_counts_characters_not_bytes() {
+        let filter = filter("źle", ContentFilterKind::Term, ContentFilterAction::Mask);
+        let filtered = CompiledContentFilters::new(vec![filter]).apply("bardzo źle");
+
+        assert_eq!(filtered.text, "bardzo ***");
+    }
+
+    #[test]
+    fn pattern_matches() {
+        let filter = filter(
+            r"buy\s+followers",
+            ContentFilterKind::Pattern,
+            ContentFilterAction::Mask,
+        );
+        let filtered = CompiledContentFilters::new(vec![filter]).apply("Buy   followers now");
+
+        assert_eq!(filtered.text, "*************** now");
+    }
+
+    #[test]
+    fn hold_and_reject_keep_text() {
+        let filters = CompiledContentFilters::new(vec![
+            filter("bad", ContentFilterKind::Term, ContentFilterAction::Hold),
+            filter(
+                "worse",
+                ContentFilterKind::Term,
+                ContentFilterAction::Reject,
+            ),
+        ]);
+        let filtered = filters.apply("bad and worse");
+
+        assert_eq!(filtered.text, "bad and worse");
+        assert_eq!(filtered.hits.len(), 2);
+    }
+
+    #[test]
+    fn strongest_action_wins() {
+        let filters = CompiledContentFilters::new(vec![
+            filter("mild", ContentFilterKind::Term, ContentFilterAction::Mask),
+            filter("bad", ContentFilterKind::Term, ContentFilterAction::Hold),
+        ]);
+
+        assert_eq!(
+            filters.apply("mild and bad").action(),
+            Some(ContentFilterAction::Hold)
+        );
+        assert_eq!(
+            filters.apply("mild").action(),
+            Some(ContentFilterAction::Mask)
+        );
+        assert_eq!(filters.apply("fine").action(), None);
+    }
+
+    #[test]
+    fn compiled_filters_skip_invalid_patterns() {
+        let mut invalid = filter(
+            "spam",
+            ContentFilterKind::Pattern,
+            ContentFilterAction::Reject,
+        );
+        invalid.pattern = "(unclosed".into();
+
+        let filters = CompiledContentFilters::new(vec![
+            invalid,
+            filter("spam", ContentFilterKind::Term, ContentFilterAction::Mask),
+        ]);
+        let filtered = filters.apply("(unclosed spam");
+
+        assert_eq!(filtered.text, "(unclosed ****");
+        assert_eq!(filtered.action(), Some(ContentFilterAction::Mask));
+    }
+}
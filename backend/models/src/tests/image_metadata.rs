@@ -0,0 +1,147 @@
+#[cfg(test)]
+mod tests {
+    use base64::{engine::general_purpose::STANDARD, Engine};
+    use chrono::NaiveDate;
+
+    use crate::domain::image_metadata::{strip_identifying_metadata, DataUrlImage, ImageMetadata};
+
+    fn ifd_entry(tag: u16, kind: u16, count: u32, value: [u8; 4]) -> Vec<u8> {
+        let mut entry = vec![];
+        entry.extend(tag.to_le_bytes());
+        entry.extend(kind.to_le_bytes());
+        entry.extend(count.to_le_bytes());
+        entry.extend(value);
+        entry
+    }
+
+    fn rationals(values: [(u32, u32); 3]) -> Vec<u8> {
+        values
+            .iter()
+            .flat_map(|(numerator, denominator)| {
+                [numerator.to_le_bytes(), denominator.to_le_bytes()].concat()
+            })
+            .collect()
+    }
+
+    /// TIFF with orientation, time and the GPS position of Brno (49°11'42" N, 16°36'24" E)
+    fn tiff() -> Vec<u8> {
+        let ifd0 = 8u32;
+        let date_time = ifd0 + 2 + 3 * 12 + 4;
+        let gps_ifd = date_time + 20;
+        let latitude = gps_ifd + 2 + 4 * 12 + 4;
+        let longitude = latitude + 24;
+
+        let mut tiff = b"II\x2A\x00".to_vec();
+        tiff.extend(ifd0.to_le_bytes());
+
+        tiff.extend(3u16.to_le_bytes());
+        tiff.extend(ifd_entry(0x0112, 3, 1, [6, 0, 0, 0]));
+        tiff.extend(ifd_entry(0x0132, 2, 20, date_time.to_le_bytes()));
+        tiff.extend(ifd_entry(0x8825, 4, 1, gps_ifd.to_le_bytes()));
+        tiff.extend(0u32.to_le_bytes());
+        tiff.extend(b"2024:06:01 18:30:05\0");
+
+        tiff.extend(4u16.to_le_bytes());
+        tiff.extend(ifd_entry(0x0001, 2, 2, [b'N', 0, 0, 0]));
+        tiff.extend(ifd_entry(0x0002, 5, 3, latitude.to_le_bytes()));
+        tiff.extend(ifd_entry(0x0003, 2, 2, [b'E', 0, 0, 0]));
+        tiff.extend(ifd_entry(0x0004, 5, 3, longitude.to_le_bytes()));
+        tiff.extend(0u32.to_le_bytes());
+        tiff.extend(rationals([(49, 1), (11, 1), (42, 1)]));
+        tiff.extend(rationals([(16, 1), (36, 1), (24, 1)]));
+
+        tiff
+    }
+
+    fn jpeg(tiff: &[u8]) -> Vec<u8> {
+        let mut jpeg = vec![0xFF, 0xD8];
+        // APP0 JFIF
+        jpeg.extend([0xFF, 0xE0, 0x00, 0x07]);
+        jpeg.extend(b"JFIF\0");
+        // APP1 Exif
+        jpeg.extend([0xFF, 0xE1]);
+        jpeg.extend(((2 + 6 + tiff.len()) as u16).to_be_bytes());
+        jpeg.extend(b"Exif\0\0");
+        jpeg.extend(tiff);
+        // Comment
+        jpeg.extend([0xFF, 0xFE, 0x00, 0x06]);
+        jpeg.extend(b"Jana");
+        // Start of scan, image data and the end of the image
+        jpeg.extend([0xFF, 0xDA, 0x00, 0x04, 0x01, 0x02, 0x03, 0x04, 0xFF, 0xD9]);
+        jpeg
+    }
+
+    #[test]
+    fn data_url_image() {
+        let image = DataUrlImage::parse("data:image/JPEG;base64,/9j/2Q==");
+
+        assert!(image.is_ok());
+
+        let image = image.unwrap();
+        assert_eq!(image.mime_type, "image/jpeg");
+        assert_eq!(image.bytes, vec![0xFF, 0xD8, 0xFF, 0xD9]);
+        assert!(image.has_exif_format());
+    }
+
+    #[test]
+    fn data_url_image_keeps_bytes() {
+        let original = jpeg(&tiff());
+
+        let image = DataUrlImage::parse(&format!(
+            "data:image/jpeg;base64,{}",
+            STANDARD.encode(&original)
+        ))
+        .unwrap();
+
+        assert_eq!(image.bytes, original);
+    }
+
+    #[test]
+    fn data_url_image_invalid() {
+        assert!(DataUrlImage::parse("https://example.com/image.jpg").is_err());
+        assert!(DataUrlImage::parse("data:text/plain;base64,YWhvag==").is_err());
+        assert!(DataUrlImage::parse("data:image/png;base64,not base64!").is_err());
+    }
+
+    #[test]
+    fn image_metadata() {
+        let metadata = ImageMetadata::read(&jpeg(&tiff()));
+
+        assert_eq!(
+            metadata.captured_at,
+            NaiveDate::from_ymd_opt(2024, 6, 1).and_then(|date| date.and_hms_opt(18, 30, 5))
+        );
+        assert_eq!(metadata.orientation, Some(6));
+
+        let location = metadata.location.unwrap();
+        assert!((location.latitude - 49.195).abs() < 0.001);
+        assert!((location.longitude - 16.6067).abs() < 0.001);
+    }
+
+    #[test]
+    fn image_metadata_without_exif() {
+        assert_eq!(
+            ImageMetadata::read(&[0xFF, 0xD8, 0xFF, 0xD9]),
+            ImageMetadata::default()
+        );
+    }
+
+    #[test]
+    fn strip_jpeg_metadata_keeps_orientation() {
+        let original = jpeg(&tiff());
+
+        let stripped = strip_identifying_metadata(&original, Some(6)).unwrap();
+        let metadata = ImageMetadata::read(&stripped);
+
+        assert_eq!(metadata.orientation, Some(6));
+        assert_eq!(metadata.location, None);
+        assert_eq!(metadata.captured_at, None);
+        assert!(!stripped.windows(4).any(|window| window == b"Jana"));
+        assert!(stripped.ends_with(&[0xFF, 0xDA, 0x00, 0x04, 0x01, 0x02, 0x03, 0x04, 0xFF, 0xD9]));
+    }
+
+    #[test]
+    fn strip_jpeg_metadata_invalid() {
+        assert!(strip_identifying_metadata(&[0xFF, 0xD8, 0x00, 0x01], None).is_err());
+    }
+}
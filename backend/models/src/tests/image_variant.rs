@@ -0,0 +1,160 @@
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use image::{DynamicImage, ImageFormat};
+
+    use crate::domain::{
+        image_metadata::DataUrlImage,
+        image_variant::{
+            can_process_image, process_image, variant_dimensions, ImageLimits, ImageVariant,
+            ImageVariantKind,
+        },
+        post::{Post, PostType, PostVisibilityType},
+        Id,
+    };
+
+    fn png(width: u32, height: u32) -> Vec<u8> {
+        let mut bytes = vec![];
+        DynamicImage::new_rgb8(width, height)
+            .write_to(&mut Cursor::new(&mut bytes), ImageFormat::Png)
+            .unwrap();
+        bytes
+    }
+
+    fn variant(kind: ImageVariantKind) -> ImageVariant {
+        ImageVariant::new(kind, 320, 240, "https://example.com/image.webp".into()).unwrap()
+    }
+
+    #[test]
+    fn variant_dimensions_scale_down() {
+        assert_eq!(variant_dimensions(4000, 3000, 1080), (1080, 810));
+        assert_eq!(variant_dimensions(3000, 4000, 1080), (810, 1080));
+        assert_eq!(variant_dimensions(10000, 1, 320), (320, 1));
+    }
+
+    #[test]
+    fn variant_dimensions_never_upscale() {
+        assert_eq!(variant_dimensions(800, 600, 1080), (800, 600));
+        assert_eq!(variant_dimensions(1080, 1080, 1080), (1080, 1080));
+    }
+
+    #[test]
+    fn image_limits() {
+        let limits = ImageLimits {
+            max_bytes: 1000,
+            max_pixels: 100,
+        };
+
+        assert!(limits.check_size(1000).is_ok());
+        assert!(limits.check_size(1001).is_err());
+        assert!(limits.check_dimensions(10, 10).is_ok());
+        assert!(limits.check_dimensions(11, 10).is_err());
+    }
+
+    #[test]
+    fn process_image_variants() {
+        let variants = process_image(&png(2500, 500), None, &ImageLimits::default()).unwrap();
+
+        let sizes: Vec<_> = variants
+            .iter()
+            .map(|variant| (variant.kind, variant.width, variant.height))
+            .collect();
+
+        assert_eq!(
+            sizes,
+            vec![
+                (ImageVariantKind::Full, 2048, 410),
+                (ImageVariantKind::Feed, 1080, 216),
+                (ImageVariantKind::Thumbnail, 320, 64),
+            ]
+        );
+
+        for variant in variants {
+            assert_eq!(&variant.bytes[..4], b"RIFF");
+            assert_eq!(&variant.bytes[8..12], b"WEBP");
+        }
+    }
+
+    #[test]
+    fn process_image_applies_orientation() {
+        // Orientation 6 means the camera was rotated, the image is turned by 90 degrees
+        let variants = process_image(&png(40, 20), Some(6), &ImageLimits::default()).unwrap();
+
+        assert!(variants
+            .iter()
+            .all(|variant| variant.width == 20 && variant.height == 40));
+    }
+
+    #[test]
+    fn process_image_over_limits() {
+        let image = png(40, 20);
+
+        let too_many_pixels = ImageLimits {
+            max_pixels: 799,
+            ..ImageLimits::default()
+        };
+        let too_many_bytes = ImageLimits {
+            max_bytes: image.len() - 1,
+            ..ImageLimits::default()
+        };
+
+        assert!(process_image(&image, None, &too_many_pixels).is_err());
+        assert!(process_image(&image, None, &too_many_bytes).is_err());
+    }
+
+    #[test]
+    fn process_image_invalid() {
+        assert!(process_image(b"not an image", None, &ImageLimits::default()).is_err());
+    }
+
+    #[test]
+    fn heic_kept_without_variants() {
+        let heic = DataUrlImage {
+            mime_type: "image/heic".into(),
+            bytes: b"\0\0\0\x18ftypheic\0\0\0\0mif1heic".to_vec(),
+        };
+
+        // HEIC uploads are stored as they are instead of failing in `process_image`
+        assert!(heic.is_heif());
+        assert!(!can_process_image(&heic.bytes));
+        assert!(process_image(&heic.bytes, None, &ImageLimits::default()).is_err());
+        assert!(can_process_image(&png(40, 20)));
+    }
+
+    #[test]
+    fn image_variant_invalid() {
+        assert!(
+            ImageVariant::new(ImageVariantKind::Feed, 0, 240, "https://example.com".into())
+                .is_err()
+        );
+        assert!(ImageVariant::new(ImageVariantKind::Feed, 320, 240, "not a url".into()).is_err());
+    }
+
+    #[test]
+    fn post_with_image_variants() {
+        let post = Post::new(
+            "Vylet".into(),
+            "".into(),
+            Id::gen(),
+            PostType::Photo,
+            "https://example.com/image.webp".into(),
+            PostVisibilityType::Public,
+            None,
+        )
+        .unwrap();
+
+        let variants = vec![
+            variant(ImageVariantKind::Full),
+            variant(ImageVariantKind::Feed),
+            variant(ImageVariantKind::Thumbnail),
+        ];
+
+        assert!(post.clone().with_image_variants(variants.clone()).is_ok());
+
+        let mut too_many = variants;
+        too_many.push(variant(ImageVariantKind::Thumbnail));
+
+        assert!(post.with_image_variants(too_many).is_err());
+    }
+}
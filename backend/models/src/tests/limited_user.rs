@@ -0,0 +1,62 @@
+#[cfg(test)]
+mod tests {
+    use crate::domain::{
+        user::{User, UserType},
+        Id,
+    };
+
+    #[test]
+    fn content_of_regular_user_is_visible() {
+        let user = User::new(
+            "limited".into(),
+            None,
+            None,
+            None,
+            UserType::Regular,
+            Id::gen(),
+            "password_hash".into(),
+        )
+        .unwrap();
+
+        assert!(!user.is_limited);
+        assert!(user.is_content_visible_to(None, false));
+        assert!(user.is_content_visible_to(Some(&Id::gen()), false));
+    }
+
+    #[test]
+    fn content_of_limited_user_is_hidden_from_others() {
+        let mut user = User::new(
+            "limited".into(),
+            None,
+            None,
+            None,
+            UserType::Regular,
+            Id::gen(),
+            "password_hash".into(),
+        )
+        .unwrap();
+        user.set_limited(true);
+
+        assert!(!user.is_content_visible_to(None, false));
+        assert!(!user.is_content_visible_to(Some(&Id::gen()), false));
+    }
+
+    #[test]
+    fn content_of_limited_user_is_visible_to_themselves_and_moderators() {
+        let mut user = User::new(
+            "limited".into(),
+            None,
+            None,
+            None,
+            UserType::Regular,
+            Id::gen(),
+            "password_hash".into(),
+        )
+        .unwrap();
+        user.set_limited(true);
+
+        assert!(user.is_content_visible_to(Some(&user.id), false));
+        assert!(user.is_content_visible_to(Some(&Id::gen()), true));
+        assert!(user.is_content_visible_to(None, true));
+    }
+}
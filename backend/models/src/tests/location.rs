@@ -2,7 +2,17 @@
 mod tests {
     use uuid::Uuid;
 
-    use crate::domain::{email::Email, group::Group, location::Location, user::User, Id};
+    use crate::{
+        domain::{
+            email::Email,
+            group::Group,
+            location::{name_similarity, normalize_location_name, BoundingBox, GeoPoint, Location},
+            post::{Post, PostType, PostVisibilityType},
+            user::User,
+            Id,
+        },
+        schema,
+    };
 
     #[test]
     fn location() {
@@ -29,4 +39,114 @@ mod tests {
 
         assert!(location.is_err());
     }
+
+    #[test]
+    fn location_invalid_coordinates() {
+        assert!(Location::new(None, "ahoj".into(), 90.5, 0.0).is_err());
+        assert!(Location::new(None, "ahoj".into(), 0.0, -180.5).is_err());
+        assert!(Location::new(None, "ahoj".into(), -90.0, 180.0).is_ok());
+    }
+
+    #[test]
+    fn geo_point_distance() {
+        let brno = GeoPoint::new(49.1951, 16.6068).unwrap();
+        let prague = GeoPoint::new(50.0755, 14.4378).unwrap();
+
+        let distance = brno.distance_km(&prague);
+
+        assert!((distance - 184.0).abs() < 2.0);
+        assert_eq!(brno.distance_km(&brno), 0.0);
+    }
+
+    #[test]
+    fn bounding_box() {
+        let bbox = BoundingBox::new(48.5, 12.0, 51.1, 18.9).unwrap();
+
+        assert!(!bbox.crosses_antimeridian());
+        assert!(bbox.contains(&GeoPoint::new(49.1951, 16.6068).unwrap()));
+        assert!(!bbox.contains(&GeoPoint::new(52.52, 13.405).unwrap()));
+    }
+
+    #[test]
+    fn bounding_box_across_antimeridian() {
+        let bbox = BoundingBox::new(-20.0, 170.0, 10.0, -170.0).unwrap();
+
+        assert!(bbox.crosses_antimeridian());
+        assert!(bbox.contains(&GeoPoint::new(0.0, 179.0).unwrap()));
+        assert!(bbox.contains(&GeoPoint::new(0.0, -175.0).unwrap()));
+        assert!(!bbox.contains(&GeoPoint::new(0.0, 0.0).unwrap()));
+    }
+
+    #[test]
+    fn bounding_box_invalid() {
+        assert!(BoundingBox::new(10.0, 0.0, -10.0, 5.0).is_err());
+        assert!(BoundingBox::new(0.0, 0.0, 95.0, 5.0).is_err());
+    }
+
+    #[test]
+    fn location_name_normalization() {
+        assert_eq!(normalize_location_name("  Brno,  CZ "), "brno cz");
+        assert_eq!(
+            normalize_location_name("Náměstí Svobody"),
+            "namesti svobody"
+        );
+    }
+
+    #[test]
+    fn location_name_similarity() {
+        assert_eq!(name_similarity("Brno", "brno"), 1.0);
+        assert_eq!(name_similarity("Brno", "Brno, CZ"), 0.9);
+        assert!(name_similarity("Špilberk", "Spilberk castle") >= 0.8);
+        assert!(name_similarity("Spilbrek", "Spilberk") >= 0.7);
+        assert!(name_similarity("Brno", "Praha") < 0.5);
+        assert_eq!(name_similarity("", "Brno"), 0.0);
+    }
+
+    #[test]
+    fn location_duplicates() {
+        let brno = Location::new(None, "Brno".into(), 49.1951, 16.6068).unwrap();
+        let brno_cz = Location::new(None, "Brno, CZ".into(), 49.1953, 16.6071).unwrap();
+        let brno_far = Location::new(None, "Brno".into(), 49.3, 16.6068).unwrap();
+        let other = Location::new(None, "Zelný trh".into(), 49.1952, 16.6069).unwrap();
+
+        assert!(brno.is_likely_duplicate_of(&brno_cz));
+        assert!(!brno.is_likely_duplicate_of(&brno_far));
+        assert!(!brno.is_likely_duplicate_of(&other));
+    }
+
+    #[test]
+    fn location_detail_round_trip() {
+        let location = Location::new(
+            Some("https://www.krejzac.cz".into()),
+            "Brno".into(),
+            49.1951,
+            16.6068,
+        )
+        .unwrap();
+
+        let model: schema::location::Model = location.clone().into();
+
+        assert!(Location::from(model) == location);
+    }
+
+    #[test]
+    fn post_taken_at_location() {
+        let location = Location::new(None, "Brno".into(), 49.1951, 16.6068).unwrap();
+        let post = Post::new(
+            "Muj post".into(),
+            "hmmmmmmm".into(),
+            Id::gen(),
+            PostType::Photo,
+            "https://www.krejzac.cz".into(),
+            PostVisibilityType::Public,
+            Some(Id::new(location.id.id)),
+        )
+        .unwrap();
+
+        // The feed of a location matches posts by the stored location id
+        let model: schema::post::Model = post.into();
+
+        assert!(model.location_id == Some(location.id.id));
+        assert!(Post::from(model).location_id.map(|id| id.id) == Some(location.id.id));
+    }
 }
@@ -0,0 +1,104 @@
+#[cfg(test)]
+mod tests {
+    use crate::domain::{
+        mention::{
+            mentioned_usernames, parse_mentions, stored_mention_spans, Mention, MentionSpan,
+        },
+        user::{User, UserType},
+        Id,
+    };
+
+    #[test]
+    fn parse_single_mention() {
+        let mentions = parse_mentions("hello @john_doe!");
+
+        assert_eq!(
+            mentions,
+            vec![MentionSpan {
+                username: "john_doe".into(),
+                start: 6,
+                end: 15,
+            }]
+        );
+    }
+
+    #[test]
+    fn parse_mention_at_start() {
+        let mentions = parse_mentions("@alice look");
+
+        assert_eq!(mentions.len(), 1);
+        assert_eq!(mentions[0].start, 0);
+        assert_eq!(mentions[0].end, 6);
+    }
+
+    #[test]
+    fn parse_mention_offsets_are_in_characters() {
+        let mentions = parse_mentions("čau @bob");
+
+        assert_eq!(mentions[0].start, 4);
+        assert_eq!(mentions[0].end, 8);
+    }
+
+    #[test]
+    fn email_is_not_mention() {
+        assert!(parse_mentions("write to john@example.com").is_empty());
+    }
+
+    #[test]
+    fn invalid_usernames_are_ignored() {
+        assert!(parse_mentions("@ab @@alice @ @this_name_is_too_long").is_empty());
+    }
+
+    #[test]
+    fn mentioned_usernames_are_unique() {
+        let usernames = mentioned_usernames("@bob and @alice, @bob again");
+
+        assert_eq!(usernames, vec!["bob".to_string(), "alice".to_string()]);
+    }
+
+    #[test]
+    fn only_stored_mentions_are_highlighted() {
+        let post_id = Id::gen();
+        let comment_id = Id::gen();
+        let alice = User::new(
+            "alice".into(),
+            None,
+            None,
+            None,
+            UserType::Regular,
+            Id::gen(),
+            "hash".into(),
+        )
+        .unwrap();
+        let bob = User::new(
+            "bob".into(),
+            None,
+            None,
+            None,
+            UserType::Regular,
+            Id::gen(),
+            "hash".into(),
+        )
+        .unwrap();
+        let mentions = vec![
+            (
+                Mention::new(post_id.clone(), None, alice.id.clone(), Id::gen()),
+                alice,
+            ),
+            (
+                Mention::new(post_id, Some(comment_id.clone()), bob.id.clone(), Id::gen()),
+                bob,
+            ),
+        ];
+        let text = "@alice @bob @nobody";
+
+        let description_spans = stored_mention_spans(text, None, &mentions);
+        let comment_spans = stored_mention_spans(text, Some(&comment_id), &mentions);
+
+        assert_eq!(description_spans.len(), 1);
+        assert_eq!(description_spans[0].username, "alice");
+        assert_eq!(comment_spans.len(), 1);
+        assert_eq!(comment_spans[0].username, "bob");
+        assert!(stored_mention_spans(text, Some(&Id::gen()), &mentions).is_empty());
+    }
+}
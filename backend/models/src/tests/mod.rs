@@ -1,6 +1,23 @@
+pub mod account_deletion;
+pub mod audit_log;
+pub mod comment_tree;
+pub mod content_filter;
 pub mod email;
 pub mod group;
+pub mod image_metadata;
+pub mod image_variant;
+pub mod limited_user;
 pub mod location;
+pub mod mention;
 pub mod post;
 pub mod post_comment;
+pub mod post_tag;
+pub mod reaction;
+pub mod report;
+pub mod saved_collection;
+pub mod stats;
+pub mod tag_follow;
 pub mod user;
+pub mod user_management;
+pub mod user_relation;
+pub mod user_suspension;
@@ -5,7 +5,12 @@ mod tests {
     use uuid::Uuid;
 
     use crate::domain::{
-        email::Email, group::Group, post::Post, post_comment::PostComment, user::User, Id,
+        email::Email,
+        group::Group,
+        post::{CommentPolicy, Post},
+        post_comment::PostComment,
+        user::User,
+        Id,
     };
 
     #[test]
@@ -93,4 +98,32 @@ mod tests {
 
         assert!(post.is_err());
     }
+
+    #[test]
+    fn post_comment_policy() {
+        let mut post = Post::new(
+            "Muj post".into(),
+            "hmmmmmmm".into(),
+            Id::gen(),
+            crate::domain::post::PostType::Photo,
+            "https://www.krejzac.cz".into(),
+            crate::domain::post::PostVisibilityType::Public,
+            None,
+        )
+        .unwrap();
+        let author_id = post.author_id.clone();
+        let user_id = Id::gen();
+
+        assert!(post.comment_policy == CommentPolicy::Everyone);
+        assert!(post.accepts_comments_from(&user_id, false));
+
+        post.comment_policy = CommentPolicy::Followers;
+        assert!(!post.accepts_comments_from(&user_id, false));
+        assert!(post.accepts_comments_from(&user_id, true));
+        assert!(post.accepts_comments_from(&author_id, false));
+
+        post.comment_policy = CommentPolicy::Nobody;
+        assert!(!post.accepts_comments_from(&user_id, true));
+        assert!(!post.accepts_comments_from(&author_id, false));
+    }
 }
@@ -35,4 +35,47 @@ mod tests {
 
         assert!(post_comment.is_err());
     }
+
+    #[test]
+    fn post_comment_edit() {
+        let mut post_comment = PostComment::new(Id::gen(), Id::gen(), "ahoj".into(), None).unwrap();
+
+        assert!(!post_comment.is_edited());
+        assert!(post_comment.edit("nazdar".into()).is_ok());
+        assert!(post_comment.content == "nazdar");
+        assert!(post_comment.is_edited());
+    }
+
+    #[test]
+    fn post_comment_edit_content_too_long() {
+        let mut post_comment = PostComment::new(Id::gen(), Id::gen(), "ahoj".into(), None).unwrap();
+
+        assert!(post_comment.edit(repeat("X").take(256).collect()).is_err());
+    }
+
+    #[test]
+    fn post_comment_hidden_visibility() {
+        let post_author_id = Id::gen();
+        let commenter_id = Id::gen();
+        let viewer_id = Id::gen();
+        let mut post_comment =
+            PostComment::new(Id::gen(), commenter_id.clone(), "ahoj".into(), None).unwrap();
+
+        assert!(post_comment.is_visible_to(Some(&viewer_id), &post_author_id, false));
+        assert!(post_comment.is_visible_to(None, &post_author_id, false));
+
+        post_comment.hide();
+
+        assert!(post_comment.is_hidden());
+        assert!(!post_comment.is_visible_to(Some(&viewer_id), &post_author_id, false));
+        assert!(!post_comment.is_visible_to(None, &post_author_id, false));
+        assert!(post_comment.is_visible_to(Some(&commenter_id), &post_author_id, false));
+        assert!(post_comment.is_visible_to(Some(&post_author_id), &post_author_id, false));
+        assert!(post_comment.is_visible_to(Some(&viewer_id), &post_author_id, true));
+
+        post_comment.unhide();
+
+        assert!(!post_comment.is_hidden());
+        assert!(post_comment.is_visible_to(Some(&viewer_id), &post_author_id, false));
+    }
 }
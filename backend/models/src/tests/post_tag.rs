@@ -0,0 +1,82 @@
+#[cfg(test)]
+mod tests {
+    use std::iter::repeat;
+
+    use crate::domain::{
+        post_tag::{extract_hashtags, normalize_tag, trending_score, PostTag, TrendingTag},
+        Id,
+    };
+
+    #[test]
+    fn post_tag() {
+        let tag = PostTag::new(Id::gen(), "#Sunset".into());
+
+        assert!(tag.is_ok());
+        assert_eq!(tag.unwrap().tag, "sunset");
+    }
+
+    #[test]
+    fn post_tag_unicode() {
+        let tag = PostTag::new(Id::gen(), "Příroda_2024".into());
+
+        assert!(tag.is_ok());
+        assert_eq!(tag.unwrap().tag, "příroda_2024");
+    }
+
+    #[test]
+    fn post_tag_invalid_characters() {
+        assert!(PostTag::new(Id::gen(), "sun-set".into()).is_err());
+        assert!(PostTag::new(Id::gen(), "sun set".into()).is_err());
+    }
+
+    #[test]
+    fn post_tag_invalid_length() {
+        assert!(PostTag::new(Id::gen(), "ab".into()).is_err());
+        assert!(PostTag::new(Id::gen(), repeat("a").take(31).collect()).is_err());
+    }
+
+    #[test]
+    fn normalize_tag_composes_characters() {
+        // "e" followed by a combining acute accent
+        let decomposed = "cafe\u{301}";
+
+        assert_eq!(normalize_tag(decomposed), "café");
+        assert_eq!(normalize_tag("CAFÉ"), normalize_tag(decomposed));
+    }
+
+    #[test]
+    fn extract_hashtags_from_text() {
+        let tags = extract_hashtags("Golden hour #Sunset at the #beach, again #sunset!");
+
+        assert_eq!(tags, vec!["sunset".to_string(), "beach".to_string()]);
+    }
+
+    #[test]
+    fn extract_hashtags_skips_invalid() {
+        let tags = extract_hashtags("#ab c#notatag ##double #ok_tag");
+
+        assert_eq!(tags, vec!["ok_tag".to_string()]);
+    }
+
+    #[test]
+    fn extract_hashtags_unicode() {
+        let tags = extract_hashtags("#Žluťoučký kůň");
+
+        assert_eq!(tags, vec!["žluťoučký".to_string()]);
+    }
+
+    #[test]
+    fn trending_score_weights_activity() {
+        assert_eq!(trending_score(0, 0, 0), 0.0);
+        assert_eq!(trending_score(2, 0, 0), 2.0);
+        assert_eq!(trending_score(1, 4, 1), 4.0);
+    }
+
+    #[test]
+    fn trending_tag_ranks_engagement() {
+        let quiet = TrendingTag::new("quiet".into(), 3, 0, 0);
+        let popular = TrendingTag::new("popular".into(), 1, 6, 2);
+
+        assert!(popular.score > quiet.score);
+    }
+}
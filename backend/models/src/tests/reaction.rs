@@ -0,0 +1,58 @@
+#[cfg(test)]
+mod tests {
+    use crate::domain::reaction::{
+        count_reactions, ReactionCount, ReactionKinds, DEFAULT_REACTION_KIND,
+    };
+
+    #[test]
+    fn parse_reaction_kinds() {
+        let kinds = ReactionKinds::parse(" Love, haha,,love ");
+
+        assert_eq!(kinds.kinds(), &["like", "love", "haha"]);
+    }
+
+    #[test]
+    fn reaction_kinds_always_contain_default() {
+        let kinds = ReactionKinds::parse("");
+
+        assert!(kinds.contains(DEFAULT_REACTION_KIND));
+        assert_eq!(kinds.kinds().len(), 1);
+    }
+
+    #[test]
+    fn default_reaction_kinds() {
+        let kinds = ReactionKinds::default();
+
+        assert!(kinds.contains("like"));
+        assert!(kinds.contains("angry"));
+        assert!(!kinds.contains("poop"));
+    }
+
+    #[test]
+    fn count_reactions_by_kind() {
+        let counts = count_reactions(["love", "like", "love", "wow", "like", "love"]);
+
+        assert_eq!(
+            counts,
+            vec![
+                ReactionCount {
+                    kind: "love".to_string(),
+                    count: 3
+                },
+                ReactionCount {
+                    kind: "like".to_string(),
+                    count: 2
+                },
+                ReactionCount {
+                    kind: "wow".to_string(),
+                    count: 1
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn count_no_reactions() {
+        assert!(count_reactions([]).is_empty());
+    }
+}
@@ -0,0 +1,150 @@
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use crate::domain::{
+        report::{Report, ReportOutcome, ReportReason, ReportStatus, ReportTargetType},
+        Id,
+    };
+
+    fn report() -> Report {
+        Report::new(
+            Id::gen(),
+            ReportTargetType::Post,
+            Uuid::new_v4(),
+            ReportReason::Spam,
+            "  Buy followers here  ".into(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn new_report() {
+        let report = report();
+
+        assert_eq!(report.status, ReportStatus::Open);
+        assert_eq!(report.details, "Buy followers here");
+        assert!(report.moderator_id.is_none());
+        assert!(!report.is_closed());
+    }
+
+    #[test]
+    fn report_other_reason_requires_details() {
+        let report = Report::new(
+            Id::gen(),
+            ReportTargetType::User,
+            Uuid::new_v4(),
+            ReportReason::Other,
+            "   ".into(),
+        );
+
+        assert!(report.is_err());
+    }
+
+    #[test]
+    fn report_details_too_long() {
+        let report = Report::new(
+            Id::gen(),
+            ReportTargetType::Comment,
+            Uuid::new_v4(),
+            ReportReason::Harassment,
+            "a".repeat(1001),
+        );
+
+        assert!(report.is_err());
+    }
+
+    #[test]
+    fn claim_report() {
+        let mut report = report();
+        let moderator = Id::gen();
+
+        assert!(report.claim(moderator.clone()).is_ok());
+        assert_eq!(report.status, ReportStatus::Claimed);
+        assert_eq!(report.moderator_id, Some(moderator.clone()));
+        assert!(report.claimed_at.is_some());
+
+        // Claiming again by the same moderator keeps the claim
+        let claimed_at = report.claimed_at;
+        assert!(report.claim(moderator).is_ok());
+        assert_eq!(report.claimed_at, claimed_at);
+    }
+
+    #[test]
+    fn claimed_report_is_reserved() {
+        let mut report = report();
+        report.claim(Id::gen()).unwrap();
+
+        assert!(report.claim(Id::gen()).is_err());
+        assert!(report.dismiss(Id::gen(), None).is_err());
+        assert!(report
+            .resolve(Id::gen(), ReportOutcome::PostDeleted, None)
+            .is_err());
+    }
+
+    #[test]
+    fn resolve_report() {
+        let mut report = report();
+        let moderator = Id::gen();
+
+        let result = report.resolve(
+            moderator.clone(),
+            ReportOutcome::PostDeleted,
+            Some(" Obvious spam ".into()),
+        );
+
+        assert!(result.is_ok());
+        assert_eq!(report.status, ReportStatus::Resolved);
+        assert_eq!(report.outcome, Some(ReportOutcome::PostDeleted));
+        assert_eq!(report.resolution_note, Some("Obvious spam".into()));
+        assert_eq!(report.moderator_id, Some(moderator));
+        assert!(report.is_closed());
+        assert!(report.closed_at.is_some());
+    }
+
+    #[test]
+    fn dismiss_report() {
+        let mut report = report();
+
+        assert!(report.dismiss(Id::gen(), Some("".into())).is_ok());
+        assert_eq!(report.status, ReportStatus::Dismissed);
+        assert_eq!(report.outcome, Some(ReportOutcome::NoAction));
+        assert_eq!(report.resolution_note, None);
+    }
+
+    #[test]
+    fn closed_report_can_not_change() {
+        let mut report = report();
+        let moderator = Id::gen();
+        report.dismiss(moderator.clone(), None).unwrap();
+
+        assert!(report.claim(moderator.clone()).is_err());
+        assert!(report
+            .resolve(moderator, ReportOutcome::UserBlocked, None)
+            .is_err());
+        assert_eq!(report.status, ReportStatus::Dismissed);
+    }
+
+    #[test]
+    fn report_filtered_content_reason_reserved() {
+        let report = Report::new(
+            Id::gen(),
+            ReportTargetType::Post,
+            Uuid::new_v4(),
+            ReportReason::FilteredContent,
+            "Looks filtered".into(),
+        );
+
+        assert!(report.is_err());
+    }
+
+    #[test]
+    fn flag_report() {
+        let report = Report::flag(ReportTargetType::Comment, Uuid::new_v4(), "a".repeat(1500));
+
+        assert!(report.reporter_id.is_none());
+        assert_eq!(report.reason, ReportReason::FilteredContent);
+        assert_eq!(report.status, ReportStatus::Open);
+        assert_eq!(report.details.len(), 1000);
+    }
+}
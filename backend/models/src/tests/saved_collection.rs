@@ -0,0 +1,44 @@
+#[cfg(test)]
+mod tests {
+    use std::iter::repeat;
+
+    use crate::domain::{saved_collection::SavedCollection, Id};
+
+    #[test]
+    fn saved_collection() {
+        let collection = SavedCollection::new(Id::gen(), "  Trips ".into());
+
+        assert!(collection.is_ok());
+        assert_eq!(collection.unwrap().name, "Trips");
+    }
+
+    #[test]
+    fn saved_collection_name_empty() {
+        let collection = SavedCollection::new(Id::gen(), "   ".into());
+
+        assert!(collection.is_err());
+    }
+
+    #[test]
+    fn saved_collection_name_too_long() {
+        let collection = SavedCollection::new(Id::gen(), repeat("X").take(51).collect());
+
+        assert!(collection.is_err());
+    }
+
+    #[test]
+    fn saved_collection_rename() {
+        let mut collection = SavedCollection::new(Id::gen(), "Trips".into()).unwrap();
+
+        assert!(collection.rename("Food".into()).is_ok());
+        assert_eq!(collection.name, "Food");
+    }
+
+    #[test]
+    fn saved_collection_invalid_rename_keeps_name() {
+        let mut collection = SavedCollection::new(Id::gen(), "Trips".into()).unwrap();
+
+        assert!(collection.rename("".into()).is_err());
+        assert_eq!(collection.name, "Trips");
+    }
+}
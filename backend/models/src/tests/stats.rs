@@ -0,0 +1,65 @@
+#[cfg(test)]
+mod tests {
+    use chrono::{Datelike, NaiveDate, Weekday};
+
+    use crate::domain::stats::{StatsBucket, StatsRange, MAX_STATS_BUCKETS};
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn daily_range_keeps_dates() {
+        let range =
+            StatsRange::new(date(2024, 12, 1), date(2024, 12, 31), StatsBucket::Day).unwrap();
+
+        assert_eq!(range.from, date(2024, 12, 1));
+        assert_eq!(range.to, date(2024, 12, 31));
+        assert_eq!(range.bucket_count(), 31);
+    }
+
+    #[test]
+    fn range_end_is_exclusive() {
+        let range =
+            StatsRange::new(date(2024, 12, 1), date(2024, 12, 1), StatsBucket::Day).unwrap();
+
+        assert_eq!(range.end() - range.start(), chrono::Duration::days(1));
+    }
+
+    #[test]
+    fn weekly_range_covers_whole_weeks() {
+        // Wednesday to Thursday of the following week
+        let range =
+            StatsRange::new(date(2024, 12, 4), date(2024, 12, 12), StatsBucket::Week).unwrap();
+
+        assert_eq!(range.from, date(2024, 12, 2));
+        assert_eq!(range.from.weekday(), Weekday::Mon);
+        assert_eq!(range.to, date(2024, 12, 15));
+        assert_eq!(range.to.weekday(), Weekday::Sun);
+        assert_eq!(range.bucket_count(), 2);
+    }
+
+    #[test]
+    fn reversed_range() {
+        let range = StatsRange::new(date(2024, 12, 2), date(2024, 12, 1), StatsBucket::Day);
+
+        assert!(range.is_err());
+    }
+
+    #[test]
+    fn too_many_buckets() {
+        let today = date(2024, 12, 31);
+
+        assert!(StatsRange::last_days(today, MAX_STATS_BUCKETS, StatsBucket::Day).is_ok());
+        assert!(StatsRange::last_days(today, MAX_STATS_BUCKETS + 1, StatsBucket::Day).is_err());
+        assert!(StatsRange::last_days(today, MAX_STATS_BUCKETS + 1, StatsBucket::Week).is_ok());
+    }
+
+    #[test]
+    fn last_days_ends_today() {
+        let range = StatsRange::last_days(date(2024, 12, 31), 30, StatsBucket::Day).unwrap();
+
+        assert_eq!(range.from, date(2024, 12, 2));
+        assert_eq!(range.to, date(2024, 12, 31));
+    }
+}
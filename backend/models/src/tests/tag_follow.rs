@@ -0,0 +1,18 @@
+#[cfg(test)]
+mod tests {
+    use crate::domain::{tag_follow::TagFollow, Id};
+
+    #[test]
+    fn tag_follow_normalizes_tag() {
+        let follow = TagFollow::new(Id::gen(), "#Sunset");
+
+        assert!(follow.is_ok());
+        assert_eq!(follow.unwrap().tag, "sunset");
+    }
+
+    #[test]
+    fn tag_follow_invalid_tag() {
+        assert!(TagFollow::new(Id::gen(), "ab").is_err());
+        assert!(TagFollow::new(Id::gen(), "not a tag").is_err());
+    }
+}
@@ -0,0 +1,51 @@
+#[cfg(test)]
+mod tests {
+    use crate::domain::{
+        user::{User, UserType},
+        Id,
+    };
+
+    fn user(user_type: UserType) -> User {
+        User::new(
+            "krejzac".into(),
+            None,
+            None,
+            None,
+            user_type,
+            Id::gen(),
+            "hash".into(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn moderator_manages_regular_user() {
+        let target = user(UserType::Regular);
+
+        assert!(target.is_manageable_by(&Id::gen(), &UserType::Moderator));
+        assert!(target.is_manageable_by(&Id::gen(), &UserType::Administrator));
+    }
+
+    #[test]
+    fn same_rank_cannot_manage() {
+        let moderator = user(UserType::Moderator);
+        let administrator = user(UserType::Administrator);
+
+        assert!(!moderator.is_manageable_by(&Id::gen(), &UserType::Moderator));
+        assert!(!administrator.is_manageable_by(&Id::gen(), &UserType::Administrator));
+    }
+
+    #[test]
+    fn regular_user_cannot_manage() {
+        let target = user(UserType::Regular);
+
+        assert!(!target.is_manageable_by(&Id::gen(), &UserType::Regular));
+    }
+
+    #[test]
+    fn nobody_manages_themselves() {
+        let target = user(UserType::Regular);
+
+        assert!(!target.is_manageable_by(&target.id, &UserType::Administrator));
+    }
+}
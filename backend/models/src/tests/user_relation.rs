@@ -0,0 +1,49 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        domain::{
+            user_relation::{UserRelation, UserRelationKind},
+            Id,
+        },
+        schema,
+    };
+
+    #[test]
+    fn user_relation_new() {
+        let user_id = Id::gen();
+        let target_id = Id::gen();
+
+        let relation =
+            UserRelation::new(user_id.clone(), target_id.clone(), UserRelationKind::Block).unwrap();
+
+        assert_eq!(relation.user_id, user_id);
+        assert_eq!(relation.target_id, target_id);
+        assert_eq!(relation.kind, UserRelationKind::Block);
+    }
+
+    #[test]
+    fn user_relation_with_self_is_rejected() {
+        let user_id = Id::gen();
+
+        assert!(
+            UserRelation::new(user_id.clone(), user_id.clone(), UserRelationKind::Block).is_err()
+        );
+        assert!(
+            UserRelation::new(user_id.clone(), user_id.clone(), UserRelationKind::Mute).is_err()
+        );
+        assert!(UserRelation::new(user_id.clone(), user_id, UserRelationKind::Follow).is_err());
+    }
+
+    #[test]
+    fn user_relation_model_round_trip() {
+        let relation = UserRelation::new(Id::gen(), Id::gen(), UserRelationKind::Mute).unwrap();
+
+        let model: schema::user_relation::Model = relation.clone().into();
+        assert_eq!(model.kind, "mute");
+
+        let restored = UserRelation::from(model);
+        assert_eq!(restored.user_id, relation.user_id);
+        assert_eq!(restored.target_id, relation.target_id);
+        assert_eq!(restored.kind, UserRelationKind::Mute);
+    }
+}
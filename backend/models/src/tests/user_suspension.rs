@@ -0,0 +1,58 @@
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Utc};
+
+    use crate::domain::{user_suspension::UserSuspension, Id};
+
+    #[test]
+    fn new_suspension() {
+        let ends_at = Utc::now() + Duration::days(7);
+        let suspension = UserSuspension::new(
+            Id::gen(),
+            Some(Id::gen()),
+            "  Spamming  ".into(),
+            Some(ends_at),
+        )
+        .unwrap();
+
+        assert_eq!(suspension.reason, "Spamming");
+        assert_eq!(suspension.ends_at, Some(ends_at));
+        assert_eq!(suspension.lifted_at, None);
+    }
+
+    #[test]
+    fn suspension_requires_reason() {
+        let suspension = UserSuspension::new(Id::gen(), None, "   ".into(), None);
+
+        assert!(suspension.is_err());
+    }
+
+    #[test]
+    fn suspension_must_end_in_future() {
+        let suspension = UserSuspension::new(
+            Id::gen(),
+            None,
+            "Spamming".into(),
+            Some(Utc::now() - Duration::hours(1)),
+        );
+
+        assert!(suspension.is_err());
+    }
+
+    #[test]
+    fn indefinite_suspension_until_lifted() {
+        let moderator_id = Id::gen();
+        let mut suspension =
+            UserSuspension::new(Id::gen(), None, "Harassment".into(), None).unwrap();
+
+        assert_eq!(suspension.ends_at, None);
+
+        suspension.lift(Some(moderator_id.clone()));
+        let lifted_at = suspension.lifted_at;
+        suspension.lift(None);
+
+        assert!(lifted_at.is_some());
+        assert_eq!(suspension.lifted_by, Some(moderator_id));
+        assert_eq!(suspension.lifted_at, lifted_at);
+    }
+}
@@ -0,0 +1,139 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use models::domain::{
+    audit_log::{AuditAction, AuditLogEntry, AuditTargetType},
+    user::User,
+    Id,
+};
+use sea_orm::{
+    sea_query::ExprTrait, ConnectionTrait, DbConn, DbErr, EntityTrait, IntoSimpleExpr, QueryFilter,
+    QueryOrder, QuerySelect,
+};
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct DbAuditLogRepository {
+    db: Arc<DbConn>,
+}
+
+impl DbAuditLogRepository {
+    pub fn new(db: Arc<DbConn>) -> Self {
+        Self { db }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct AuditLogFilter {
+    pub actor_id: Option<Id<User>>,
+    pub action: Option<AuditAction>,
+    pub target_type: Option<AuditTargetType>,
+    pub target_id: Option<Uuid>,
+    pub since: Option<DateTime<Utc>>,
+    pub until: Option<DateTime<Utc>>,
+}
+
+/// Writes the entries on the given connection, so moderation actions can record them
+/// in the same transaction as the change itself.
+pub(crate) async fn append_entries<C>(db: &C, entries: Vec<AuditLogEntry>) -> Result<(), DbErr>
+where
+    C: ConnectionTrait,
+{
+    if entries.is_empty() {
+        return Ok(());
+    }
+
+    let entries = entries.into_iter().map(|entry| {
+        let entry_model: models::schema::audit_log::Model = entry.into();
+        models::schema::audit_log::ActiveModel::from(entry_model)
+    });
+
+    models::schema::audit_log::Entity::insert_many(entries)
+        .exec_without_returning(db)
+        .await?;
+
+    Ok(())
+}
+
+/// The log is append-only, there is no way to change or remove an entry.
+pub trait AuditLogRepository {
+    async fn append(&self, entry: AuditLogEntry) -> Result<(), DbErr>;
+    async fn get_entries(
+        &self,
+        filter: AuditLogFilter,
+        offset: i64,
+        limit: i64,
+    ) -> Result<Vec<AuditLogEntry>, DbErr>;
+}
+
+impl AuditLogRepository for DbAuditLogRepository {
+    async fn append(&self, entry: AuditLogEntry) -> Result<(), DbErr> {
+        append_entries(self.db.as_ref(), vec![entry]).await
+    }
+
+    async fn get_entries(
+        &self,
+        filter: AuditLogFilter,
+        offset: i64,
+        limit: i64,
+    ) -> Result<Vec<AuditLogEntry>, DbErr> {
+        let mut query = models::schema::audit_log::Entity::find();
+
+        if let Some(actor_id) = filter.actor_id {
+            query = query.filter(
+                models::schema::audit_log::Column::ActorId
+                    .into_simple_expr()
+                    .eq(actor_id.id),
+            );
+        }
+
+        if let Some(action) = filter.action {
+            query = query.filter(
+                models::schema::audit_log::Column::Action
+                    .into_simple_expr()
+                    .eq(action.to_string()),
+            );
+        }
+
+        if let Some(target_type) = filter.target_type {
+            query = query.filter(
+                models::schema::audit_log::Column::TargetType
+                    .into_simple_expr()
+                    .eq(target_type.to_string()),
+            );
+        }
+
+        if let Some(target_id) = filter.target_id {
+            query = query.filter(
+                models::schema::audit_log::Column::TargetId
+                    .into_simple_expr()
+                    .eq(target_id),
+            );
+        }
+
+        if let Some(since) = filter.since {
+            query = query.filter(
+                models::schema::audit_log::Column::CreatedAt
+                    .into_simple_expr()
+                    .gte(since.naive_utc()),
+            );
+        }
+
+        if let Some(until) = filter.until {
+            query = query.filter(
+                models::schema::audit_log::Column::CreatedAt
+                    .into_simple_expr()
+                    .lt(until.naive_utc()),
+            );
+        }
+
+        let entries = query
+            .order_by_desc(models::schema::audit_log::Column::CreatedAt)
+            .offset(offset as u64)
+            .limit(limit as u64)
+            .all(self.db.as_ref())
+            .await?;
+
+        Ok(entries.into_iter().map(AuditLogEntry::from).collect())
+    }
+}
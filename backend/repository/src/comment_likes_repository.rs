@@ -0,0 +1,116 @@
+use std::{collections::HashMap, sync::Arc};
+
+use models::domain::{comment_like::CommentLike, post_comment::PostComment, user::User, Id};
+use sea_orm::{
+    sea_query::{ExprTrait, OnConflict},
+    ColumnTrait, DbConn, DbErr, EntityTrait, IntoSimpleExpr, QueryFilter, QuerySelect,
+};
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct DbCommentLikesRepository {
+    db: Arc<DbConn>,
+}
+
+impl DbCommentLikesRepository {
+    pub fn new(db: Arc<DbConn>) -> Self {
+        Self { db }
+    }
+}
+
+pub trait CommentLikesRepository {
+    async fn create(&self, like: CommentLike) -> Result<(), DbErr>;
+    async fn delete(&self, comment_id: Id<PostComment>, user_id: Id<User>) -> Result<(), DbErr>;
+    async fn get_is_liked_by_user(
+        &self,
+        comment_id: Id<PostComment>,
+        user_id: Id<User>,
+    ) -> Result<bool, DbErr>;
+    async fn get_like_counts(
+        &self,
+        comment_ids: Vec<Id<PostComment>>,
+    ) -> Result<HashMap<Uuid, i64>, DbErr>;
+}
+
+impl CommentLikesRepository for DbCommentLikesRepository {
+    async fn create(&self, like: CommentLike) -> Result<(), DbErr> {
+        let like_model: models::schema::comment_like::Model = like.into();
+        let active_model: models::schema::comment_like::ActiveModel = like_model.into();
+
+        // Liking an already liked comment is a no-op
+        models::schema::comment_like::Entity::insert(active_model)
+            .on_conflict(
+                OnConflict::columns([
+                    models::schema::comment_like::Column::CommentId,
+                    models::schema::comment_like::Column::UserId,
+                ])
+                .do_nothing()
+                .to_owned(),
+            )
+            .do_nothing()
+            .exec(self.db.as_ref())
+            .await?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, comment_id: Id<PostComment>, user_id: Id<User>) -> Result<(), DbErr> {
+        models::schema::comment_like::Entity::delete_by_id((comment_id.id, user_id.id))
+            .exec(self.db.as_ref())
+            .await?;
+
+        Ok(())
+    }
+
+    async fn get_is_liked_by_user(
+        &self,
+        comment_id: Id<PostComment>,
+        user_id: Id<User>,
+    ) -> Result<bool, DbErr> {
+        let like = models::schema::comment_like::Entity::find_by_id((comment_id.id, user_id.id))
+            .one(self.db.as_ref())
+            .await?;
+
+        Ok(like.is_some())
+    }
+
+    async fn get_like_counts(
+        &self,
+        comment_ids: Vec<Id<PostComment>>,
+    ) -> Result<HashMap<Uuid, i64>, DbErr> {
+        get_comment_like_counts(
+            self.db.as_ref(),
+            comment_ids.into_iter().map(|id| id.id).collect(),
+        )
+        .await
+    }
+}
+
+/// Number of likes of every given comment, comments without likes are left out.
+pub(crate) async fn get_comment_like_counts(
+    db: &DbConn,
+    comment_ids: Vec<Uuid>,
+) -> Result<HashMap<Uuid, i64>, DbErr> {
+    if comment_ids.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    let counts = models::schema::comment_like::Entity::find()
+        .select_only()
+        .column(models::schema::comment_like::Column::CommentId)
+        .column_as(
+            models::schema::comment_like::Column::UserId.count(),
+            "count",
+        )
+        .filter(
+            models::schema::comment_like::Column::CommentId
+                .into_simple_expr()
+                .is_in(comment_ids),
+        )
+        .group_by(models::schema::comment_like::Column::CommentId)
+        .into_tuple::<(Uuid, i64)>()
+        .all(db)
+        .await?;
+
+    Ok(counts.into_iter().collect())
+}
@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use models::domain::{content_filter::ContentFilter, Id};
+use sea_orm::{DbConn, DbErr, EntityTrait, QueryOrder};
+
+#[derive(Debug, Clone)]
+pub struct DbContentFilterRepository {
+    db: Arc<DbConn>,
+}
+
+impl DbContentFilterRepository {
+    pub fn new(db: Arc<DbConn>) -> Self {
+        Self { db }
+    }
+}
+
+pub trait ContentFilterRepository {
+    async fn create(&self, filter: ContentFilter) -> Result<ContentFilter, DbErr>;
+    async fn delete(&self, id: Id<ContentFilter>) -> Result<bool, DbErr>;
+    async fn get_all(&self) -> Result<Vec<ContentFilter>, DbErr>;
+}
+
+impl ContentFilterRepository for DbContentFilterRepository {
+    async fn create(&self, filter: ContentFilter) -> Result<ContentFilter, DbErr> {
+        let filter_model: models::schema::content_filter::Model = filter.into();
+        let active_model: models::schema::content_filter::ActiveModel = filter_model.into();
+
+        let filter = models::schema::content_filter::Entity::insert(active_model)
+            .exec_with_returning(self.db.as_ref())
+            .await?;
+
+        Ok(ContentFilter::from(filter))
+    }
+
+    async fn delete(&self, id: Id<ContentFilter>) -> Result<bool, DbErr> {
+        let result = models::schema::content_filter::Entity::delete_by_id(id.id)
+            .exec(self.db.as_ref())
+            .await?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    async fn get_all(&self) -> Result<Vec<ContentFilter>, DbErr> {
+        let filters = models::schema::content_filter::Entity::find()
+            .order_by_asc(models::schema::content_filter::Column::CreatedAt)
+            .all(self.db.as_ref())
+            .await?;
+
+        Ok(filters.into_iter().map(ContentFilter::from).collect())
+    }
+}
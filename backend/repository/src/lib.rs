@@ -1,13 +1,25 @@
-pub mod cloudinary_repository;
+pub mod audit_log_repository;
+pub mod comment_likes_repository;
+pub mod content_filter_repository;
 pub mod group_join_request_repository;
 pub mod group_member_repository;
 pub mod group_repository;
 pub mod location_repository;
+pub mod media_storage;
+pub mod mention_repository;
+pub mod notification_repository;
 pub mod post_comments_repository;
 pub mod post_likes_repository;
 pub mod post_repository;
 pub mod post_visibility_repository;
+pub mod report_repository;
+pub mod saved_posts_repository;
+pub mod stats_repository;
+pub mod tag_follow_repository;
 pub mod tag_repository;
+pub mod user_relation_repository;
 pub mod user_repository;
+pub mod user_suspension_repository;
 pub mod wall_post_repository;
 pub mod wall_repository;
+pub mod tests;
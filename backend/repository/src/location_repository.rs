@@ -1,9 +1,18 @@
 use std::sync::Arc;
 
-use models::domain::{location::Location, Id};
+use models::{
+    domain::{
+        location::{BoundingBox, GeoPoint, Location, EARTH_RADIUS_KM},
+        Id,
+    },
+    schema,
+};
 use sea_orm::{
-    sea_query::extension::postgres::PgExpr, DbConn, DbErr, EntityTrait, IntoSimpleExpr, QueryFilter,
+    sea_query::{extension::postgres::PgExpr, Expr, ExprTrait},
+    ColumnTrait, DbBackend, DbConn, DbErr, EntityTrait, FromQueryResult, IntoSimpleExpr,
+    QueryFilter, QueryOrder, QuerySelect, Statement, TransactionTrait,
 };
+use uuid::Uuid;
 
 #[derive(Debug, Clone)]
 pub struct DbLocationRepository {
@@ -16,10 +25,54 @@ impl DbLocationRepository {
     }
 }
 
+/// SQL expression of the great-circle distance in kilometers between `l.latitude`, `l.longitude`
+/// and the point bound to the `$lat_param` and `$lon_param` parameters.
+pub(crate) fn distance_km_sql(lat_param: usize, lon_param: usize) -> String {
+    format!(
+        r#"({EARTH_RADIUS_KM} * 2 * ASIN(LEAST(1, SQRT(
+            POWER(SIN(RADIANS(l.latitude - ${lat_param}) / 2), 2)
+            + COS(RADIANS(${lat_param})) * COS(RADIANS(l.latitude))
+            * POWER(SIN(RADIANS(l.longitude - ${lon_param}) / 2), 2)
+        ))))"#
+    )
+}
+
+/// Latitude band around the point which contains every place within the radius,
+/// lets the database use the coordinate index before computing exact distances.
+pub(crate) fn latitude_band(center: &GeoPoint, radius_km: f64) -> (f64, f64) {
+    let delta = (radius_km / EARTH_RADIUS_KM).to_degrees();
+
+    (center.latitude - delta, center.latitude + delta)
+}
+
+#[derive(Debug, FromQueryResult)]
+struct LocationDistanceRow {
+    id: Uuid,
+    picture_url: Option<String>,
+    name: String,
+    latitude: f64,
+    longitude: f64,
+    distance_km: f64,
+}
+
 pub trait LocationRepository {
     async fn create(&self, like: Location) -> Result<Id<Location>, DbErr>;
     async fn delete_by_id(&self, id: Id<Location>) -> Result<(), DbErr>;
     async fn search(&self, query: String) -> Result<Option<Vec<Location>>, DbErr>;
+    async fn get_by_id(&self, id: Id<Location>) -> Result<Option<Location>, DbErr>;
+    async fn get_nearby(
+        &self,
+        center: GeoPoint,
+        radius_km: f64,
+        limit: i64,
+    ) -> Result<Vec<(Location, f64)>, DbErr>;
+    async fn get_in_bounds(&self, bounds: BoundingBox, limit: i64) -> Result<Vec<Location>, DbErr>;
+    async fn get_by_ids(&self, ids: Vec<Id<Location>>) -> Result<Vec<Location>, DbErr>;
+    async fn merge(
+        &self,
+        canonical_id: Id<Location>,
+        duplicate_ids: Vec<Id<Location>>,
+    ) -> Result<u64, DbErr>;
 }
 
 impl LocationRepository for DbLocationRepository {
@@ -59,4 +112,137 @@ impl LocationRepository for DbLocationRepository {
                 .collect::<Vec<_>>(),
         ))
     }
+
+    async fn get_by_id(&self, id: Id<Location>) -> Result<Option<Location>, DbErr> {
+        let location = models::schema::location::Entity::find_by_id(id.id)
+            .one(self.db.as_ref())
+            .await?;
+
+        Ok(location.map(Location::from))
+    }
+
+    async fn get_nearby(
+        &self,
+        center: GeoPoint,
+        radius_km: f64,
+        limit: i64,
+    ) -> Result<Vec<(Location, f64)>, DbErr> {
+        let (min_latitude, max_latitude) = latitude_band(&center, radius_km);
+
+        let rows = LocationDistanceRow::find_by_statement(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            format!(
+                r#"
+SELECT * 
+FROM (
+    SELECT l.*, {} AS distance_km
+    FROM location l
+    WHERE l.latitude BETWEEN $4 AND $5
+) l
+WHERE l.distance_km <= $3
+ORDER BY l.distance_km, l.name
+LIMIT $6;
+"#,
+                distance_km_sql(1, 2)
+            ),
+            [
+                center.latitude.into(),
+                center.longitude.into(),
+                radius_km.into(),
+                min_latitude.into(),
+                max_latitude.into(),
+                limit.into(),
+            ],
+        ))
+        .all(self.db.as_ref())
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                let location = Location::from(schema::location::Model {
+                    id: row.id,
+                    picture_url: row.picture_url,
+                    name: row.name,
+                    latitude: row.latitude,
+                    longitude: row.longitude,
+                });
+
+                (location, row.distance_km)
+            })
+            .collect())
+    }
+
+    async fn get_in_bounds(&self, bounds: BoundingBox, limit: i64) -> Result<Vec<Location>, DbErr> {
+        let latitude = schema::location::Column::Latitude.into_simple_expr();
+        let longitude = schema::location::Column::Longitude.into_simple_expr();
+
+        let within_longitude = if bounds.crosses_antimeridian() {
+            longitude
+                .clone()
+                .gte(bounds.west)
+                .or(longitude.lte(bounds.east))
+        } else {
+            longitude
+                .clone()
+                .gte(bounds.west)
+                .and(longitude.lte(bounds.east))
+        };
+
+        let locations = schema::location::Entity::find()
+            .filter(
+                latitude
+                    .clone()
+                    .gte(bounds.south)
+                    .and(latitude.lte(bounds.north))
+                    .and(within_longitude),
+            )
+            .order_by_asc(schema::location::Column::Name)
+            .limit(limit as u64)
+            .all(self.db.as_ref())
+            .await?;
+
+        Ok(locations.into_iter().map(Location::from).collect())
+    }
+
+    async fn get_by_ids(&self, ids: Vec<Id<Location>>) -> Result<Vec<Location>, DbErr> {
+        let locations = schema::location::Entity::find()
+            .filter(schema::location::Column::Id.is_in(ids.into_iter().map(|id| id.id)))
+            .all(self.db.as_ref())
+            .await?;
+
+        Ok(locations.into_iter().map(Location::from).collect())
+    }
+
+    async fn merge(
+        &self,
+        canonical_id: Id<Location>,
+        duplicate_ids: Vec<Id<Location>>,
+    ) -> Result<u64, DbErr> {
+        let duplicate_ids = duplicate_ids
+            .into_iter()
+            .map(|id| id.id)
+            .collect::<Vec<_>>();
+
+        // Posts are moved and the duplicates removed together, or not at all
+        let txn = self.db.begin().await?;
+
+        let moved = schema::post::Entity::update_many()
+            .col_expr(
+                schema::post::Column::LocationId,
+                Expr::value(canonical_id.id),
+            )
+            .filter(schema::post::Column::LocationId.is_in(duplicate_ids.clone()))
+            .exec(&txn)
+            .await?;
+
+        schema::location::Entity::delete_many()
+            .filter(schema::location::Column::Id.is_in(duplicate_ids))
+            .exec(&txn)
+            .await?;
+
+        txn.commit().await?;
+
+        Ok(moved.rows_affected)
+    }
 }
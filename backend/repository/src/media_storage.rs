@@ -0,0 +1,361 @@
+use std::path::PathBuf;
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use chrono::Utc;
+use cloudinary::upload::{result::UploadResult, Source, Upload, UploadOptions};
+use hmac::{Hmac, Mac};
+use sha2::{Digest, Sha256};
+
+const DEFAULT_LOCAL_MEDIA_DIR: &str = "media";
+const DEFAULT_LOCAL_MEDIA_URL: &str = "http://localhost:8000/media";
+const DEFAULT_S3_REGION: &str = "us-east-1";
+
+#[derive(Debug, thiserror::Error)]
+pub enum MediaStorageError {
+    #[error("Media storage is not configured: {0}")]
+    Config(String),
+    #[error("Failed to store media: {0}")]
+    Upload(String),
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
+
+/// Storage of uploaded media, returns the public URL of the stored file.
+pub trait MediaStorage {
+    async fn store(
+        &self,
+        key: &str,
+        content_type: &str,
+        bytes: Vec<u8>,
+    ) -> Result<String, MediaStorageError>;
+
+    /// Whether the URL points to a file in this storage.
+    fn is_stored_url(&self, url: &str) -> bool;
+}
+
+/// Content addressed name of a file, uploading the same file twice stores it only once.
+pub fn media_key(content_type: &str, bytes: &[u8]) -> String {
+    let extension = match content_type {
+        "image/jpeg" | "image/jpg" => "jpg",
+        "image/png" => "png",
+        "image/gif" => "gif",
+        "image/webp" => "webp",
+        "image/heic" => "heic",
+        "image/heif" => "heif",
+        _ => "bin",
+    };
+
+    format!("{:x}.{}", md5::compute(bytes), extension)
+}
+
+#[derive(Debug, Clone)]
+pub struct CloudinaryStorage {
+    api_key: String,
+    cloud_name: String,
+    api_secret: String,
+}
+
+impl CloudinaryStorage {
+    pub fn from_env() -> Result<Self, MediaStorageError> {
+        Ok(Self {
+            api_key: required_env("CLOUDINARY_API_KEY")?,
+            cloud_name: required_env("CLOUDINARY_CLOUD_NAME")?,
+            api_secret: required_env("CLOUDINARY_API_SECRET")?,
+        })
+    }
+}
+
+impl MediaStorage for CloudinaryStorage {
+    async fn store(
+        &self,
+        key: &str,
+        content_type: &str,
+        bytes: Vec<u8>,
+    ) -> Result<String, MediaStorageError> {
+        let public_id = key.split('.').next().unwrap_or(key);
+
+        let options = UploadOptions::new()
+            .set_folder("iis_project".to_string())
+            .set_public_id(public_id.to_string());
+
+        let upload = Upload::new(
+            self.api_key.clone(),
+            self.cloud_name.clone(),
+            self.api_secret.clone(),
+        );
+
+        let data_url = format!("data:{};base64,{}", content_type, STANDARD.encode(bytes));
+
+        match upload.image(Source::DataUrl(data_url), &options).await {
+            Ok(UploadResult::Response(response)) => Ok(response.secure_url),
+            _ => Err(MediaStorageError::Upload(
+                "Cloudinary rejected the upload".into(),
+            )),
+        }
+    }
+
+    fn is_stored_url(&self, url: &str) -> bool {
+        url.starts_with(&format!(
+            "https://res.cloudinary.com/{}/image/upload/",
+            self.cloud_name
+        ))
+    }
+}
+
+/// Files written to a directory on the server, the API serves them under `/media`.
+#[derive(Debug, Clone)]
+pub struct LocalStorage {
+    directory: PathBuf,
+    public_url: String,
+}
+
+impl LocalStorage {
+    pub fn new(directory: PathBuf, public_url: String) -> Self {
+        Self {
+            directory,
+            public_url: public_url.trim_end_matches('/').to_string(),
+        }
+    }
+
+    pub fn from_env() -> Self {
+        Self::new(
+            dotenvy::var("MEDIA_LOCAL_DIR")
+                .unwrap_or_else(|_| DEFAULT_LOCAL_MEDIA_DIR.into())
+                .into(),
+            dotenvy::var("MEDIA_PUBLIC_URL").unwrap_or_else(|_| DEFAULT_LOCAL_MEDIA_URL.into()),
+        )
+    }
+
+    pub fn directory(&self) -> &PathBuf {
+        &self.directory
+    }
+}
+
+impl MediaStorage for LocalStorage {
+    async fn store(
+        &self,
+        key: &str,
+        _content_type: &str,
+        bytes: Vec<u8>,
+    ) -> Result<String, MediaStorageError> {
+        tokio::fs::create_dir_all(&self.directory).await?;
+        tokio::fs::write(self.directory.join(key), bytes).await?;
+
+        Ok(format!("{}/{}", self.public_url, key))
+    }
+
+    fn is_stored_url(&self, url: &str) -> bool {
+        is_under(url, &self.public_url)
+    }
+}
+
+/// S3 compatible object storage (AWS, MinIO, ...), objects are addressed path-style
+/// as `<endpoint>/<bucket>/<key>` and requests signed with AWS Signature Version 4.
+#[derive(Debug, Clone)]
+pub struct S3Storage {
+    client: reqwest::Client,
+    endpoint: String,
+    bucket: String,
+    region: String,
+    access_key: String,
+    secret_key: String,
+    public_url: String,
+}
+
+impl S3Storage {
+    pub fn new(
+        endpoint: String,
+        bucket: String,
+        region: String,
+        access_key: String,
+        secret_key: String,
+        public_url: Option<String>,
+    ) -> Self {
+        let endpoint = endpoint.trim_end_matches('/').to_string();
+        let public_url = public_url
+            .unwrap_or_else(|| format!("{}/{}", endpoint, bucket))
+            .trim_end_matches('/')
+            .to_string();
+
+        Self {
+            client: reqwest::Client::new(),
+            endpoint,
+            bucket,
+            region,
+            access_key,
+            secret_key,
+            public_url,
+        }
+    }
+
+    pub fn from_env() -> Result<Self, MediaStorageError> {
+        Ok(Self::new(
+            required_env("S3_ENDPOINT")?,
+            required_env("S3_BUCKET")?,
+            dotenvy::var("S3_REGION").unwrap_or_else(|_| DEFAULT_S3_REGION.into()),
+            required_env("S3_ACCESS_KEY")?,
+            required_env("S3_SECRET_KEY")?,
+            optional_env("S3_PUBLIC_URL"),
+        ))
+    }
+
+    pub(crate) fn authorization(
+        &self,
+        host: &str,
+        path: &str,
+        content_type: &str,
+        payload_hash: &str,
+        amz_date: &str,
+    ) -> String {
+        let date = &amz_date[..8];
+        let scope = format!("{}/{}/s3/aws4_request", date, self.region);
+        let signed_headers = "content-type;host;x-amz-content-sha256;x-amz-date";
+
+        let canonical_request = format!(
+            "PUT\n{}\n\ncontent-type:{}\nhost:{}\nx-amz-content-sha256:{}\nx-amz-date:{}\n\n{}\n{}",
+            path, content_type, host, payload_hash, amz_date, signed_headers, payload_hash
+        );
+
+        let string_to_sign = format!(
+            "AWS4-HMAC-SHA256\n{}\n{}\n{}",
+            amz_date,
+            scope,
+            hex::encode(Sha256::digest(canonical_request.as_bytes()))
+        );
+
+        let signing_key = [date, self.region.as_str(), "s3", "aws4_request"]
+            .iter()
+            .fold(
+                format!("AWS4{}", self.secret_key).into_bytes(),
+                |key, part| hmac_sha256(&key, part.as_bytes()),
+            );
+
+        format!(
+            "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}",
+            self.access_key,
+            scope,
+            signed_headers,
+            hex::encode(hmac_sha256(&signing_key, string_to_sign.as_bytes()))
+        )
+    }
+}
+
+impl MediaStorage for S3Storage {
+    async fn store(
+        &self,
+        key: &str,
+        content_type: &str,
+        bytes: Vec<u8>,
+    ) -> Result<String, MediaStorageError> {
+        let url = reqwest::Url::parse(&format!("{}/{}/{}", self.endpoint, self.bucket, key))
+            .map_err(|err| MediaStorageError::Config(err.to_string()))?;
+
+        let host = match (url.host_str(), url.port()) {
+            (Some(host), Some(port)) => format!("{}:{}", host, port),
+            (Some(host), None) => host.to_string(),
+            (None, _) => return Err(MediaStorageError::Config("S3_ENDPOINT has no host".into())),
+        };
+
+        let payload_hash = hex::encode(Sha256::digest(&bytes));
+        let amz_date = Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+        let authorization =
+            self.authorization(&host, url.path(), content_type, &payload_hash, &amz_date);
+
+        let response = self
+            .client
+            .put(url)
+            .header("content-type", content_type)
+            .header("x-amz-content-sha256", payload_hash)
+            .header("x-amz-date", amz_date)
+            .header("authorization", authorization)
+            .body(bytes)
+            .send()
+            .await
+            .map_err(|err| MediaStorageError::Upload(err.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(MediaStorageError::Upload(format!(
+                "S3 responded with {}",
+                response.status()
+            )));
+        }
+
+        Ok(format!("{}/{}", self.public_url, key))
+    }
+
+    fn is_stored_url(&self, url: &str) -> bool {
+        is_under(url, &self.public_url)
+    }
+}
+
+/// The storage selected by the `MEDIA_STORAGE` variable, `cloudinary`, `local` or `s3`.
+/// Without it Cloudinary is used when its credentials are set, the local disk otherwise.
+#[derive(Debug, Clone)]
+pub enum MediaStorageBackend {
+    Cloudinary(CloudinaryStorage),
+    Local(LocalStorage),
+    S3(S3Storage),
+}
+
+impl MediaStorageBackend {
+    pub fn from_env() -> Result<Self, MediaStorageError> {
+        let Some(backend) = optional_env("MEDIA_STORAGE") else {
+            return Ok(match CloudinaryStorage::from_env() {
+                Ok(storage) => Self::Cloudinary(storage),
+                Err(_) => Self::Local(LocalStorage::from_env()),
+            });
+        };
+
+        match backend.as_str() {
+            "cloudinary" => Ok(Self::Cloudinary(CloudinaryStorage::from_env()?)),
+            "local" => Ok(Self::Local(LocalStorage::from_env())),
+            "s3" => Ok(Self::S3(S3Storage::from_env()?)),
+            other => Err(MediaStorageError::Config(format!(
+                "unknown MEDIA_STORAGE \"{}\"",
+                other
+            ))),
+        }
+    }
+}
+
+impl MediaStorage for MediaStorageBackend {
+    async fn store(
+        &self,
+        key: &str,
+        content_type: &str,
+        bytes: Vec<u8>,
+    ) -> Result<String, MediaStorageError> {
+        match self {
+            Self::Cloudinary(storage) => storage.store(key, content_type, bytes).await,
+            Self::Local(storage) => storage.store(key, content_type, bytes).await,
+            Self::S3(storage) => storage.store(key, content_type, bytes).await,
+        }
+    }
+
+    fn is_stored_url(&self, url: &str) -> bool {
+        match self {
+            Self::Cloudinary(storage) => storage.is_stored_url(url),
+            Self::Local(storage) => storage.is_stored_url(url),
+            Self::S3(storage) => storage.is_stored_url(url),
+        }
+    }
+}
+
+fn required_env(name: &str) -> Result<String, MediaStorageError> {
+    optional_env(name).ok_or_else(|| MediaStorageError::Config(format!("{} is not set", name)))
+}
+
+fn is_under(url: &str, public_url: &str) -> bool {
+    url.strip_prefix(public_url)
+        .is_some_and(|path| path.starts_with('/') && !path.contains(".."))
+}
+
+fn optional_env(name: &str) -> Option<String> {
+    dotenvy::var(name).ok().filter(|value| !value.is_empty())
+}
+
+fn hmac_sha256(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(data);
+    mac.finalize().into_bytes().to_vec()
+}
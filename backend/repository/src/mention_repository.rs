@@ -0,0 +1,150 @@
+use std::{collections::HashMap, sync::Arc};
+
+use models::domain::{mention::Mention, post::Post, post_comment::PostComment, user::User, Id};
+use sea_orm::{
+    sea_query::{Expr, ExprTrait},
+    ColumnTrait, ConnectionTrait, DbConn, DbErr, EntityTrait, IntoSimpleExpr, QueryFilter,
+};
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct DbMentionRepository {
+    db: Arc<DbConn>,
+}
+
+impl DbMentionRepository {
+    pub fn new(db: Arc<DbConn>) -> Self {
+        Self { db }
+    }
+}
+
+/// Stored mentions of the posts' descriptions and comments together with the mentioned users.
+pub(crate) async fn get_mentions_with_users<C>(
+    db: &C,
+    post_ids: Vec<Uuid>,
+) -> Result<Vec<(Mention, User)>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    if post_ids.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let mentions = models::schema::mention::Entity::find()
+        .filter(models::schema::mention::Column::PostId.is_in(post_ids))
+        .all(db)
+        .await?;
+
+    let users = models::schema::user::Entity::find()
+        .filter(
+            models::schema::user::Column::Id.is_in(
+                mentions
+                    .iter()
+                    .map(|mention| mention.user_id)
+                    .collect::<Vec<_>>(),
+            ),
+        )
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|user| (user.id, User::from(user)))
+        .collect::<HashMap<_, _>>();
+
+    Ok(mentions
+        .into_iter()
+        .filter_map(|mention| {
+            let user = users.get(&mention.user_id)?.clone();
+            Some((Mention::from(mention), user))
+        })
+        .collect())
+}
+
+pub trait MentionRepository {
+    /// Replaces the mentions of a post description (`comment_id` is `None`) or of a comment
+    /// with mentions of the given users. Returns only the mentions which did not exist before.
+    async fn replace_mentions(
+        &self,
+        post_id: Id<Post>,
+        comment_id: Option<Id<PostComment>>,
+        author_id: Id<User>,
+        user_ids: Vec<Id<User>>,
+    ) -> Result<Vec<Mention>, DbErr>;
+    async fn get_by_post_id(&self, post_id: Id<Post>) -> Result<Vec<(Mention, User)>, DbErr>;
+    async fn delete(&self, id: Id<Mention>) -> Result<(), DbErr>;
+}
+
+impl MentionRepository for DbMentionRepository {
+    async fn replace_mentions(
+        &self,
+        post_id: Id<Post>,
+        comment_id: Option<Id<PostComment>>,
+        author_id: Id<User>,
+        user_ids: Vec<Id<User>>,
+    ) -> Result<Vec<Mention>, DbErr> {
+        let comment_filter = match &comment_id {
+            Some(comment_id) => models::schema::mention::Column::CommentId
+                .into_simple_expr()
+                .eq(comment_id.id),
+            None => models::schema::mention::Column::CommentId
+                .into_simple_expr()
+                .is_null(),
+        };
+        let source_filter = models::schema::mention::Column::PostId
+            .into_simple_expr()
+            .eq(post_id.id)
+            .and(comment_filter);
+
+        let existing = models::schema::mention::Entity::find()
+            .filter(source_filter.clone())
+            .all(self.db.as_ref())
+            .await?;
+
+        models::schema::mention::Entity::delete_many()
+            .filter(
+                source_filter.and(
+                    Expr::col(models::schema::mention::Column::UserId)
+                        .is_not_in(user_ids.iter().map(|id| id.id).collect::<Vec<_>>()),
+                ),
+            )
+            .exec(self.db.as_ref())
+            .await?;
+
+        let new_mentions = user_ids
+            .into_iter()
+            .filter(|user_id| !existing.iter().any(|mention| mention.user_id == user_id.id))
+            .map(|user_id| {
+                Mention::new(
+                    post_id.clone(),
+                    comment_id.clone(),
+                    user_id,
+                    author_id.clone(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        if !new_mentions.is_empty() {
+            models::schema::mention::Entity::insert_many(new_mentions.iter().cloned().map(
+                |mention| {
+                    let model: models::schema::mention::Model = mention.into();
+                    models::schema::mention::ActiveModel::from(model)
+                },
+            ))
+            .exec(self.db.as_ref())
+            .await?;
+        }
+
+        Ok(new_mentions)
+    }
+
+    async fn get_by_post_id(&self, post_id: Id<Post>) -> Result<Vec<(Mention, User)>, DbErr> {
+        get_mentions_with_users(self.db.as_ref(), vec![post_id.id]).await
+    }
+
+    async fn delete(&self, id: Id<Mention>) -> Result<(), DbErr> {
+        models::schema::mention::Entity::delete_by_id(id.id)
+            .exec(self.db.as_ref())
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,156 @@
+use std::{collections::HashMap, sync::Arc};
+
+use chrono::Utc;
+use models::domain::{notification::Notification, user::User, Id};
+use sea_orm::{
+    sea_query::{Expr, ExprTrait},
+    DbConn, DbErr, EntityTrait, IntoSimpleExpr, PaginatorTrait, QueryFilter, QueryOrder,
+    QuerySelect,
+};
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct DbNotificationRepository {
+    db: Arc<DbConn>,
+}
+
+impl DbNotificationRepository {
+    pub fn new(db: Arc<DbConn>) -> Self {
+        Self { db }
+    }
+}
+
+pub trait NotificationRepository {
+    async fn create(&self, notification: Notification) -> Result<Id<Notification>, DbErr>;
+    async fn get_by_user_id(
+        &self,
+        user_id: Id<User>,
+        unread_only: bool,
+        offset: i64,
+        limit: i64,
+    ) -> Result<Vec<(Notification, User)>, DbErr>;
+    async fn count_unread(&self, user_id: Id<User>) -> Result<u64, DbErr>;
+    /// Marks the given notifications of the user as read, all of them when `ids` is `None`.
+    async fn mark_read(
+        &self,
+        user_id: Id<User>,
+        ids: Option<Vec<Id<Notification>>>,
+    ) -> Result<u64, DbErr>;
+}
+
+impl NotificationRepository for DbNotificationRepository {
+    async fn create(&self, notification: Notification) -> Result<Id<Notification>, DbErr> {
+        let notification_model: models::schema::notification::Model = notification.into();
+        let active_model: models::schema::notification::ActiveModel = notification_model.into();
+
+        let inserted = models::schema::notification::Entity::insert(active_model)
+            .exec(self.db.as_ref())
+            .await?;
+
+        Ok(inserted.last_insert_id.into())
+    }
+
+    async fn get_by_user_id(
+        &self,
+        user_id: Id<User>,
+        unread_only: bool,
+        offset: i64,
+        limit: i64,
+    ) -> Result<Vec<(Notification, User)>, DbErr> {
+        let mut query = models::schema::notification::Entity::find().filter(
+            models::schema::notification::Column::UserId
+                .into_simple_expr()
+                .eq(user_id.id),
+        );
+
+        if unread_only {
+            query = query.filter(
+                models::schema::notification::Column::ReadAt
+                    .into_simple_expr()
+                    .is_null(),
+            );
+        }
+
+        let notifications = query
+            .order_by_desc(models::schema::notification::Column::CreatedAt)
+            .offset(offset as u64)
+            .limit(limit as u64)
+            .all(self.db.as_ref())
+            .await?;
+
+        // The notification has two relations to users, the actors are loaded separately
+        let actors: HashMap<Uuid, User> = models::schema::user::Entity::find()
+            .filter(
+                models::schema::user::Column::Id.into_simple_expr().is_in(
+                    notifications
+                        .iter()
+                        .map(|notification| notification.actor_id)
+                        .collect::<Vec<_>>(),
+                ),
+            )
+            .all(self.db.as_ref())
+            .await?
+            .into_iter()
+            .map(|actor| (actor.id, User::from(actor)))
+            .collect();
+
+        Ok(notifications
+            .into_iter()
+            .filter_map(|notification| {
+                let actor = actors.get(&notification.actor_id).cloned()?;
+
+                Some((Notification::from(notification), actor))
+            })
+            .collect())
+    }
+
+    async fn count_unread(&self, user_id: Id<User>) -> Result<u64, DbErr> {
+        models::schema::notification::Entity::find()
+            .filter(
+                models::schema::notification::Column::UserId
+                    .into_simple_expr()
+                    .eq(user_id.id)
+                    .and(
+                        models::schema::notification::Column::ReadAt
+                            .into_simple_expr()
+                            .is_null(),
+                    ),
+            )
+            .count(self.db.as_ref())
+            .await
+    }
+
+    async fn mark_read(
+        &self,
+        user_id: Id<User>,
+        ids: Option<Vec<Id<Notification>>>,
+    ) -> Result<u64, DbErr> {
+        let mut query = models::schema::notification::Entity::update_many()
+            .col_expr(
+                models::schema::notification::Column::ReadAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .filter(
+                models::schema::notification::Column::UserId
+                    .into_simple_expr()
+                    .eq(user_id.id)
+                    .and(
+                        models::schema::notification::Column::ReadAt
+                            .into_simple_expr()
+                            .is_null(),
+                    ),
+            );
+
+        if let Some(ids) = ids {
+            query = query.filter(
+                models::schema::notification::Column::Id
+                    .into_simple_expr()
+                    .is_in(ids.into_iter().map(|id| id.id)),
+            );
+        }
+
+        let result = query.exec(self.db.as_ref()).await?;
+
+        Ok(result.rows_affected)
+    }
+}
@@ -1,10 +1,18 @@
 use std::sync::Arc;
 
+use chrono::{DateTime, Utc};
 use models::domain::{
-        post::Post, post_comment::PostComment, user::User, Id
-    };
+    audit_log::AuditLogEntry, post::Post, post_comment::PostComment, user::User, Id,
+};
 use sea_orm::{
-    DbConn, DbErr, EntityTrait, IntoSimpleExpr, QueryFilter,
+    sea_query::{Expr, ExprTrait},
+    DbBackend, DbConn, DbErr, EntityTrait, FromQueryResult, IntoSimpleExpr, QueryFilter,
+    QueryOrder, QuerySelect, Set, Statement, TransactionTrait,
+};
+
+use crate::{
+    audit_log_repository::append_entries, comment_likes_repository::get_comment_like_counts,
+    user_relation_repository::get_blocker_ids,
 };
 
 #[derive(Debug, Clone)]
@@ -18,14 +26,42 @@ impl DbPostCommentsRepository {
     }
 }
 
+#[derive(Debug, FromQueryResult)]
+struct DepthRow {
+    depth: i64,
+}
+
 pub trait PostCommentsRepository {
     async fn create(&self, like: PostComment) -> Result<Id<PostComment>, DbErr>;
+    async fn update(&self, comment: PostComment) -> Result<PostComment, DbErr>;
     async fn delete_by_id(&self, id: Id<PostComment>) -> Result<(), DbErr>;
+    /// Comments of limited users are only returned to themselves and moderators,
+    /// as are hidden comments, which the author of the post sees too.
+    /// Comments of users who blocked the viewer are left out.
     async fn get_comments_by_post_id(
         &self,
         id: Id<Post>,
-    ) -> Result<Option<Vec<(PostComment, User)>>, DbErr>;
+        viewer_id: Option<Id<User>>,
+        is_mod: bool,
+    ) -> Result<Option<Vec<(PostComment, User, i64)>>, DbErr>;
     async fn get_comment_by_id(&self, id: Id<PostComment>) -> Result<Option<PostComment>, DbErr>;
+    /// Number of ancestors of the comment, top-level comments are at depth 0
+    async fn get_comment_depth(&self, id: Id<PostComment>) -> Result<usize, DbErr>;
+    async fn get_deleted_comment_by_id(
+        &self,
+        id: Id<PostComment>,
+    ) -> Result<Option<PostComment>, DbErr>;
+    /// Moves the comment to the trash, together with the audit entry of a moderator deleting it
+    async fn soft_delete_by_id(
+        &self,
+        id: Id<PostComment>,
+        deleted_by: Id<User>,
+        audit: Option<AuditLogEntry>,
+    ) -> Result<bool, DbErr>;
+    async fn restore_by_id(&self, id: Id<PostComment>) -> Result<bool, DbErr>;
+    /// Comments the content filter held back, oldest first
+    async fn get_held(&self, offset: i64, limit: i64) -> Result<Vec<PostComment>, DbErr>;
+    async fn purge_deleted_before(&self, before: DateTime<Utc>) -> Result<u64, DbErr>;
 }
 
 impl PostCommentsRepository for DbPostCommentsRepository {
@@ -40,6 +76,24 @@ impl PostCommentsRepository for DbPostCommentsRepository {
         Ok(inserted.last_insert_id.into())
     }
 
+    async fn update(&self, comment: PostComment) -> Result<PostComment, DbErr> {
+        let comment_model: models::schema::post_comment::Model = comment.clone().into();
+        let mut active_model: models::schema::post_comment::ActiveModel = comment_model.into();
+
+        active_model.content = Set(comment.content);
+        active_model.updated_at = Set(comment.updated_at.map(|date| date.naive_utc()));
+        active_model.hidden_at = Set(comment.hidden_at.map(|date| date.naive_utc()));
+        active_model.deleted_at = Set(comment.deleted_at.map(|date| date.naive_utc()));
+        active_model.deleted_by = Set(comment.deleted_by.map(|id| id.id));
+        active_model.held = Set(comment.held);
+
+        let comment = models::schema::post_comment::Entity::update(active_model)
+            .exec(self.db.as_ref())
+            .await?;
+
+        Ok(comment.into())
+    }
+
     async fn delete_by_id(&self, id: Id<PostComment>) -> Result<(), DbErr> {
         models::schema::post_comment::Entity::delete_by_id(id.id)
             .exec(self.db.as_ref())
@@ -51,16 +105,46 @@ impl PostCommentsRepository for DbPostCommentsRepository {
     async fn get_comments_by_post_id(
         &self,
         id: Id<Post>,
-    ) -> Result<Option<Vec<(PostComment, User)>>, DbErr> {
+        viewer_id: Option<Id<User>>,
+        is_mod: bool,
+    ) -> Result<Option<Vec<(PostComment, User, i64)>>, DbErr> {
+        // Comments of a trashed post are hidden together with it
+        let Some(post) = models::schema::post::Entity::find_by_id(id.id)
+            .filter(
+                models::schema::post::Column::DeletedAt
+                    .into_simple_expr()
+                    .is_null(),
+            )
+            .one(self.db.as_ref())
+            .await?
+        else {
+            return Ok(None);
+        };
+        let post_author_id = Id::new(post.author_id);
+
         let comments = models::schema::post_comment::Entity::find()
             .filter(
                 models::schema::post_comment::Column::PostId
                     .into_simple_expr()
-                    .eq(id.id),
+                    .eq(id.id)
+                    .and(
+                        models::schema::post_comment::Column::DeletedAt
+                            .into_simple_expr()
+                            .is_null(),
+                    ),
             )
+            .order_by_asc(models::schema::post_comment::Column::CreatedAt)
             .all(self.db.as_ref())
             .await?;
 
+        let like_counts = get_comment_like_counts(
+            self.db.as_ref(),
+            comments.iter().map(|comment| comment.id).collect(),
+        )
+        .await?;
+
+        let blocker_ids = get_blocker_ids(self.db.as_ref(), viewer_id.as_ref(), is_mod).await?;
+
         let comments: Vec<PostComment> = comments.into_iter().map(|model| model.into()).collect();
         let mut result = Vec::new();
 
@@ -69,7 +153,17 @@ impl PostCommentsRepository for DbPostCommentsRepository {
                 .one(self.db.as_ref())
                 .await?
             {
-                result.push((comment, user.into()));
+                let user = User::from(user);
+
+                if !user.is_content_visible_to(viewer_id.as_ref(), is_mod)
+                    || !comment.is_visible_to(viewer_id.as_ref(), &post_author_id, is_mod)
+                    || blocker_ids.contains(&user.id.id)
+                {
+                    continue;
+                }
+
+                let like_count = like_counts.get(&comment.id.id).copied().unwrap_or(0);
+                result.push((comment, user, like_count));
             }
         }
 
@@ -78,9 +172,162 @@ impl PostCommentsRepository for DbPostCommentsRepository {
 
     async fn get_comment_by_id(&self, id: Id<PostComment>) -> Result<Option<PostComment>, DbErr> {
         let comment = models::schema::post_comment::Entity::find_by_id(id.id)
+            .filter(
+                models::schema::post_comment::Column::DeletedAt
+                    .into_simple_expr()
+                    .is_null(),
+            )
             .one(self.db.as_ref())
             .await?;
 
         Ok(comment.map(|model| model.into()))
     }
+
+    async fn get_comment_depth(&self, id: Id<PostComment>) -> Result<usize, DbErr> {
+        let row = DepthRow::find_by_statement(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            r#"
+WITH RECURSIVE ancestors AS (
+    SELECT id, parent_id, 0 AS depth
+    FROM post_comment
+    WHERE id = $1
+    UNION ALL
+    SELECT pc.id, pc.parent_id, a.depth + 1
+    FROM post_comment pc
+    JOIN ancestors a ON pc.id = a.parent_id
+)
+SELECT COALESCE(MAX(depth), 0)::bigint AS depth FROM ancestors;
+"#,
+            [id.id.into()],
+        ))
+        .one(self.db.as_ref())
+        .await?;
+
+        Ok(row.map_or(0, |row| row.depth as usize))
+    }
+
+    async fn get_deleted_comment_by_id(
+        &self,
+        id: Id<PostComment>,
+    ) -> Result<Option<PostComment>, DbErr> {
+        let comment = models::schema::post_comment::Entity::find_by_id(id.id)
+            .filter(
+                models::schema::post_comment::Column::DeletedAt
+                    .into_simple_expr()
+                    .is_not_null(),
+            )
+            .one(self.db.as_ref())
+            .await?;
+
+        Ok(comment.map(|model| model.into()))
+    }
+
+    async fn soft_delete_by_id(
+        &self,
+        id: Id<PostComment>,
+        deleted_by: Id<User>,
+        audit: Option<AuditLogEntry>,
+    ) -> Result<bool, DbErr> {
+        let txn = self.db.begin().await?;
+
+        let result = models::schema::post_comment::Entity::update_many()
+            .col_expr(
+                models::schema::post_comment::Column::DeletedAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .col_expr(
+                models::schema::post_comment::Column::DeletedBy,
+                Expr::value(deleted_by.id),
+            )
+            .filter(
+                models::schema::post_comment::Column::Id
+                    .into_simple_expr()
+                    .eq(id.id)
+                    .and(
+                        models::schema::post_comment::Column::DeletedAt
+                            .into_simple_expr()
+                            .is_null(),
+                    ),
+            )
+            .exec(&txn)
+            .await?;
+
+        // An already deleted comment is left as it is, without an audit entry
+        if result.rows_affected != 1 {
+            return Ok(false);
+        }
+
+        append_entries(&txn, audit.into_iter().collect()).await?;
+        txn.commit().await?;
+
+        Ok(true)
+    }
+
+    async fn restore_by_id(&self, id: Id<PostComment>) -> Result<bool, DbErr> {
+        let result = models::schema::post_comment::Entity::update_many()
+            .col_expr(
+                models::schema::post_comment::Column::DeletedAt,
+                Expr::value(Option::<chrono::NaiveDateTime>::None),
+            )
+            .col_expr(
+                models::schema::post_comment::Column::DeletedBy,
+                Expr::value(Option::<uuid::Uuid>::None),
+            )
+            .col_expr(
+                models::schema::post_comment::Column::Held,
+                Expr::value(false),
+            )
+            .filter(
+                models::schema::post_comment::Column::Id
+                    .into_simple_expr()
+                    .eq(id.id)
+                    .and(
+                        models::schema::post_comment::Column::DeletedAt
+                            .into_simple_expr()
+                            .is_not_null(),
+                    ),
+            )
+            .exec(self.db.as_ref())
+            .await?;
+
+        match result.rows_affected {
+            1 => Ok(true),
+            _ => Ok(false),
+        }
+    }
+
+    async fn get_held(&self, offset: i64, limit: i64) -> Result<Vec<PostComment>, DbErr> {
+        let comments = models::schema::post_comment::Entity::find()
+            .filter(
+                models::schema::post_comment::Column::Held
+                    .into_simple_expr()
+                    .eq(true),
+            )
+            .order_by_asc(models::schema::post_comment::Column::DeletedAt)
+            .offset(offset as u64)
+            .limit(limit as u64)
+            .all(self.db.as_ref())
+            .await?;
+
+        Ok(comments.into_iter().map(PostComment::from).collect())
+    }
+
+    async fn purge_deleted_before(&self, before: DateTime<Utc>) -> Result<u64, DbErr> {
+        // Held content stays in the trash until a moderator reviews it
+        let result = models::schema::post_comment::Entity::delete_many()
+            .filter(
+                models::schema::post_comment::Column::DeletedAt
+                    .into_simple_expr()
+                    .lt(before.naive_utc())
+                    .and(
+                        models::schema::post_comment::Column::Held
+                            .into_simple_expr()
+                            .eq(false),
+                    ),
+            )
+            .exec(self.db.as_ref())
+            .await?;
+
+        Ok(result.rows_affected)
+    }
 }
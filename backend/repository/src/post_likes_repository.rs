@@ -1,9 +1,9 @@
 use std::sync::Arc;
 
-use models::domain::{post::Post, post_like::PostLike, user::User, Id};
+use models::domain::{post::Post, post_like::PostLike, reaction::ReactionCount, user::User, Id};
 use sea_orm::{
-    DbConn, DbErr, EntityTrait, IntoSimpleExpr,
-    PaginatorTrait, QueryFilter,
+    sea_query::OnConflict, ColumnTrait, DbConn, DbErr, EntityTrait, IntoSimpleExpr, PaginatorTrait,
+    QueryFilter, QueryOrder, QuerySelect,
 };
 
 #[derive(Debug, Clone)]
@@ -26,6 +26,19 @@ pub trait PostLikesRepository {
         post_id: Id<Post>,
         user_id: Id<User>,
     ) -> Result<bool, DbErr>;
+    async fn get_user_reaction(
+        &self,
+        post_id: Id<Post>,
+        user_id: Id<User>,
+    ) -> Result<Option<String>, DbErr>;
+    async fn get_reaction_counts(&self, post_id: Id<Post>) -> Result<Vec<ReactionCount>, DbErr>;
+    async fn get_users_by_reaction(
+        &self,
+        post_id: Id<Post>,
+        kind: String,
+        offset: i64,
+        limit: i64,
+    ) -> Result<Vec<(PostLike, User)>, DbErr>;
 }
 
 impl PostLikesRepository for DbPostLikesRepository {
@@ -33,7 +46,19 @@ impl PostLikesRepository for DbPostLikesRepository {
         let post_like_model: models::schema::post_like::Model = post_like.into();
         let active_model: models::schema::post_like::ActiveModel = post_like_model.into();
 
+        // A user has a single reaction per post, reacting again only changes its kind
         let inserted = models::schema::post_like::Entity::insert(active_model)
+            .on_conflict(
+                OnConflict::columns([
+                    models::schema::post_like::Column::PostId,
+                    models::schema::post_like::Column::UserId,
+                ])
+                .update_columns([
+                    models::schema::post_like::Column::Kind,
+                    models::schema::post_like::Column::CreatedAt,
+                ])
+                .to_owned(),
+            )
             .exec(self.db.as_ref())
             .await?;
 
@@ -82,4 +107,70 @@ impl PostLikesRepository for DbPostLikesRepository {
 
         Ok(like.is_some())
     }
+
+    async fn get_user_reaction(
+        &self,
+        post_id: Id<Post>,
+        user_id: Id<User>,
+    ) -> Result<Option<String>, DbErr> {
+        let like = models::schema::post_like::Entity::find_by_id((post_id.id, user_id.id))
+            .one(self.db.as_ref())
+            .await?;
+
+        Ok(like.map(|like| like.kind))
+    }
+
+    async fn get_reaction_counts(&self, post_id: Id<Post>) -> Result<Vec<ReactionCount>, DbErr> {
+        let counts = models::schema::post_like::Entity::find()
+            .select_only()
+            .column(models::schema::post_like::Column::Kind)
+            .column_as(models::schema::post_like::Column::UserId.count(), "count")
+            .filter(
+                models::schema::post_like::Column::PostId
+                    .into_simple_expr()
+                    .eq(post_id.id),
+            )
+            .group_by(models::schema::post_like::Column::Kind)
+            .order_by_desc(models::schema::post_like::Column::UserId.count())
+            .order_by_asc(models::schema::post_like::Column::Kind)
+            .into_tuple::<(String, i64)>()
+            .all(self.db.as_ref())
+            .await?;
+
+        Ok(counts
+            .into_iter()
+            .map(|(kind, count)| ReactionCount { kind, count })
+            .collect())
+    }
+
+    async fn get_users_by_reaction(
+        &self,
+        post_id: Id<Post>,
+        kind: String,
+        offset: i64,
+        limit: i64,
+    ) -> Result<Vec<(PostLike, User)>, DbErr> {
+        let likes = models::schema::post_like::Entity::find()
+            .find_also_related(models::schema::user::Entity)
+            .filter(
+                models::schema::post_like::Column::PostId
+                    .into_simple_expr()
+                    .eq(post_id.id)
+                    .and(
+                        models::schema::post_like::Column::Kind
+                            .into_simple_expr()
+                            .eq(kind),
+                    ),
+            )
+            .order_by_desc(models::schema::post_like::Column::CreatedAt)
+            .offset(offset as u64)
+            .limit(limit as u64)
+            .all(self.db.as_ref())
+            .await?;
+
+        Ok(likes
+            .into_iter()
+            .filter_map(|(like, user)| user.map(|user| (PostLike::from(like), User::from(user))))
+            .collect())
+    }
 }
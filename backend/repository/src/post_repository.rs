@@ -1,13 +1,20 @@
 use std::sync::Arc;
 
+use chrono::{DateTime, Utc};
 use models::domain::{
+        audit_log::AuditLogEntry,
         post::{Post, PostVisibilityType},
+        user::User,
         Id,
     };
 use sea_orm::{
-    DbConn, DbErr, EntityTrait, Set,
+    sea_query::{Expr, ExprTrait},
+    DbConn, DbErr, EntityTrait, IntoSimpleExpr, QueryFilter, QueryOrder, QuerySelect, Set,
+    TransactionTrait,
 };
 
+use crate::audit_log_repository::append_entries;
+
 #[derive(Debug, Clone)]
 pub struct DbPostRepository {
     db: Arc<DbConn>,
@@ -21,20 +28,104 @@ impl DbPostRepository {
 
 pub trait PostRepository {
     async fn get_by_id(&self, id: Id<Post>) -> Result<Option<Post>, DbErr>;
+    async fn get_deleted_by_id(&self, id: Id<Post>) -> Result<Option<Post>, DbErr>;
+    async fn get_deleted(
+        &self,
+        author_id: Option<Id<User>>,
+        offset: i64,
+        limit: i64,
+    ) -> Result<Vec<Post>, DbErr>;
+    /// Posts the content filter held back, oldest first
+    async fn get_held(&self, offset: i64, limit: i64) -> Result<Vec<Post>, DbErr>;
     async fn create(&self, post: Post) -> Result<Id<Post>, DbErr>;
     async fn delete_by_id(&self, id: Id<Post>) -> Result<bool, DbErr>;
+    /// Moves the post to the trash, together with the audit entry of a moderator deleting it
+    async fn soft_delete_by_id(
+        &self,
+        id: Id<Post>,
+        deleted_by: Id<User>,
+        audit: Option<AuditLogEntry>,
+    ) -> Result<bool, DbErr>;
+    async fn restore_by_id(&self, id: Id<Post>) -> Result<bool, DbErr>;
+    async fn purge_deleted_before(&self, before: DateTime<Utc>) -> Result<u64, DbErr>;
     async fn update(&self, post: Post) -> Result<Option<Post>, DbErr>;
 }
 
 impl PostRepository for DbPostRepository {
     async fn get_by_id(&self, id: Id<Post>) -> Result<Option<Post>, DbErr> {
         let post = models::schema::post::Entity::find_by_id(id.id)
+            .filter(
+                models::schema::post::Column::DeletedAt
+                    .into_simple_expr()
+                    .is_null(),
+            )
             .one(self.db.as_ref())
             .await?;
 
         Ok(post.map(Post::from))
     }
 
+    async fn get_deleted_by_id(&self, id: Id<Post>) -> Result<Option<Post>, DbErr> {
+        let post = models::schema::post::Entity::find_by_id(id.id)
+            .filter(
+                models::schema::post::Column::DeletedAt
+                    .into_simple_expr()
+                    .is_not_null(),
+            )
+            .one(self.db.as_ref())
+            .await?;
+
+        Ok(post.map(Post::from))
+    }
+
+    async fn get_deleted(
+        &self,
+        author_id: Option<Id<User>>,
+        offset: i64,
+        limit: i64,
+    ) -> Result<Vec<Post>, DbErr> {
+        // Held posts wait in the review queue, not in the trash
+        let mut query = models::schema::post::Entity::find().filter(
+            models::schema::post::Column::DeletedAt
+                .into_simple_expr()
+                .is_not_null()
+                .and(
+                    models::schema::post::Column::Held
+                        .into_simple_expr()
+                        .eq(false),
+                ),
+        );
+
+        if let Some(author_id) = author_id {
+            query = query.filter(
+                models::schema::post::Column::AuthorId
+                    .into_simple_expr()
+                    .eq(author_id.id),
+            );
+        }
+
+        let posts = query
+            .order_by_desc(models::schema::post::Column::DeletedAt)
+            .offset(offset as u64)
+            .limit(limit as u64)
+            .all(self.db.as_ref())
+            .await?;
+
+        Ok(posts.into_iter().map(Post::from).collect())
+    }
+
+    async fn get_held(&self, offset: i64, limit: i64) -> Result<Vec<Post>, DbErr> {
+        let posts = models::schema::post::Entity::find()
+            .filter(models::schema::post::Column::Held.into_simple_expr().eq(true))
+            .order_by_asc(models::schema::post::Column::DeletedAt)
+            .offset(offset as u64)
+            .limit(limit as u64)
+            .all(self.db.as_ref())
+            .await?;
+
+        Ok(posts.into_iter().map(Post::from).collect())
+    }
+
     async fn update(&self, post: Post) -> Result<Option<Post>, DbErr> {
         let post_model: models::schema::post::Model = post.clone().into();
         let mut active_model: models::schema::post::ActiveModel = post_model.into();
@@ -46,6 +137,10 @@ impl PostRepository for DbPostRepository {
             PostVisibilityType::Private => "private".to_owned(),
         });
         active_model.location_id = Set(post.location_id.map(|id| id.id));
+        active_model.deleted_at = Set(post.deleted_at.map(|date| date.naive_utc()));
+        active_model.deleted_by = Set(post.deleted_by.map(|id| id.id));
+        active_model.held = Set(post.held);
+        active_model.comment_policy = Set(post.comment_policy.to_string());
 
         let post = models::schema::post::Entity::update(active_model)
             .exec(self.db.as_ref())
@@ -65,6 +160,96 @@ impl PostRepository for DbPostRepository {
         }
     }
 
+    async fn soft_delete_by_id(
+        &self,
+        id: Id<Post>,
+        deleted_by: Id<User>,
+        audit: Option<AuditLogEntry>,
+    ) -> Result<bool, DbErr> {
+        let txn = self.db.begin().await?;
+
+        let result = models::schema::post::Entity::update_many()
+            .col_expr(
+                models::schema::post::Column::DeletedAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .col_expr(
+                models::schema::post::Column::DeletedBy,
+                Expr::value(deleted_by.id),
+            )
+            .filter(
+                models::schema::post::Column::Id
+                    .into_simple_expr()
+                    .eq(id.id)
+                    .and(
+                        models::schema::post::Column::DeletedAt
+                            .into_simple_expr()
+                            .is_null(),
+                    ),
+            )
+            .exec(&txn)
+            .await?;
+
+        // An already deleted post is left as it is, without an audit entry
+        if result.rows_affected != 1 {
+            return Ok(false);
+        }
+
+        append_entries(&txn, audit.into_iter().collect()).await?;
+        txn.commit().await?;
+
+        Ok(true)
+    }
+
+    async fn restore_by_id(&self, id: Id<Post>) -> Result<bool, DbErr> {
+        let result = models::schema::post::Entity::update_many()
+            .col_expr(
+                models::schema::post::Column::DeletedAt,
+                Expr::value(Option::<chrono::NaiveDateTime>::None),
+            )
+            .col_expr(
+                models::schema::post::Column::DeletedBy,
+                Expr::value(Option::<uuid::Uuid>::None),
+            )
+            .col_expr(models::schema::post::Column::Held, Expr::value(false))
+            .filter(
+                models::schema::post::Column::Id
+                    .into_simple_expr()
+                    .eq(id.id)
+                    .and(
+                        models::schema::post::Column::DeletedAt
+                            .into_simple_expr()
+                            .is_not_null(),
+                    ),
+            )
+            .exec(self.db.as_ref())
+            .await?;
+
+        match result.rows_affected {
+            1 => Ok(true),
+            _ => Ok(false),
+        }
+    }
+
+    async fn purge_deleted_before(&self, before: DateTime<Utc>) -> Result<u64, DbErr> {
+        // Held content stays in the trash until a moderator reviews it
+        let result = models::schema::post::Entity::delete_many()
+            .filter(
+                models::schema::post::Column::DeletedAt
+                    .into_simple_expr()
+                    .lt(before.naive_utc())
+                    .and(
+                        models::schema::post::Column::Held
+                            .into_simple_expr()
+                            .eq(false),
+                    ),
+            )
+            .exec(self.db.as_ref())
+            .await?;
+
+        Ok(result.rows_affected)
+    }
+
     async fn create(&self, post: Post) -> Result<Id<Post>, DbErr> {
         let post_model: models::schema::post::Model = post.into();
         let active_model: models::schema::post::ActiveModel = post_model.into();
@@ -0,0 +1,142 @@
+use std::sync::Arc;
+
+use models::domain::{
+    report::{Report, ReportStatus, ReportTargetType},
+    user::User,
+    Id,
+};
+use sea_orm::{
+    sea_query::Expr, ActiveModelTrait, DbConn, DbErr, EntityTrait, IntoSimpleExpr, PaginatorTrait,
+    QueryFilter, QueryOrder, QuerySelect,
+};
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct DbReportRepository {
+    db: Arc<DbConn>,
+}
+
+impl DbReportRepository {
+    pub fn new(db: Arc<DbConn>) -> Self {
+        Self { db }
+    }
+}
+
+pub trait ReportRepository {
+    async fn create(&self, report: Report) -> Result<Report, DbErr>;
+    async fn get_by_id(&self, id: Id<Report>) -> Result<Option<Report>, DbErr>;
+    async fn update(&self, report: Report) -> Result<Report, DbErr>;
+    /// Whether the user, or the content filter when `None`, already has a report of the target
+    /// waiting in the queue
+    async fn has_pending_report(
+        &self,
+        reporter_id: Option<Id<User>>,
+        target_type: ReportTargetType,
+        target_id: Uuid,
+    ) -> Result<bool, DbErr>;
+    async fn get_queue(
+        &self,
+        statuses: Vec<ReportStatus>,
+        target_type: Option<ReportTargetType>,
+        offset: i64,
+        limit: i64,
+    ) -> Result<Vec<Report>, DbErr>;
+}
+
+impl ReportRepository for DbReportRepository {
+    async fn create(&self, report: Report) -> Result<Report, DbErr> {
+        let report_model: models::schema::report::Model = report.into();
+        let active_model: models::schema::report::ActiveModel = report_model.into();
+
+        let report = models::schema::report::Entity::insert(active_model)
+            .exec_with_returning(self.db.as_ref())
+            .await?;
+
+        Ok(Report::from(report))
+    }
+
+    async fn get_by_id(&self, id: Id<Report>) -> Result<Option<Report>, DbErr> {
+        let report = models::schema::report::Entity::find_by_id(id.id)
+            .one(self.db.as_ref())
+            .await?;
+
+        Ok(report.map(Report::from))
+    }
+
+    async fn update(&self, report: Report) -> Result<Report, DbErr> {
+        let report_model: models::schema::report::Model = report.into();
+        let active_model: models::schema::report::ActiveModel = report_model.into();
+
+        let report = models::schema::report::Entity::update(active_model.reset_all())
+            .exec(self.db.as_ref())
+            .await?;
+
+        Ok(Report::from(report))
+    }
+
+    async fn has_pending_report(
+        &self,
+        reporter_id: Option<Id<User>>,
+        target_type: ReportTargetType,
+        target_id: Uuid,
+    ) -> Result<bool, DbErr> {
+        let reporter_condition = match reporter_id {
+            Some(reporter_id) => {
+                Expr::col(models::schema::report::Column::ReporterId).eq(reporter_id.id)
+            }
+            None => Expr::col(models::schema::report::Column::ReporterId).is_null(),
+        };
+
+        let count = models::schema::report::Entity::find()
+            .filter(reporter_condition)
+            .filter(
+                models::schema::report::Column::TargetType
+                    .into_simple_expr()
+                    .eq(target_type.to_string()),
+            )
+            .filter(
+                models::schema::report::Column::TargetId
+                    .into_simple_expr()
+                    .eq(target_id),
+            )
+            .filter(Expr::col(models::schema::report::Column::Status).is_in([
+                ReportStatus::Open.to_string(),
+                ReportStatus::Claimed.to_string(),
+            ]))
+            .count(self.db.as_ref())
+            .await?;
+
+        Ok(count > 0)
+    }
+
+    async fn get_queue(
+        &self,
+        statuses: Vec<ReportStatus>,
+        target_type: Option<ReportTargetType>,
+        offset: i64,
+        limit: i64,
+    ) -> Result<Vec<Report>, DbErr> {
+        let mut query = models::schema::report::Entity::find().filter(
+            Expr::col(models::schema::report::Column::Status)
+                .is_in(statuses.iter().map(|status| status.to_string())),
+        );
+
+        if let Some(target_type) = target_type {
+            query = query.filter(
+                models::schema::report::Column::TargetType
+                    .into_simple_expr()
+                    .eq(target_type.to_string()),
+            );
+        }
+
+        // Oldest reports are handled first
+        let reports = query
+            .order_by_asc(models::schema::report::Column::CreatedAt)
+            .offset(offset as u64)
+            .limit(limit as u64)
+            .all(self.db.as_ref())
+            .await?;
+
+        Ok(reports.into_iter().map(Report::from).collect())
+    }
+}
@@ -0,0 +1,192 @@
+use std::{collections::HashMap, sync::Arc};
+
+use models::domain::{
+    post::Post, saved_collection::SavedCollection, saved_post::SavedPost, user::User, Id,
+};
+use sea_orm::{
+    sea_query::{ExprTrait, OnConflict},
+    ColumnTrait, DbConn, DbErr, EntityTrait, IntoSimpleExpr, QueryFilter, QueryOrder, QuerySelect,
+    Set,
+};
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct DbSavedPostsRepository {
+    db: Arc<DbConn>,
+}
+
+impl DbSavedPostsRepository {
+    pub fn new(db: Arc<DbConn>) -> Self {
+        Self { db }
+    }
+}
+
+pub trait SavedPostsRepository {
+    async fn save(&self, saved_post: SavedPost) -> Result<(), DbErr>;
+    async fn unsave(&self, user_id: Id<User>, post_id: Id<Post>) -> Result<bool, DbErr>;
+    async fn get_saved_post(
+        &self,
+        user_id: Id<User>,
+        post_id: Id<Post>,
+    ) -> Result<Option<SavedPost>, DbErr>;
+    async fn create_collection(
+        &self,
+        collection: SavedCollection,
+    ) -> Result<Id<SavedCollection>, DbErr>;
+    async fn update_collection(
+        &self,
+        collection: SavedCollection,
+    ) -> Result<SavedCollection, DbErr>;
+    async fn delete_collection(&self, id: Id<SavedCollection>) -> Result<bool, DbErr>;
+    async fn get_collection_by_id(
+        &self,
+        id: Id<SavedCollection>,
+    ) -> Result<Option<SavedCollection>, DbErr>;
+    async fn get_collections(
+        &self,
+        user_id: Id<User>,
+    ) -> Result<Vec<(SavedCollection, i64)>, DbErr>;
+}
+
+impl SavedPostsRepository for DbSavedPostsRepository {
+    async fn save(&self, saved_post: SavedPost) -> Result<(), DbErr> {
+        let saved_post_model: models::schema::saved_post::Model = saved_post.into();
+        let active_model: models::schema::saved_post::ActiveModel = saved_post_model.into();
+
+        // Saving an already saved post only moves it to the given collection
+        models::schema::saved_post::Entity::insert(active_model)
+            .on_conflict(
+                OnConflict::columns([
+                    models::schema::saved_post::Column::UserId,
+                    models::schema::saved_post::Column::PostId,
+                ])
+                .update_column(models::schema::saved_post::Column::CollectionId)
+                .to_owned(),
+            )
+            .exec_without_returning(self.db.as_ref())
+            .await?;
+
+        Ok(())
+    }
+
+    async fn unsave(&self, user_id: Id<User>, post_id: Id<Post>) -> Result<bool, DbErr> {
+        let result = models::schema::saved_post::Entity::delete_by_id((user_id.id, post_id.id))
+            .exec(self.db.as_ref())
+            .await?;
+
+        match result.rows_affected {
+            1 => Ok(true),
+            _ => Ok(false),
+        }
+    }
+
+    async fn get_saved_post(
+        &self,
+        user_id: Id<User>,
+        post_id: Id<Post>,
+    ) -> Result<Option<SavedPost>, DbErr> {
+        let saved_post = models::schema::saved_post::Entity::find_by_id((user_id.id, post_id.id))
+            .one(self.db.as_ref())
+            .await?;
+
+        Ok(saved_post.map(SavedPost::from))
+    }
+
+    async fn create_collection(
+        &self,
+        collection: SavedCollection,
+    ) -> Result<Id<SavedCollection>, DbErr> {
+        let collection_model: models::schema::saved_collection::Model = collection.into();
+        let active_model: models::schema::saved_collection::ActiveModel = collection_model.into();
+
+        let inserted = models::schema::saved_collection::Entity::insert(active_model)
+            .exec(self.db.as_ref())
+            .await?;
+
+        Ok(inserted.last_insert_id.into())
+    }
+
+    async fn update_collection(
+        &self,
+        collection: SavedCollection,
+    ) -> Result<SavedCollection, DbErr> {
+        let collection_model: models::schema::saved_collection::Model = collection.clone().into();
+        let mut active_model: models::schema::saved_collection::ActiveModel =
+            collection_model.into();
+
+        active_model.name = Set(collection.name);
+
+        let collection = models::schema::saved_collection::Entity::update(active_model)
+            .exec(self.db.as_ref())
+            .await?;
+
+        Ok(collection.into())
+    }
+
+    async fn delete_collection(&self, id: Id<SavedCollection>) -> Result<bool, DbErr> {
+        let result = models::schema::saved_collection::Entity::delete_by_id(id.id)
+            .exec(self.db.as_ref())
+            .await?;
+
+        match result.rows_affected {
+            1 => Ok(true),
+            _ => Ok(false),
+        }
+    }
+
+    async fn get_collection_by_id(
+        &self,
+        id: Id<SavedCollection>,
+    ) -> Result<Option<SavedCollection>, DbErr> {
+        let collection = models::schema::saved_collection::Entity::find_by_id(id.id)
+            .one(self.db.as_ref())
+            .await?;
+
+        Ok(collection.map(SavedCollection::from))
+    }
+
+    async fn get_collections(
+        &self,
+        user_id: Id<User>,
+    ) -> Result<Vec<(SavedCollection, i64)>, DbErr> {
+        let collections = models::schema::saved_collection::Entity::find()
+            .filter(
+                models::schema::saved_collection::Column::UserId
+                    .into_simple_expr()
+                    .eq(user_id.id),
+            )
+            .order_by_asc(models::schema::saved_collection::Column::CreatedAt)
+            .all(self.db.as_ref())
+            .await?;
+
+        let counts: HashMap<Uuid, i64> = models::schema::saved_post::Entity::find()
+            .select_only()
+            .column(models::schema::saved_post::Column::CollectionId)
+            .column_as(models::schema::saved_post::Column::PostId.count(), "count")
+            .filter(
+                models::schema::saved_post::Column::UserId
+                    .into_simple_expr()
+                    .eq(user_id.id)
+                    .and(
+                        models::schema::saved_post::Column::CollectionId
+                            .into_simple_expr()
+                            .is_not_null(),
+                    ),
+            )
+            .group_by(models::schema::saved_post::Column::CollectionId)
+            .into_tuple::<(Uuid, i64)>()
+            .all(self.db.as_ref())
+            .await?
+            .into_iter()
+            .collect();
+
+        Ok(collections
+            .into_iter()
+            .map(|collection| {
+                let count = counts.get(&collection.id).copied().unwrap_or(0);
+
+                (SavedCollection::from(collection), count)
+            })
+            .collect())
+    }
+}
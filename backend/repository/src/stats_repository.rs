@@ -0,0 +1,184 @@
+use std::sync::Arc;
+
+use chrono::NaiveDateTime;
+use models::domain::stats::{StatsPoint, StatsRange, StatsTotals};
+use sea_orm::{DbBackend, DbConn, DbErr, FromQueryResult, Statement};
+
+#[derive(Debug, Clone)]
+pub struct DbStatsRepository {
+    db: Arc<DbConn>,
+}
+
+impl DbStatsRepository {
+    pub fn new(db: Arc<DbConn>) -> Self {
+        Self { db }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatsMetric {
+    Signups,
+    Posts,
+    Comments,
+    /// Likes of posts and comments together
+    Likes,
+    /// Distinct users who posted, commented or liked something
+    ActiveUsers,
+    Groups,
+    JoinRequests,
+}
+
+const POSTS_SQL: &str = "SELECT author_id AS user_id, created_at AS ts FROM post";
+const COMMENTS_SQL: &str = "SELECT user_id, created_at AS ts FROM post_comment";
+const LIKES_SQL: &str = "SELECT user_id, created_at AS ts FROM post_like \
+     UNION ALL SELECT user_id, created_at AS ts FROM comment_like";
+
+impl StatsMetric {
+    /// Events of the metric as `(user_id, ts)` rows. Soft-deleted content still counts,
+    /// it was created in its bucket all the same.
+    fn events_sql(&self) -> String {
+        match self {
+            Self::Signups => r#"SELECT id AS user_id, created_at AS ts FROM "user""#.into(),
+            Self::Posts => POSTS_SQL.into(),
+            Self::Comments => COMMENTS_SQL.into(),
+            Self::Likes => LIKES_SQL.into(),
+            Self::ActiveUsers => {
+                format!("{POSTS_SQL} UNION ALL {COMMENTS_SQL} UNION ALL {LIKES_SQL}")
+            }
+            Self::Groups => r#"SELECT admin_id AS user_id, created_at AS ts FROM "group""#.into(),
+            Self::JoinRequests => "SELECT user_id, created_at AS ts FROM group_join_request".into(),
+        }
+    }
+
+    fn count_sql(&self) -> &'static str {
+        match self {
+            Self::ActiveUsers => "COUNT(DISTINCT events.user_id)",
+            _ => "COUNT(events.user_id)",
+        }
+    }
+}
+
+#[derive(Debug, FromQueryResult)]
+struct StatsPointRow {
+    bucket_start: NaiveDateTime,
+    count: i64,
+}
+
+#[derive(Debug, FromQueryResult)]
+struct CountRow {
+    count: i64,
+}
+
+#[derive(Debug, FromQueryResult)]
+struct StatsTotalsRow {
+    users: i64,
+    posts: i64,
+    comments: i64,
+    likes: i64,
+    groups: i64,
+    pending_join_requests: i64,
+}
+
+pub trait StatsRepository {
+    async fn get_totals(&self) -> Result<StatsTotals, DbErr>;
+    /// One point per bucket of the range, buckets without events count zero
+    async fn get_series(
+        &self,
+        metric: StatsMetric,
+        range: StatsRange,
+    ) -> Result<Vec<StatsPoint>, DbErr>;
+    /// Distinct users active anywhere in the range
+    async fn get_active_user_count(&self, range: StatsRange) -> Result<i64, DbErr>;
+}
+
+impl StatsRepository for DbStatsRepository {
+    async fn get_totals(&self) -> Result<StatsTotals, DbErr> {
+        let row = StatsTotalsRow::find_by_statement(Statement::from_string(
+            DbBackend::Postgres,
+            r#"
+SELECT
+    (SELECT COUNT(*) FROM "user") AS users,
+    (SELECT COUNT(*) FROM post WHERE deleted_at IS NULL) AS posts,
+    (SELECT COUNT(*) FROM post_comment WHERE deleted_at IS NULL) AS comments,
+    (SELECT COUNT(*) FROM post_like) + (SELECT COUNT(*) FROM comment_like) AS likes,
+    (SELECT COUNT(*) FROM "group") AS groups,
+    (SELECT COUNT(*) FROM group_join_request WHERE status = 'pending') AS pending_join_requests;
+"#,
+        ))
+        .one(self.db.as_ref())
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound("Statistics totals".into()))?;
+
+        Ok(StatsTotals {
+            users: row.users,
+            posts: row.posts,
+            comments: row.comments,
+            likes: row.likes,
+            groups: row.groups,
+            pending_join_requests: row.pending_join_requests,
+        })
+    }
+
+    async fn get_series(
+        &self,
+        metric: StatsMetric,
+        range: StatsRange,
+    ) -> Result<Vec<StatsPoint>, DbErr> {
+        let rows = StatsPointRow::find_by_statement(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            format!(
+                r#"
+SELECT buckets.bucket_start, {count} AS count
+FROM generate_series($2::timestamp, $3::timestamp - $1::interval, $1::interval)
+    AS buckets(bucket_start)
+LEFT JOIN (
+    SELECT * FROM ({events}) AS all_events
+    WHERE all_events.ts >= $2 AND all_events.ts < $3
+) AS events
+    ON events.ts >= buckets.bucket_start AND events.ts < buckets.bucket_start + $1::interval
+GROUP BY buckets.bucket_start
+ORDER BY buckets.bucket_start;
+"#,
+                count = metric.count_sql(),
+                events = metric.events_sql(),
+            ),
+            [
+                format!("1 {}", range.bucket).into(),
+                range.start().naive_utc().into(),
+                range.end().naive_utc().into(),
+            ],
+        ))
+        .all(self.db.as_ref())
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| StatsPoint {
+                bucket_start: row.bucket_start.and_utc(),
+                count: row.count,
+            })
+            .collect())
+    }
+
+    async fn get_active_user_count(&self, range: StatsRange) -> Result<i64, DbErr> {
+        let row = CountRow::find_by_statement(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            format!(
+                r#"
+SELECT COUNT(DISTINCT events.user_id) AS count
+FROM ({events}) AS events
+WHERE events.ts >= $1 AND events.ts < $2;
+"#,
+                events = StatsMetric::ActiveUsers.events_sql(),
+            ),
+            [
+                range.start().naive_utc().into(),
+                range.end().naive_utc().into(),
+            ],
+        ))
+        .one(self.db.as_ref())
+        .await?;
+
+        Ok(row.map_or(0, |row| row.count))
+    }
+}
@@ -0,0 +1,79 @@
+use std::sync::Arc;
+
+use models::domain::{tag_follow::TagFollow, user::User, Id};
+use sea_orm::{
+    sea_query::OnConflict, DbConn, DbErr, EntityTrait, IntoSimpleExpr, QueryFilter, QueryOrder,
+};
+
+#[derive(Debug, Clone)]
+pub struct DbTagFollowRepository {
+    db: Arc<DbConn>,
+}
+
+impl DbTagFollowRepository {
+    pub fn new(db: Arc<DbConn>) -> Self {
+        Self { db }
+    }
+}
+
+pub trait TagFollowRepository {
+    async fn follow(&self, tag_follow: TagFollow) -> Result<(), DbErr>;
+    async fn unfollow(&self, user_id: Id<User>, tag: String) -> Result<bool, DbErr>;
+    async fn is_following(&self, user_id: Id<User>, tag: String) -> Result<bool, DbErr>;
+    async fn get_followed_tags(&self, user_id: Id<User>) -> Result<Vec<TagFollow>, DbErr>;
+}
+
+impl TagFollowRepository for DbTagFollowRepository {
+    async fn follow(&self, tag_follow: TagFollow) -> Result<(), DbErr> {
+        let tag_follow_model: models::schema::tag_follow::Model = tag_follow.into();
+        let active_model: models::schema::tag_follow::ActiveModel = tag_follow_model.into();
+
+        // Following an already followed tag is a no-op
+        models::schema::tag_follow::Entity::insert(active_model)
+            .on_conflict(
+                OnConflict::columns([
+                    models::schema::tag_follow::Column::UserId,
+                    models::schema::tag_follow::Column::Tag,
+                ])
+                .do_nothing()
+                .to_owned(),
+            )
+            .exec_without_returning(self.db.as_ref())
+            .await?;
+
+        Ok(())
+    }
+
+    async fn unfollow(&self, user_id: Id<User>, tag: String) -> Result<bool, DbErr> {
+        let result = models::schema::tag_follow::Entity::delete_by_id((user_id.id, tag))
+            .exec(self.db.as_ref())
+            .await?;
+
+        match result.rows_affected {
+            1 => Ok(true),
+            _ => Ok(false),
+        }
+    }
+
+    async fn is_following(&self, user_id: Id<User>, tag: String) -> Result<bool, DbErr> {
+        let tag_follow = models::schema::tag_follow::Entity::find_by_id((user_id.id, tag))
+            .one(self.db.as_ref())
+            .await?;
+
+        Ok(tag_follow.is_some())
+    }
+
+    async fn get_followed_tags(&self, user_id: Id<User>) -> Result<Vec<TagFollow>, DbErr> {
+        let tag_follows = models::schema::tag_follow::Entity::find()
+            .filter(
+                models::schema::tag_follow::Column::UserId
+                    .into_simple_expr()
+                    .eq(user_id.id),
+            )
+            .order_by_asc(models::schema::tag_follow::Column::Tag)
+            .all(self.db.as_ref())
+            .await?;
+
+        Ok(tag_follows.into_iter().map(TagFollow::from).collect())
+    }
+}
@@ -1,15 +1,29 @@
 use std::{future::Future, sync::Arc};
 
+use chrono::{DateTime, NaiveDateTime, Utc};
 use models::{
-    domain::{group::Group, post::Post, post_like::PostLike, post_tag::PostTag, user::User, Id},
+    domain::{
+        group::Group,
+        post::Post,
+        post_like::PostLike,
+        post_tag::{
+            PostTag, RelatedTag, TagDetail, TrendingTag, TRENDING_COMMENT_WEIGHT,
+            TRENDING_LIKE_WEIGHT,
+        },
+        user::User,
+        Id,
+    },
     schema,
 };
 use sea_orm::{
-    sea_query::extension::postgres::PgExpr, Condition, DbConn, DbErr, EntityTrait, IntoSimpleExpr,
-    PaginatorTrait, QueryFilter,
+    sea_query::{extension::postgres::PgExpr, OnConflict},
+    Condition, DbBackend, DbConn, DbErr, EntityTrait, FromQueryResult, IntoSimpleExpr,
+    PaginatorTrait, QueryFilter, Statement, Value,
 };
 use uuid::Uuid;
 
+use crate::wall_repository::{visible_to_anonymous_condition, visible_to_user_condition};
+
 #[derive(Debug, Clone)]
 pub struct DbTagRepository {
     db: Arc<DbConn>,
@@ -26,6 +40,38 @@ pub trait TagRepository {
     async fn delete_by_id(&self, id: Id<PostTag>, tag: &str) -> Result<(), DbErr>;
     async fn search(&self, query: String) -> Result<Option<Vec<PostTag>>, DbErr>;
     async fn get_tags_by_post_id(&self, id: Id<Post>) -> Result<Option<Vec<PostTag>>, DbErr>;
+    async fn get_trending(
+        &self,
+        user_id: Option<Id<User>>,
+        since: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<TrendingTag>, DbErr>;
+    async fn get_tag_detail(
+        &self,
+        tag: String,
+        user_id: Option<Id<User>>,
+        related_limit: i64,
+    ) -> Result<Option<TagDetail>, DbErr>;
+}
+
+#[derive(Debug, FromQueryResult)]
+struct TrendingTagRow {
+    tag: String,
+    post_count: i64,
+    like_count: i64,
+    comment_count: i64,
+}
+
+#[derive(Debug, FromQueryResult)]
+struct TagStatsRow {
+    post_count: i64,
+    first_used_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, FromQueryResult)]
+struct RelatedTagRow {
+    tag: String,
+    post_count: i64,
 }
 
 impl TagRepository for DbTagRepository {
@@ -33,11 +79,20 @@ impl TagRepository for DbTagRepository {
         let post_tag_model: models::schema::post_tag::Model = tag.into();
         let active_model: models::schema::post_tag::ActiveModel = post_tag_model.into();
 
-        let inserted = models::schema::post_tag::Entity::insert(active_model)
-            .exec(self.db.as_ref())
+        // Adding a tag the post already has is a no-op
+        models::schema::post_tag::Entity::insert(active_model.clone())
+            .on_conflict(
+                OnConflict::columns([
+                    models::schema::post_tag::Column::Tag,
+                    models::schema::post_tag::Column::PostId,
+                ])
+                .do_nothing()
+                .to_owned(),
+            )
+            .exec_without_returning(self.db.as_ref())
             .await?;
 
-        Ok(inserted.last_insert_id)
+        Ok((active_model.tag.unwrap(), active_model.post_id.unwrap()))
     }
 
     async fn delete_by_id(&self, id: Id<PostTag>, tag: &str) -> Result<(), DbErr> {
@@ -77,4 +132,137 @@ impl TagRepository for DbTagRepository {
             tags.into_iter().map(PostTag::from).collect::<Vec<_>>(),
         ))
     }
+
+    async fn get_trending(
+        &self,
+        user_id: Option<Id<User>>,
+        since: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<TrendingTag>, DbErr> {
+        let mut values: Vec<Value> = vec![
+            since.naive_utc().into(),
+            TRENDING_LIKE_WEIGHT.into(),
+            TRENDING_COMMENT_WEIGHT.into(),
+            limit.into(),
+        ];
+
+        let visibility = match user_id {
+            Some(user_id) => {
+                values.push(user_id.id.into());
+                visible_to_user_condition(5)
+            }
+            None => visible_to_anonymous_condition(),
+        };
+
+        // Only posts from the window count, and only likes and comments made within it
+        let rows = TrendingTagRow::find_by_statement(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            format!(
+                r#"
+SELECT t.tag, t.post_count, t.like_count, t.comment_count
+FROM (
+    SELECT
+        pt.tag AS tag,
+        COUNT(*) AS post_count,
+        COALESCE(SUM((
+            SELECT COUNT(*)
+            FROM post_like pl
+            WHERE pl.post_id = p.id AND pl.created_at >= $1
+        )), 0)::bigint AS like_count,
+        COALESCE(SUM((
+            SELECT COUNT(*)
+            FROM post_comment pc
+            WHERE pc.post_id = p.id AND pc.deleted_at IS NULL AND pc.created_at >= $1
+        )), 0)::bigint AS comment_count
+    FROM post_tag pt
+    JOIN post p ON p.id = pt.post_id
+    WHERE p.deleted_at IS NULL AND p.created_at >= $1 AND {}
+    GROUP BY pt.tag
+) t
+ORDER BY t.post_count + $2 * t.like_count + $3 * t.comment_count DESC, t.post_count DESC, t.tag
+LIMIT $4;
+"#,
+                visibility
+            ),
+            values,
+        ))
+        .all(self.db.as_ref())
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| TrendingTag::new(row.tag, row.post_count, row.like_count, row.comment_count))
+            .collect())
+    }
+
+    async fn get_tag_detail(
+        &self,
+        tag: String,
+        user_id: Option<Id<User>>,
+        related_limit: i64,
+    ) -> Result<Option<TagDetail>, DbErr> {
+        let visibility = |user_param: usize| match user_id {
+            Some(_) => visible_to_user_condition(user_param),
+            None => visible_to_anonymous_condition(),
+        };
+        let user_values: Vec<Value> = user_id.iter().map(|user_id| user_id.id.into()).collect();
+
+        let stats = TagStatsRow::find_by_statement(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            format!(
+                r#"
+SELECT COUNT(*) AS post_count, MIN(p.created_at) AS first_used_at
+FROM post_tag pt
+JOIN post p ON p.id = pt.post_id
+WHERE pt.tag = $1 AND p.deleted_at IS NULL AND {}
+"#,
+                visibility(2)
+            ),
+            [vec![tag.clone().into()], user_values.clone()].concat(),
+        ))
+        .one(self.db.as_ref())
+        .await?;
+
+        let Some(TagStatsRow {
+            post_count,
+            first_used_at: Some(first_used_at),
+        }) = stats
+        else {
+            return Ok(None);
+        };
+
+        // Tags most often found on the same posts as the requested one
+        let related_tags = RelatedTagRow::find_by_statement(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            format!(
+                r#"
+SELECT other.tag AS tag, COUNT(*) AS post_count
+FROM post_tag pt
+JOIN post_tag other ON other.post_id = pt.post_id AND other.tag <> pt.tag
+JOIN post p ON p.id = pt.post_id
+WHERE pt.tag = $1 AND p.deleted_at IS NULL AND {}
+GROUP BY other.tag
+ORDER BY post_count DESC, other.tag
+LIMIT $2
+"#,
+                visibility(3)
+            ),
+            [vec![tag.clone().into(), related_limit.into()], user_values].concat(),
+        ))
+        .all(self.db.as_ref())
+        .await?;
+
+        Ok(Some(TagDetail {
+            tag,
+            post_count,
+            first_used_at: first_used_at.and_utc(),
+            related_tags: related_tags
+                .into_iter()
+                .map(|row| RelatedTag {
+                    tag: row.tag,
+                    post_count: row.post_count,
+                })
+                .collect(),
+        }))
+    }
 }
@@ -0,0 +1,85 @@
+#[cfg(test)]
+mod tests {
+    use crate::media_storage::{media_key, LocalStorage, MediaStorage, S3Storage};
+
+    #[test]
+    fn media_key_content_addressed() {
+        let key = media_key("image/png", b"image");
+
+        assert!(key == media_key("image/png", b"image"));
+        assert!(key != media_key("image/png", b"other image"));
+        assert!(key == format!("{:x}.png", md5::compute(b"image")));
+    }
+
+    #[test]
+    fn media_key_extension() {
+        assert!(media_key("image/jpeg", b"image").ends_with(".jpg"));
+        assert!(media_key("image/jpg", b"image").ends_with(".jpg"));
+        assert!(media_key("image/webp", b"image").ends_with(".webp"));
+        assert!(media_key("image/heic", b"image").ends_with(".heic"));
+        assert!(media_key("application/pdf", b"image").ends_with(".bin"));
+    }
+
+    #[tokio::test]
+    async fn local_storage_store() {
+        let directory = std::env::temp_dir().join(format!("media-{}", uuid::Uuid::new_v4()));
+        let storage = LocalStorage::new(directory.clone(), "http://localhost:8000/media/".into());
+
+        let url = storage
+            .store("abc.png", "image/png", b"image".to_vec())
+            .await
+            .unwrap();
+
+        assert!(url == "http://localhost:8000/media/abc.png");
+        assert!(std::fs::read(directory.join("abc.png")).unwrap() == b"image");
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn s3_authorization_signature() {
+        let storage = S3Storage::new(
+            "http://localhost:9000/".into(),
+            "iis-project".into(),
+            "eu-central-1".into(),
+            "access".into(),
+            "secret".into(),
+            None,
+        );
+
+        let authorization = storage.authorization(
+            "localhost:9000",
+            "/iis-project/abc.png",
+            "image/png",
+            "6105d6cc76af400325e94d588ce511be5bfdbb73b437dc51eca43917d7a43e3d",
+            "20250107T120000Z",
+        );
+
+        assert!(
+            authorization
+                == "AWS4-HMAC-SHA256 Credential=access/20250107/eu-central-1/s3/aws4_request, \
+                    SignedHeaders=content-type;host;x-amz-content-sha256;x-amz-date, \
+                    Signature=75fbe16ef5684ee4967d0f7e51bc1d6f7e6f1cb9cbb05dfe88020cd958142766"
+        );
+    }
+
+    #[test]
+    fn stored_url() {
+        let local = LocalStorage::new("media".into(), "http://localhost:8000/media/".into());
+        let s3 = S3Storage::new(
+            "http://localhost:9000".into(),
+            "iis-project".into(),
+            "us-east-1".into(),
+            "access".into(),
+            "secret".into(),
+            None,
+        );
+
+        assert!(local.is_stored_url("http://localhost:8000/media/abc.webp"));
+        assert!(!local.is_stored_url("http://localhost:8000/media-other/abc.webp"));
+        assert!(!local.is_stored_url("http://localhost:8000/media/../secret.webp"));
+        assert!(!local.is_stored_url("https://example.com/abc.webp"));
+        assert!(s3.is_stored_url("http://localhost:9000/iis-project/abc.webp"));
+        assert!(!s3.is_stored_url("http://localhost:9000/other-bucket/abc.webp"));
+    }
+}
@@ -0,0 +1,4 @@
+pub mod media_storage;
+pub mod post_comments_repository;
+pub mod post_repository;
+pub mod user_suspension_repository;
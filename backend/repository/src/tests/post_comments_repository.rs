@@ -0,0 +1,37 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::Utc;
+    use sea_orm::{DbBackend, MockDatabase, MockExecResult, Transaction};
+
+    use crate::post_comments_repository::{DbPostCommentsRepository, PostCommentsRepository};
+
+    #[tokio::test]
+    async fn purge_skips_held_comments() {
+        let db = Arc::new(
+            MockDatabase::new(DbBackend::Postgres)
+                .append_exec_results([MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 3,
+                }])
+                .into_connection(),
+        );
+        let before = Utc::now();
+
+        let purged = DbPostCommentsRepository::new(db.clone())
+            .purge_deleted_before(before)
+            .await
+            .unwrap();
+
+        assert!(purged == 3);
+        assert!(
+            Arc::try_unwrap(db).unwrap().into_transaction_log()
+                == [Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"DELETE FROM "post_comment" WHERE "post_comment"."deleted_at" < $1 AND "post_comment"."held" = $2"#,
+                    [before.naive_utc().into(), false.into()],
+                )]
+        );
+    }
+}
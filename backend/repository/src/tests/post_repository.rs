@@ -0,0 +1,130 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::Utc;
+    use models::domain::{
+        audit_log::{AuditAction, AuditLogEntry},
+        Id,
+    };
+    use sea_orm::{DbBackend, MockDatabase, MockExecResult, Transaction};
+
+    use crate::post_repository::{DbPostRepository, PostRepository};
+
+    fn exec_result(rows_affected: u64) -> MockExecResult {
+        MockExecResult {
+            last_insert_id: 0,
+            rows_affected,
+        }
+    }
+
+    #[tokio::test]
+    async fn purge_skips_held_posts() {
+        let db = Arc::new(
+            MockDatabase::new(DbBackend::Postgres)
+                .append_exec_results([exec_result(2)])
+                .into_connection(),
+        );
+        let before = Utc::now();
+
+        let purged = DbPostRepository::new(db.clone())
+            .purge_deleted_before(before)
+            .await
+            .unwrap();
+
+        assert!(purged == 2);
+        assert!(
+            Arc::try_unwrap(db).unwrap().into_transaction_log()
+                == [Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"DELETE FROM "post" WHERE "post"."deleted_at" < $1 AND "post"."held" = $2"#,
+                    [before.naive_utc().into(), false.into()],
+                )]
+        );
+    }
+
+    #[tokio::test]
+    async fn trash_leaves_out_held_posts() {
+        let db = Arc::new(
+            MockDatabase::new(DbBackend::Postgres)
+                .append_query_results([Vec::<models::schema::post::Model>::new()])
+                .into_connection(),
+        );
+        let author_id = Id::gen();
+
+        let posts = DbPostRepository::new(db.clone())
+            .get_deleted(Some(author_id.clone()), 0, 20)
+            .await
+            .unwrap();
+
+        assert!(posts.is_empty());
+        let log = Arc::try_unwrap(db).unwrap().into_transaction_log();
+        let statement = &log[0].statements()[0];
+        assert!(statement.sql.contains(
+            r#"WHERE "post"."deleted_at" IS NOT NULL AND "post"."held" = $1 AND "post"."author_id" = $2"#
+        ));
+        assert!(statement.values.as_ref().unwrap().0[..2] == [false.into(), author_id.id.into()]);
+    }
+
+    #[tokio::test]
+    async fn restore_clears_hold() {
+        let db = Arc::new(
+            MockDatabase::new(DbBackend::Postgres)
+                .append_exec_results([exec_result(1)])
+                .into_connection(),
+        );
+        let id = Id::gen();
+
+        let restored = DbPostRepository::new(db.clone())
+            .restore_by_id(id.clone())
+            .await
+            .unwrap();
+
+        assert!(restored);
+        assert!(
+            Arc::try_unwrap(db).unwrap().into_transaction_log()
+                == [Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"UPDATE "post" SET "deleted_at" = $1, "deleted_by" = $2, "held" = $3 WHERE "post"."id" = $4 AND "post"."deleted_at" IS NOT NULL"#,
+                    [
+                        Option::<chrono::NaiveDateTime>::None.into(),
+                        Option::<uuid::Uuid>::None.into(),
+                        false.into(),
+                        id.id.into(),
+                    ],
+                )]
+        );
+    }
+
+    #[tokio::test]
+    async fn soft_delete_of_deleted_post_skips_audit() {
+        let db = Arc::new(
+            MockDatabase::new(DbBackend::Postgres)
+                .append_exec_results([exec_result(0)])
+                .into_connection(),
+        );
+        let id = Id::gen();
+        let deleted_by = Id::gen();
+        let audit = AuditLogEntry::new(
+            deleted_by.clone(),
+            AuditAction::DeletePost,
+            id.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let deleted = DbPostRepository::new(db.clone())
+            .soft_delete_by_id(id, deleted_by, Some(audit))
+            .await
+            .unwrap();
+
+        assert!(!deleted);
+        let log = Arc::try_unwrap(db).unwrap().into_transaction_log();
+        let statements: Vec<_> = log[0].statements().iter().map(|s| s.sql.as_str()).collect();
+        assert!(statements.len() == 3);
+        assert!(statements[1].starts_with(r#"UPDATE "post""#));
+        assert!(statements[2] == "ROLLBACK");
+    }
+}
@@ -0,0 +1,41 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::Utc;
+    use models::domain::Id;
+    use sea_orm::{DbBackend, MockDatabase, Transaction};
+
+    use crate::user_suspension_repository::{DbUserSuspensionRepository, UserSuspensionRepository};
+
+    #[tokio::test]
+    async fn active_suspensions() {
+        let db = Arc::new(
+            MockDatabase::new(DbBackend::Postgres)
+                .append_query_results([Vec::<models::schema::user_suspension::Model>::new()])
+                .into_connection(),
+        );
+        let user_id = Id::gen();
+        let now = Utc::now();
+
+        let suspensions = DbUserSuspensionRepository::new(db.clone())
+            .get_active_by_user_id(user_id.clone(), now)
+            .await
+            .unwrap();
+
+        assert!(suspensions.is_empty());
+        // Unlifted, already started and not yet ended, indefinite ones first
+        assert!(
+            Arc::try_unwrap(db).unwrap().into_transaction_log()
+                == [Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"SELECT "user_suspension"."id", "user_suspension"."user_id", "user_suspension"."moderator_id", "user_suspension"."reason", "user_suspension"."starts_at", "user_suspension"."ends_at", "user_suspension"."lifted_at", "user_suspension"."lifted_by" FROM "user_suspension" WHERE "user_suspension"."user_id" = $1 AND "user_suspension"."lifted_at" IS NULL AND "user_suspension"."starts_at" <= $2 AND ("user_suspension"."ends_at" IS NULL OR "user_suspension"."ends_at" > $3) ORDER BY "user_suspension"."ends_at" DESC"#,
+                    [
+                        user_id.id.into(),
+                        now.naive_utc().into(),
+                        now.naive_utc().into(),
+                    ],
+                )]
+        );
+    }
+}
@@ -0,0 +1,226 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+
+use models::domain::{
+    post::Post,
+    user::User,
+    user_relation::{UserRelation, UserRelationKind},
+    Id,
+};
+use sea_orm::{
+    sea_query::OnConflict, ColumnTrait, DbBackend, DbConn, DbErr, EntityTrait, FromQueryResult,
+    QueryFilter, QueryOrder, Statement,
+};
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct DbUserRelationRepository {
+    db: Arc<DbConn>,
+}
+
+impl DbUserRelationRepository {
+    pub fn new(db: Arc<DbConn>) -> Self {
+        Self { db }
+    }
+}
+
+/// SQL condition hiding posts whose author blocked the user bound to `$n`.
+/// The post has to be aliased as `p`.
+pub(crate) fn author_not_blocking_condition(user_param: usize) -> String {
+    format!(
+        r#"NOT EXISTS (
+            SELECT 1
+            FROM user_relation ur_block
+            WHERE ur_block.user_id = p.author_id
+                AND ur_block.target_id = ${user_param}
+                AND ur_block.kind = 'block'
+        )"#
+    )
+}
+
+/// SQL condition hiding posts of authors muted by the user bound to `$n`.
+/// The post has to be aliased as `p`.
+pub(crate) fn author_not_muted_condition(user_param: usize) -> String {
+    format!(
+        r#"NOT EXISTS (
+            SELECT 1
+            FROM user_relation ur_mute
+            WHERE ur_mute.user_id = ${user_param}
+                AND ur_mute.target_id = p.author_id
+                AND ur_mute.kind = 'mute'
+        )"#
+    )
+}
+
+/// Ids of the users who blocked the viewer, their comments are hidden from the viewer.
+/// Moderators and anonymous viewers are not affected by blocks.
+pub(crate) async fn get_blocker_ids(
+    db: &DbConn,
+    viewer_id: Option<&Id<User>>,
+    is_mod: bool,
+) -> Result<HashSet<Uuid>, DbErr> {
+    let Some(viewer_id) = viewer_id.filter(|_| !is_mod) else {
+        return Ok(HashSet::new());
+    };
+
+    let relations = models::schema::user_relation::Entity::find()
+        .filter(models::schema::user_relation::Column::TargetId.eq(viewer_id.id))
+        .filter(models::schema::user_relation::Column::Kind.eq(UserRelationKind::Block.to_string()))
+        .all(db)
+        .await?;
+
+    Ok(relations
+        .into_iter()
+        .map(|relation| relation.user_id)
+        .collect())
+}
+
+#[derive(Debug, FromQueryResult)]
+struct ExistsRow {
+    exists: bool,
+}
+
+pub trait UserRelationRepository {
+    async fn create(&self, relation: UserRelation) -> Result<(), DbErr>;
+    async fn delete(
+        &self,
+        user_id: Id<User>,
+        target_id: Id<User>,
+        kind: UserRelationKind,
+    ) -> Result<bool, DbErr>;
+    async fn exists(
+        &self,
+        user_id: Id<User>,
+        target_id: Id<User>,
+        kind: UserRelationKind,
+    ) -> Result<bool, DbErr>;
+    /// Relations of the kind set up by the user, with the targeted users, newest first
+    async fn get_by_user(
+        &self,
+        user_id: Id<User>,
+        kind: UserRelationKind,
+    ) -> Result<Vec<(UserRelation, User)>, DbErr>;
+    async fn is_blocked_by_post_author(
+        &self,
+        post_id: Id<Post>,
+        user_id: Id<User>,
+    ) -> Result<bool, DbErr>;
+}
+
+impl UserRelationRepository for DbUserRelationRepository {
+    async fn create(&self, relation: UserRelation) -> Result<(), DbErr> {
+        let relation_model: models::schema::user_relation::Model = relation.into();
+        let active_model: models::schema::user_relation::ActiveModel = relation_model.into();
+
+        // Blocking or muting someone twice is a no-op
+        models::schema::user_relation::Entity::insert(active_model)
+            .on_conflict(
+                OnConflict::columns([
+                    models::schema::user_relation::Column::UserId,
+                    models::schema::user_relation::Column::TargetId,
+                    models::schema::user_relation::Column::Kind,
+                ])
+                .do_nothing()
+                .to_owned(),
+            )
+            .exec_without_returning(self.db.as_ref())
+            .await?;
+
+        Ok(())
+    }
+
+    async fn delete(
+        &self,
+        user_id: Id<User>,
+        target_id: Id<User>,
+        kind: UserRelationKind,
+    ) -> Result<bool, DbErr> {
+        let result = models::schema::user_relation::Entity::delete_by_id((
+            user_id.id,
+            target_id.id,
+            kind.to_string(),
+        ))
+        .exec(self.db.as_ref())
+        .await?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    async fn exists(
+        &self,
+        user_id: Id<User>,
+        target_id: Id<User>,
+        kind: UserRelationKind,
+    ) -> Result<bool, DbErr> {
+        let relation = models::schema::user_relation::Entity::find_by_id((
+            user_id.id,
+            target_id.id,
+            kind.to_string(),
+        ))
+        .one(self.db.as_ref())
+        .await?;
+
+        Ok(relation.is_some())
+    }
+
+    async fn get_by_user(
+        &self,
+        user_id: Id<User>,
+        kind: UserRelationKind,
+    ) -> Result<Vec<(UserRelation, User)>, DbErr> {
+        let relations = models::schema::user_relation::Entity::find()
+            .filter(models::schema::user_relation::Column::UserId.eq(user_id.id))
+            .filter(models::schema::user_relation::Column::Kind.eq(kind.to_string()))
+            .order_by_desc(models::schema::user_relation::Column::CreatedAt)
+            .all(self.db.as_ref())
+            .await?;
+
+        // The table references users twice, so the targets are loaded separately
+        let targets: HashMap<_, _> = models::schema::user::Entity::find()
+            .filter(
+                models::schema::user::Column::Id
+                    .is_in(relations.iter().map(|relation| relation.target_id)),
+            )
+            .all(self.db.as_ref())
+            .await?
+            .into_iter()
+            .map(|user| (user.id, user))
+            .collect();
+
+        Ok(relations
+            .into_iter()
+            .filter_map(|relation| {
+                let target = targets.get(&relation.target_id)?.clone();
+
+                Some((relation.into(), target.into()))
+            })
+            .collect())
+    }
+
+    async fn is_blocked_by_post_author(
+        &self,
+        post_id: Id<Post>,
+        user_id: Id<User>,
+    ) -> Result<bool, DbErr> {
+        let row = ExistsRow::find_by_statement(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            format!(
+                r#"
+SELECT EXISTS (
+    SELECT 1
+    FROM post p
+    WHERE p.id = $1 AND NOT {}
+) AS exists;
+"#,
+                author_not_blocking_condition(2)
+            ),
+            [post_id.id.into(), user_id.id.into()],
+        ))
+        .one(self.db.as_ref())
+        .await?;
+
+        Ok(row.is_some_and(|row| row.exists))
+    }
+}
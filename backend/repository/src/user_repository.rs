@@ -1,9 +1,19 @@
-use models::domain::{user::User, Id};
+use chrono::{DateTime, Utc};
+use models::domain::{
+    audit_log::AuditLogEntry,
+    user::{User, UserType},
+    user_suspension::UserSuspension,
+    Id,
+};
 use sea_orm::{
-    sea_query::extension::postgres::PgExpr, DbConn, DbErr, EntityTrait, IntoSimpleExpr,
-    QueryFilter, Set,
+    sea_query::{extension::postgres::PgExpr, Expr, ExprTrait},
+    ActiveEnum, ColumnTrait, ConnectionTrait, DbConn, DbErr, EntityTrait, IntoSimpleExpr,
+    PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Set, TransactionTrait,
 };
 use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::audit_log_repository::append_entries;
 
 #[derive(Debug, Clone)]
 pub struct DbUserRepository {
@@ -16,6 +26,24 @@ impl DbUserRepository {
     }
 }
 
+#[derive(Debug, Clone, Default)]
+pub struct UserListFilter {
+    pub role: Option<UserType>,
+    pub is_blocked: Option<bool>,
+    pub is_limited: Option<bool>,
+    pub username_prefix: Option<String>,
+    pub created_after: Option<DateTime<Utc>>,
+    pub created_before: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub enum UserListOrder {
+    #[default]
+    Newest,
+    Oldest,
+    Username,
+}
+
 pub trait UserRepository {
     async fn get_all(&self) -> Result<Vec<User>, DbErr>;
     async fn get_by_id(&self, id: Id<User>) -> Result<Option<User>, DbErr>;
@@ -24,7 +52,57 @@ pub trait UserRepository {
     async fn get_by_email(&self, email: String) -> Result<Option<User>, DbErr>;
     async fn create(&self, user: User) -> Result<Id<User>, DbErr>;
     async fn update(&self, user: User) -> Result<User, DbErr>;
+    /// Saves the user together with the audit entry of the change, all or nothing
+    async fn update_audited(&self, user: User, audit: AuditLogEntry) -> Result<User, DbErr>;
     async fn delete(&self, user: Id<User>) -> Result<(), DbErr>;
+    async fn delete_audited(&self, user: Id<User>, audit: AuditLogEntry) -> Result<(), DbErr>;
+    /// Creates the suspension, marks the user blocked and writes the audit entry, all or nothing
+    async fn block(&self, suspension: UserSuspension, audit: AuditLogEntry) -> Result<(), DbErr>;
+    /// Lifts every active suspension of the user, unblocks them and writes the audit entry
+    async fn unblock(
+        &self,
+        id: Id<User>,
+        lifted_by: Id<User>,
+        now: DateTime<Utc>,
+        audit: AuditLogEntry,
+    ) -> Result<(), DbErr>;
+    async fn get_by_ids(&self, ids: Vec<Id<User>>) -> Result<Vec<User>, DbErr>;
+    /// One page of the filtered users together with the number of all matching users
+    async fn get_page(
+        &self,
+        filter: UserListFilter,
+        order: UserListOrder,
+        offset: i64,
+        limit: i64,
+    ) -> Result<(Vec<User>, u64), DbErr>;
+    /// Creates the suspensions, marks their users blocked and writes the audit entries,
+    /// all or nothing
+    async fn bulk_block(
+        &self,
+        suspensions: Vec<UserSuspension>,
+        audit: Vec<AuditLogEntry>,
+    ) -> Result<(), DbErr>;
+    /// Lifts every active suspension of the users, unblocks them and writes the audit entries,
+    /// all or nothing
+    async fn bulk_unblock(
+        &self,
+        ids: Vec<Id<User>>,
+        lifted_by: Id<User>,
+        now: DateTime<Utc>,
+        audit: Vec<AuditLogEntry>,
+    ) -> Result<(), DbErr>;
+    /// Changes the role of the users and writes the audit entries, all or nothing
+    async fn bulk_set_role(
+        &self,
+        ids: Vec<Id<User>>,
+        role: UserType,
+        audit: Vec<AuditLogEntry>,
+    ) -> Result<(), DbErr>;
+    /// Deactivated users whose deletion was requested before the given time
+    async fn get_deletion_due(&self, before: DateTime<Utc>) -> Result<Vec<User>, DbErr>;
+    /// Removes the user with their walls, posts, comments, likes, group memberships,
+    /// join requests and the groups they administer, all or nothing
+    async fn purge(&self, user: Id<User>) -> Result<(), DbErr>;
 }
 
 impl UserRepository for DbUserRepository {
@@ -95,34 +173,409 @@ impl UserRepository for DbUserRepository {
     }
 
     async fn update(&self, user: User) -> Result<User, DbErr> {
-        let user_member_model: models::schema::user::Model = user.clone().into();
-        let mut active_model: models::schema::user::ActiveModel = user_member_model.into();
-
-        active_model.is_blocked = Set(user.is_blocked);
-        active_model.avatar_url = Set(user.avatar_url);
-        active_model.description = Set(user.description);
-        active_model.email = Set(user.email);
-        active_model.user_type = Set(user.user_type.into());
-        active_model.username = Set(user.username);
-        active_model.password_hash = Set(user.password_hash);
-
-        let updated = models::schema::user::Entity::update(active_model)
-            .exec(self.db.as_ref())
-            .await?;
+        update_user(self.db.as_ref(), user).await
+    }
+
+    async fn update_audited(&self, user: User, audit: AuditLogEntry) -> Result<User, DbErr> {
+        let txn = self.db.begin().await?;
+
+        let user = update_user(&txn, user).await?;
+        append_entries(&txn, vec![audit]).await?;
+
+        txn.commit().await?;
 
-        Ok(updated.into())
+        Ok(user)
     }
 
     async fn delete(&self, user: Id<User>) -> Result<(), DbErr> {
-        let active_model = models::schema::user::ActiveModel {
-            id: Set(user.id),
-            ..Default::default()
+        delete_user(self.db.as_ref(), user).await
+    }
+
+    async fn delete_audited(&self, user: Id<User>, audit: AuditLogEntry) -> Result<(), DbErr> {
+        let txn = self.db.begin().await?;
+
+        delete_user(&txn, user).await?;
+        append_entries(&txn, vec![audit]).await?;
+
+        txn.commit().await
+    }
+
+    async fn block(&self, suspension: UserSuspension, audit: AuditLogEntry) -> Result<(), DbErr> {
+        let txn = self.db.begin().await?;
+
+        block_users(&txn, vec![suspension]).await?;
+        append_entries(&txn, vec![audit]).await?;
+
+        txn.commit().await
+    }
+
+    async fn unblock(
+        &self,
+        id: Id<User>,
+        lifted_by: Id<User>,
+        now: DateTime<Utc>,
+        audit: AuditLogEntry,
+    ) -> Result<(), DbErr> {
+        let txn = self.db.begin().await?;
+
+        unblock_users(&txn, vec![id.id], lifted_by, now).await?;
+        append_entries(&txn, vec![audit]).await?;
+
+        txn.commit().await
+    }
+
+    async fn get_by_ids(&self, ids: Vec<Id<User>>) -> Result<Vec<User>, DbErr> {
+        let users = models::schema::user::Entity::find()
+            .filter(
+                models::schema::user::Column::Id
+                    .into_simple_expr()
+                    .is_in(ids.into_iter().map(|id| id.id)),
+            )
+            .all(self.db.as_ref())
+            .await?;
+
+        Ok(users.into_iter().map(User::from).collect())
+    }
+
+    async fn get_page(
+        &self,
+        filter: UserListFilter,
+        order: UserListOrder,
+        offset: i64,
+        limit: i64,
+    ) -> Result<(Vec<User>, u64), DbErr> {
+        let mut query = models::schema::user::Entity::find();
+
+        if let Some(role) = filter.role {
+            query = query.filter(
+                models::schema::user::Column::UserType
+                    .eq(models::schema::sea_orm_active_enums::UserType::from(role)),
+            );
+        }
+
+        if let Some(is_blocked) = filter.is_blocked {
+            query = query.filter(
+                models::schema::user::Column::IsBlocked
+                    .into_simple_expr()
+                    .eq(is_blocked),
+            );
+        }
+
+        if let Some(is_limited) = filter.is_limited {
+            query = query.filter(
+                models::schema::user::Column::IsLimited
+                    .into_simple_expr()
+                    .eq(is_limited),
+            );
+        }
+
+        if let Some(prefix) = filter.username_prefix {
+            // The prefix is matched literally, wildcards typed by the user don't count
+            let escaped = prefix
+                .replace('\\', "\\\\")
+                .replace('%', "\\%")
+                .replace('_', "\\_");
+
+            query = query.filter(
+                models::schema::user::Column::Username
+                    .into_simple_expr()
+                    .ilike(format!("{escaped}%")),
+            );
+        }
+
+        if let Some(created_after) = filter.created_after {
+            query = query.filter(
+                models::schema::user::Column::CreatedAt
+                    .into_simple_expr()
+                    .gte(created_after.naive_utc()),
+            );
+        }
+
+        if let Some(created_before) = filter.created_before {
+            query = query.filter(
+                models::schema::user::Column::CreatedAt
+                    .into_simple_expr()
+                    .lt(created_before.naive_utc()),
+            );
+        }
+
+        let total = query.clone().count(self.db.as_ref()).await?;
+
+        // The id breaks ties so pages don't overlap
+        query = match order {
+            UserListOrder::Newest => query
+                .order_by_desc(models::schema::user::Column::CreatedAt)
+                .order_by_desc(models::schema::user::Column::Id),
+            UserListOrder::Oldest => query
+                .order_by_asc(models::schema::user::Column::CreatedAt)
+                .order_by_asc(models::schema::user::Column::Id),
+            UserListOrder::Username => query.order_by_asc(models::schema::user::Column::Username),
         };
 
-        let _ = models::schema::user::Entity::delete(active_model)
-            .exec(self.db.as_ref())
+        let users = query
+            .offset(offset as u64)
+            .limit(limit as u64)
+            .all(self.db.as_ref())
+            .await?;
+
+        Ok((users.into_iter().map(User::from).collect(), total))
+    }
+
+    async fn bulk_block(
+        &self,
+        suspensions: Vec<UserSuspension>,
+        audit: Vec<AuditLogEntry>,
+    ) -> Result<(), DbErr> {
+        if suspensions.is_empty() {
+            return Ok(());
+        }
+
+        let txn = self.db.begin().await?;
+
+        block_users(&txn, suspensions).await?;
+        append_entries(&txn, audit).await?;
+
+        txn.commit().await
+    }
+
+    async fn bulk_unblock(
+        &self,
+        ids: Vec<Id<User>>,
+        lifted_by: Id<User>,
+        now: DateTime<Utc>,
+        audit: Vec<AuditLogEntry>,
+    ) -> Result<(), DbErr> {
+        let txn = self.db.begin().await?;
+
+        unblock_users(
+            &txn,
+            ids.into_iter().map(|id| id.id).collect(),
+            lifted_by,
+            now,
+        )
+        .await?;
+        append_entries(&txn, audit).await?;
+
+        txn.commit().await
+    }
+
+    async fn bulk_set_role(
+        &self,
+        ids: Vec<Id<User>>,
+        role: UserType,
+        audit: Vec<AuditLogEntry>,
+    ) -> Result<(), DbErr> {
+        let txn = self.db.begin().await?;
+
+        models::schema::user::Entity::update_many()
+            .col_expr(
+                models::schema::user::Column::UserType,
+                ActiveEnum::as_enum(&models::schema::sea_orm_active_enums::UserType::from(role)),
+            )
+            .filter(models::schema::user::Column::Id.is_in(ids.into_iter().map(|id| id.id)))
+            .exec(&txn)
+            .await?;
+        append_entries(&txn, audit).await?;
+
+        txn.commit().await
+    }
+
+    async fn get_deletion_due(&self, before: DateTime<Utc>) -> Result<Vec<User>, DbErr> {
+        let users = models::schema::user::Entity::find()
+            .filter(
+                models::schema::user::Column::DeletionRequestedAt
+                    .into_simple_expr()
+                    .lt(before.naive_utc()),
+            )
+            .all(self.db.as_ref())
             .await?;
 
-        Ok(())
+        Ok(users.into_iter().map(User::from).collect())
     }
+
+    async fn purge(&self, user: Id<User>) -> Result<(), DbErr> {
+        let txn = self.db.begin().await?;
+
+        let Some(user) = models::schema::user::Entity::find_by_id(user.id)
+            .one(&txn)
+            .await?
+        else {
+            return txn.commit().await;
+        };
+
+        let groups = models::schema::group::Entity::find()
+            .filter(models::schema::group::Column::AdminId.eq(user.id))
+            .all(&txn)
+            .await?;
+        let group_ids = groups.iter().map(|group| group.id).collect::<Vec<_>>();
+        let mut wall_ids = groups.iter().map(|group| group.wall_id).collect::<Vec<_>>();
+        wall_ids.push(user.wall_id);
+
+        models::schema::comment_like::Entity::delete_many()
+            .filter(models::schema::comment_like::Column::UserId.eq(user.id))
+            .exec(&txn)
+            .await?;
+
+        models::schema::post_like::Entity::delete_many()
+            .filter(models::schema::post_like::Column::UserId.eq(user.id))
+            .exec(&txn)
+            .await?;
+
+        // Replies to the user's comments go with them
+        models::schema::post_comment::Entity::delete_many()
+            .filter(models::schema::post_comment::Column::UserId.eq(user.id))
+            .exec(&txn)
+            .await?;
+
+        // Comments, likes, tags and wall entries of the posts cascade in the database
+        models::schema::post::Entity::delete_many()
+            .filter(models::schema::post::Column::AuthorId.eq(user.id))
+            .exec(&txn)
+            .await?;
+
+        models::schema::group_join_request::Entity::delete_many()
+            .filter(
+                models::schema::group_join_request::Column::UserId
+                    .eq(user.id)
+                    .or(models::schema::group_join_request::Column::GroupId
+                        .is_in(group_ids.clone())),
+            )
+            .exec(&txn)
+            .await?;
+
+        models::schema::group_member::Entity::delete_many()
+            .filter(
+                models::schema::group_member::Column::UserId
+                    .eq(user.id)
+                    .or(models::schema::group_member::Column::GroupId.is_in(group_ids.clone())),
+            )
+            .exec(&txn)
+            .await?;
+
+        models::schema::group::Entity::delete_many()
+            .filter(models::schema::group::Column::Id.is_in(group_ids))
+            .exec(&txn)
+            .await?;
+
+        models::schema::user::Entity::delete_by_id(user.id)
+            .exec(&txn)
+            .await?;
+
+        // Walls are referenced by their owners, so they go last
+        models::schema::wall::Entity::delete_many()
+            .filter(models::schema::wall::Column::Id.is_in(wall_ids))
+            .exec(&txn)
+            .await?;
+
+        txn.commit().await
+    }
+}
+
+async fn update_user<C>(db: &C, user: User) -> Result<User, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let user_member_model: models::schema::user::Model = user.clone().into();
+    let mut active_model: models::schema::user::ActiveModel = user_member_model.into();
+
+    active_model.is_blocked = Set(user.is_blocked);
+    active_model.is_limited = Set(user.is_limited);
+    active_model.deletion_requested_at = Set(user.deletion_requested_at.map(|at| at.naive_utc()));
+    active_model.avatar_url = Set(user.avatar_url);
+    active_model.description = Set(user.description);
+    active_model.email = Set(user.email);
+    active_model.user_type = Set(user.user_type.into());
+    active_model.username = Set(user.username);
+    active_model.password_hash = Set(user.password_hash);
+
+    let updated = models::schema::user::Entity::update(active_model)
+        .exec(db)
+        .await?;
+
+    Ok(updated.into())
+}
+
+async fn delete_user<C>(db: &C, user: Id<User>) -> Result<(), DbErr>
+where
+    C: ConnectionTrait,
+{
+    let active_model = models::schema::user::ActiveModel {
+        id: Set(user.id),
+        ..Default::default()
+    };
+
+    let _ = models::schema::user::Entity::delete(active_model)
+        .exec(db)
+        .await?;
+
+    Ok(())
+}
+
+async fn block_users<C>(db: &C, suspensions: Vec<UserSuspension>) -> Result<(), DbErr>
+where
+    C: ConnectionTrait,
+{
+    let user_ids = suspensions
+        .iter()
+        .map(|suspension| suspension.user_id.id)
+        .collect::<Vec<_>>();
+    let suspensions = suspensions.into_iter().map(|suspension| {
+        let model: models::schema::user_suspension::Model = suspension.into();
+        models::schema::user_suspension::ActiveModel::from(model)
+    });
+
+    models::schema::user_suspension::Entity::insert_many(suspensions)
+        .exec_without_returning(db)
+        .await?;
+
+    models::schema::user::Entity::update_many()
+        .col_expr(models::schema::user::Column::IsBlocked, Expr::value(true))
+        .filter(models::schema::user::Column::Id.is_in(user_ids))
+        .exec(db)
+        .await?;
+
+    Ok(())
+}
+
+async fn unblock_users<C>(
+    db: &C,
+    ids: Vec<Uuid>,
+    lifted_by: Id<User>,
+    now: DateTime<Utc>,
+) -> Result<(), DbErr>
+where
+    C: ConnectionTrait,
+{
+    let now = now.naive_utc();
+
+    models::schema::user_suspension::Entity::update_many()
+        .col_expr(
+            models::schema::user_suspension::Column::LiftedAt,
+            Expr::value(now),
+        )
+        .col_expr(
+            models::schema::user_suspension::Column::LiftedBy,
+            Expr::value(lifted_by.id),
+        )
+        .filter(models::schema::user_suspension::Column::UserId.is_in(ids.clone()))
+        .filter(
+            models::schema::user_suspension::Column::LiftedAt
+                .into_simple_expr()
+                .is_null(),
+        )
+        .filter(
+            Expr::col(models::schema::user_suspension::Column::EndsAt)
+                .is_null()
+                .or(Expr::col(models::schema::user_suspension::Column::EndsAt).gt(now)),
+        )
+        .exec(db)
+        .await?;
+
+    models::schema::user::Entity::update_many()
+        .col_expr(models::schema::user::Column::IsBlocked, Expr::value(false))
+        .filter(models::schema::user::Column::Id.is_in(ids))
+        .exec(db)
+        .await?;
+
+    Ok(())
 }
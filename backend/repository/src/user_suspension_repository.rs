@@ -0,0 +1,117 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use models::domain::{user::User, user_suspension::UserSuspension, Id};
+use sea_orm::{
+    sea_query::ExprTrait, ActiveModelTrait, Condition, DbConn, DbErr, EntityTrait, IntoSimpleExpr,
+    QueryFilter, QueryOrder,
+};
+
+#[derive(Debug, Clone)]
+pub struct DbUserSuspensionRepository {
+    db: Arc<DbConn>,
+}
+
+impl DbUserSuspensionRepository {
+    pub fn new(db: Arc<DbConn>) -> Self {
+        Self { db }
+    }
+}
+
+pub trait UserSuspensionRepository {
+    async fn create(&self, suspension: UserSuspension) -> Result<UserSuspension, DbErr>;
+    async fn update(&self, suspension: UserSuspension) -> Result<UserSuspension, DbErr>;
+    /// Suspensions of the user in effect at `now`, the longest lasting first
+    async fn get_active_by_user_id(
+        &self,
+        user_id: Id<User>,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<UserSuspension>, DbErr>;
+    /// Suspensions which ended before `now` but were not lifted yet
+    async fn get_expired(&self, now: DateTime<Utc>) -> Result<Vec<UserSuspension>, DbErr>;
+}
+
+impl UserSuspensionRepository for DbUserSuspensionRepository {
+    async fn create(&self, suspension: UserSuspension) -> Result<UserSuspension, DbErr> {
+        let suspension_model: models::schema::user_suspension::Model = suspension.into();
+        let active_model: models::schema::user_suspension::ActiveModel = suspension_model.into();
+
+        let suspension = models::schema::user_suspension::Entity::insert(active_model)
+            .exec_with_returning(self.db.as_ref())
+            .await?;
+
+        Ok(UserSuspension::from(suspension))
+    }
+
+    async fn update(&self, suspension: UserSuspension) -> Result<UserSuspension, DbErr> {
+        let suspension_model: models::schema::user_suspension::Model = suspension.into();
+        let active_model: models::schema::user_suspension::ActiveModel = suspension_model.into();
+
+        let suspension = models::schema::user_suspension::Entity::update(active_model.reset_all())
+            .exec(self.db.as_ref())
+            .await?;
+
+        Ok(UserSuspension::from(suspension))
+    }
+
+    async fn get_active_by_user_id(
+        &self,
+        user_id: Id<User>,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<UserSuspension>, DbErr> {
+        let now = now.naive_utc();
+
+        let suspensions = models::schema::user_suspension::Entity::find()
+            .filter(
+                models::schema::user_suspension::Column::UserId
+                    .into_simple_expr()
+                    .eq(user_id.id),
+            )
+            .filter(
+                models::schema::user_suspension::Column::LiftedAt
+                    .into_simple_expr()
+                    .is_null(),
+            )
+            .filter(
+                models::schema::user_suspension::Column::StartsAt
+                    .into_simple_expr()
+                    .lte(now),
+            )
+            .filter(
+                Condition::any()
+                    .add(
+                        models::schema::user_suspension::Column::EndsAt
+                            .into_simple_expr()
+                            .is_null(),
+                    )
+                    .add(
+                        models::schema::user_suspension::Column::EndsAt
+                            .into_simple_expr()
+                            .gt(now),
+                    ),
+            )
+            .order_by_desc(models::schema::user_suspension::Column::EndsAt)
+            .all(self.db.as_ref())
+            .await?;
+
+        Ok(suspensions.into_iter().map(UserSuspension::from).collect())
+    }
+
+    async fn get_expired(&self, now: DateTime<Utc>) -> Result<Vec<UserSuspension>, DbErr> {
+        let suspensions = models::schema::user_suspension::Entity::find()
+            .filter(
+                models::schema::user_suspension::Column::LiftedAt
+                    .into_simple_expr()
+                    .is_null(),
+            )
+            .filter(
+                models::schema::user_suspension::Column::EndsAt
+                    .into_simple_expr()
+                    .lte(now.naive_utc()),
+            )
+            .all(self.db.as_ref())
+            .await?;
+
+        Ok(suspensions.into_iter().map(UserSuspension::from).collect())
+    }
+}
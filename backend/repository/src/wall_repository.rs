@@ -1,12 +1,33 @@
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 use models::{
     domain::{
-        post::Post, post_comment::PostComment, post_like::PostLike, user::User, wall::Wall, Id,
+        location::{GeoPoint, Location},
+        mention::Mention,
+        post::Post,
+        post_comment::PostComment,
+        post_like::PostLike,
+        saved_collection::SavedCollection,
+        user::User,
+        wall::Wall,
+        Id,
     },
     schema,
 };
-use sea_orm::{DbBackend, DbConn, DbErr, EntityTrait, IntoSimpleExpr, QueryFilter, Statement};
+use sea_orm::{
+    sea_query::ExprTrait, DbBackend, DbConn, DbErr, EntityTrait, FromQueryResult, IntoSimpleExpr,
+    QueryFilter, QueryOrder, Statement, Value,
+};
+use uuid::Uuid;
+
+use crate::{
+    comment_likes_repository::get_comment_like_counts,
+    location_repository::{distance_km_sql, latitude_band},
+    mention_repository::get_mentions_with_users,
+    user_relation_repository::{
+        author_not_blocking_condition, author_not_muted_condition, get_blocker_ids,
+    },
+};
 
 #[derive(Debug, Clone)]
 pub struct DbWallRepository {
@@ -22,11 +43,69 @@ impl DbWallRepository {
 pub type WallPostTuple = (
     Post,
     User,
-    Vec<(PostComment, User)>,
+    Vec<(PostComment, User, i64)>,
     Vec<(PostLike, User)>,
     Vec<String>,
+    Vec<(Mention, User)>,
 );
 
+/// SQL condition hiding posts of limited or deactivated authors, except from the author
+/// bound to `$n`.
+/// The post has to be aliased as `p`.
+pub(crate) fn author_not_limited_condition(user_param: Option<usize>) -> String {
+    let limited_author = r#"EXISTS (
+            SELECT 1
+            FROM "user" au
+            WHERE au.id = p.author_id
+                AND (au.is_limited OR au.deletion_requested_at IS NOT NULL)
+        )"#;
+
+    match user_param {
+        Some(user_param) => format!("(p.author_id = ${user_param} OR NOT {limited_author})"),
+        None => format!("(NOT {limited_author})"),
+    }
+}
+
+/// SQL condition matching the posts visible without signing in.
+/// The post has to be aliased as `p`, deleted posts are not filtered out by it.
+pub(crate) fn visible_to_anonymous_condition() -> String {
+    format!(
+        "(p.visibility = 'public' AND {})",
+        author_not_limited_condition(None)
+    )
+}
+
+/// SQL condition matching the posts the user bound to the `$n` parameter is allowed to see.
+/// Posts of authors who blocked the user are left out. The post has to be aliased as `p`,
+/// deleted posts are not filtered out by it.
+pub(crate) fn visible_to_user_condition(user_param: usize) -> String {
+    format!(
+        r#"((p.visibility = 'public'
+        OR (p.visibility = 'private' AND EXISTS (
+            SELECT 1 
+            FROM post_user_visibility puv 
+            WHERE puv.post_id = p.id AND puv.user_id = ${user_param}
+        ))
+        OR (p.visibility = 'private' AND EXISTS (
+            SELECT 1 
+            FROM post_group_visibility pgv
+            JOIN group_member gm ON pgv.group_id = gm.group_id
+            WHERE pgv.post_id = p.id AND gm.user_id = ${user_param}
+        ))
+        OR (p.visibility = 'private' AND p.author_id = ${user_param}))
+        AND {}
+        AND {})"#,
+        author_not_limited_condition(Some(user_param)),
+        author_not_blocking_condition(user_param)
+    )
+}
+
+#[derive(Debug, FromQueryResult)]
+struct PostDistanceRow {
+    id: Uuid,
+    distance_km: f64,
+}
+
 pub trait WallRepository {
     async fn get_by_id(&self, id: Id<Wall>) -> Result<Option<Wall>, DbErr>;
     async fn create(&self, wall: Wall) -> Result<Id<Wall>, DbErr>;
@@ -52,6 +131,39 @@ pub trait WallRepository {
         offset: i64,
         limit: i64,
     ) -> Result<Vec<WallPostTuple>, DbErr>;
+    async fn get_followed_tags_feed(
+        &self,
+        user_id: Id<User>,
+        offset: i64,
+        limit: i64,
+    ) -> Result<Vec<WallPostTuple>, DbErr>;
+    async fn get_posts_by_location(
+        &self,
+        location_id: Id<Location>,
+        user_id: Option<Id<User>>,
+        offset: i64,
+        limit: i64,
+    ) -> Result<Vec<WallPostTuple>, DbErr>;
+    async fn get_posts_nearby(
+        &self,
+        center: GeoPoint,
+        radius_km: f64,
+        user_id: Option<Id<User>>,
+        offset: i64,
+        limit: i64,
+    ) -> Result<Vec<(WallPostTuple, f64)>, DbErr>;
+    async fn get_saved_posts(
+        &self,
+        user_id: Id<User>,
+        collection_id: Option<Id<SavedCollection>>,
+        offset: i64,
+        limit: i64,
+    ) -> Result<Vec<WallPostTuple>, DbErr>;
+    async fn is_post_visible_to(
+        &self,
+        post_id: Id<Post>,
+        user_id: Option<Id<User>>,
+    ) -> Result<bool, DbErr>;
 }
 
 impl WallRepository for DbWallRepository {
@@ -82,6 +194,7 @@ impl WallRepository for DbWallRepository {
         limit: i64,
         is_mod: bool,
     ) -> Result<Vec<WallPostTuple>, DbErr> {
+        let viewer_id = user_id.clone();
         let is_user_wall = models::schema::user::Entity::find()
             .filter(
                 models::schema::user::Column::WallId
@@ -99,6 +212,7 @@ impl WallRepository for DbWallRepository {
                     r#"select * from wall_post wp  
 join post p on p.id  = wp.post_id 
 where wp.wall_id = $1 
+and p.deleted_at is null
 ORDER BY created_at DESC  -- Order posts by the latest first
 LIMIT $2 OFFSET $3"#,
                     [wall_id.id.into(), limit.into(), offset.into()],
@@ -113,9 +227,13 @@ LIMIT $2 OFFSET $3"#,
             models::schema::wall_post::Entity::find()
                 .from_raw_sql(Statement::from_sql_and_values(
                     DbBackend::Postgres,
-                    r#"select * from wall_post wp  
+                    format!(
+                        r#"select * from wall_post wp  
 join post p on p.id  = wp.post_id 
 where wp.wall_id = $1 
+and p.deleted_at is null
+and {}
+and {}
 and $1 in (
 	select wall_id from "group" g 
 	where g.id in (
@@ -124,6 +242,9 @@ and $1 in (
 	)
 ORDER BY created_at DESC  -- Order posts by the latest first
 LIMIT $3 OFFSET $4"#,
+                        author_not_limited_condition(Some(2)),
+                        author_not_blocking_condition(2)
+                    ),
                     [
                         wall_id.id.into(),
                         user_id.unwrap().id.into(),
@@ -138,11 +259,13 @@ LIMIT $3 OFFSET $4"#,
                 models::schema::wall_post::Entity::find()
                     .from_raw_sql(Statement::from_sql_and_values(
                         DbBackend::Postgres,
-                        r#"WITH visible_posts AS (
+                        format!(
+                            r#"WITH visible_posts AS (
     SELECT * 
     FROM wall_post wp
     join post p on p.id = wp.post_id  
     where wp.wall_id = $1
+    and p.deleted_at is null
     and (
         (p.visibility = 'public'
         OR (p.visibility = 'private' AND EXISTS (
@@ -151,6 +274,8 @@ LIMIT $3 OFFSET $4"#,
             WHERE puv.post_id = p.id AND puv.user_id = $2
         ))
         OR (p.visibility = 'private' AND p.author_id = $2)))
+    and {}
+    and {}
 ),
 paged_posts AS (
     SELECT * 
@@ -161,6 +286,9 @@ paged_posts AS (
 
 SELECT * 
 FROM paged_posts;"#,
+                            author_not_limited_condition(Some(2)),
+                            author_not_blocking_condition(2)
+                        ),
                         [
                             wall_id.id.into(),
                             user_id.id.into(),
@@ -174,13 +302,14 @@ FROM paged_posts;"#,
                 models::schema::wall_post::Entity::find()
                     .from_raw_sql(Statement::from_sql_and_values(
                         DbBackend::Postgres,
-                        r#"WITH visible_posts AS (
+                        format!(
+                            r#"WITH visible_posts AS (
     SELECT * 
     FROM wall_post wp
     join post p on p.id = wp.post_id  
     where wp.wall_id = $1
-    and (
-        (p.visibility = 'public'))
+    and p.deleted_at is null
+    and {}
 
 ),
 paged_posts AS (
@@ -192,6 +321,8 @@ paged_posts AS (
 
 SELECT * 
 FROM paged_posts;"#,
+                            visible_to_anonymous_condition()
+                        ),
                         [wall_id.id.into(), limit.into(), offset.into()],
                     ))
                     .all(self.db.as_ref())
@@ -199,72 +330,12 @@ FROM paged_posts;"#,
             }
         };
 
-        let future_posts = wall_posts.iter().map(|wall_post| {
-            let db_ref = self.db.clone();
-
-            async move {
-                let Some((post, Some(author))) =
-                    models::schema::post::Entity::find_by_id(wall_post.post_id)
-                        .find_also_related(schema::user::Entity)
-                        .one(db_ref.as_ref())
-                        .await?
-                else {
-                    unreachable!("Post without author");
-                };
-
-                let comments = models::schema::post_comment::Entity::find()
-                    .filter(
-                        models::schema::post_comment::Column::PostId
-                            .into_simple_expr()
-                            .eq(wall_post.post_id),
-                    )
-                    .find_also_related(schema::user::Entity)
-                    .all(db_ref.as_ref())
-                    .await?
-                    .into_iter()
-                    .map(|(comment, user)| {
-                        (comment.into(), user.expect("Comment without user").into())
-                    })
-                    .collect::<Vec<(PostComment, User)>>();
-
-                let likes = models::schema::post_like::Entity::find()
-                    .filter(
-                        models::schema::post_like::Column::PostId
-                            .into_simple_expr()
-                            .eq(wall_post.post_id),
-                    )
-                    .find_also_related(schema::user::Entity)
-                    .all(db_ref.as_ref())
-                    .await?
-                    .into_iter()
-                    .map(|(like, user)| (like.into(), user.expect("Like without user").into()))
-                    .collect::<Vec<(PostLike, User)>>();
-
-                let tags = models::schema::post_tag::Entity::find()
-                    .filter(
-                        models::schema::post_tag::Column::PostId
-                            .into_simple_expr()
-                            .eq(wall_post.post_id),
-                    )
-                    .all(db_ref.as_ref())
-                    .await?
-                    .into_iter()
-                    .map(|tag| tag.tag)
-                    .collect::<Vec<String>>();
-
-                let res: WallPostTuple =
-                    (Post::from(post), User::from(author), comments, likes, tags);
+        let post_ids = wall_posts
+            .iter()
+            .map(|wall_post| wall_post.post_id)
+            .collect::<Vec<_>>();
 
-                Ok(res)
-            }
-        });
-
-        let posts_awaited: Vec<Result<WallPostTuple, DbErr>> =
-            futures::future::join_all(future_posts).await;
-
-        let posts: Vec<WallPostTuple> = posts_awaited.into_iter().collect::<Result<_, _>>()?;
-
-        Ok(posts)
+        get_wall_post_tuples(self.db.clone(), post_ids, viewer_id.as_ref(), is_mod).await
     }
 
     async fn get_posts_by_tag(
@@ -291,6 +362,266 @@ FROM paged_posts;"#,
 
         Ok(posts)
     }
+
+    async fn get_followed_tags_feed(
+        &self,
+        user_id: Id<User>,
+        offset: i64,
+        limit: i64,
+    ) -> Result<Vec<WallPostTuple>, DbErr> {
+        // The user's own posts stay in the feed next to the posts with tags they follow,
+        // posts of muted authors are left out
+        let posts = models::schema::post::Entity::find()
+            .from_raw_sql(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                format!(
+                    r#"
+SELECT p.* 
+FROM post p
+WHERE p.deleted_at IS NULL
+    AND {}
+    AND {}
+    AND (
+        p.author_id = $1
+        OR EXISTS (
+            SELECT 1
+            FROM post_tag pt
+            JOIN tag_follow tf ON tf.tag = pt.tag
+            WHERE pt.post_id = p.id AND tf.user_id = $1
+        )
+    )
+ORDER BY p.created_at DESC
+LIMIT $2 OFFSET $3;
+"#,
+                    visible_to_user_condition(1),
+                    author_not_muted_condition(1)
+                ),
+                [user_id.id.into(), limit.into(), offset.into()],
+            ))
+            .all(self.db.as_ref())
+            .await?;
+
+        let post_ids = posts.iter().map(|post| post.id).collect::<Vec<_>>();
+
+        get_wall_post_tuples(self.db.clone(), post_ids, Some(&user_id), false).await
+    }
+
+    async fn get_posts_by_location(
+        &self,
+        location_id: Id<Location>,
+        user_id: Option<Id<User>>,
+        offset: i64,
+        limit: i64,
+    ) -> Result<Vec<WallPostTuple>, DbErr> {
+        let viewer_id = user_id.clone();
+        let posts = if let Some(user_id) = user_id {
+            models::schema::post::Entity::find()
+                .from_raw_sql(Statement::from_sql_and_values(
+                    DbBackend::Postgres,
+                    format!(
+                        r#"
+WITH visible_posts AS (
+    SELECT * 
+    FROM post p
+    WHERE 
+        (p.location_id = $1) AND (p.deleted_at IS NULL) AND {}
+),
+paged_posts AS (
+    SELECT * 
+    FROM visible_posts
+    ORDER BY created_at DESC
+    LIMIT $3 OFFSET $4
+)
+
+SELECT * 
+FROM paged_posts;
+"#,
+                        visible_to_user_condition(2)
+                    ),
+                    [
+                        location_id.id.into(),
+                        user_id.id.into(),
+                        limit.into(),
+                        offset.into(),
+                    ],
+                ))
+                .all(self.db.as_ref())
+                .await?
+        } else {
+            models::schema::post::Entity::find()
+                .from_raw_sql(Statement::from_sql_and_values(
+                    DbBackend::Postgres,
+                    format!(
+                        r#"
+SELECT * 
+FROM post p
+WHERE p.location_id = $1 AND {} AND p.deleted_at IS NULL
+ORDER BY created_at DESC
+LIMIT $2 OFFSET $3;
+"#,
+                        visible_to_anonymous_condition()
+                    ),
+                    [location_id.id.into(), limit.into(), offset.into()],
+                ))
+                .all(self.db.as_ref())
+                .await?
+        };
+
+        let post_ids = posts.iter().map(|post| post.id).collect::<Vec<_>>();
+
+        get_wall_post_tuples(self.db.clone(), post_ids, viewer_id.as_ref(), false).await
+    }
+
+    async fn get_posts_nearby(
+        &self,
+        center: GeoPoint,
+        radius_km: f64,
+        user_id: Option<Id<User>>,
+        offset: i64,
+        limit: i64,
+    ) -> Result<Vec<(WallPostTuple, f64)>, DbErr> {
+        let viewer_id = user_id.clone();
+        let (min_latitude, max_latitude) = latitude_band(&center, radius_km);
+        let mut values: Vec<Value> = vec![
+            center.latitude.into(),
+            center.longitude.into(),
+            radius_km.into(),
+            min_latitude.into(),
+            max_latitude.into(),
+            limit.into(),
+            offset.into(),
+        ];
+
+        let visibility = match user_id {
+            Some(user_id) => {
+                values.push(user_id.id.into());
+                visible_to_user_condition(8)
+            }
+            None => visible_to_anonymous_condition(),
+        };
+
+        // Posts are ordered by the distance of their location, the newest first within a place
+        let rows = PostDistanceRow::find_by_statement(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            format!(
+                r#"
+SELECT p.id, {} AS distance_km
+FROM post p
+JOIN location l ON l.id = p.location_id
+WHERE p.deleted_at IS NULL
+    AND l.latitude BETWEEN $4 AND $5
+    AND {} <= $3
+    AND {}
+ORDER BY distance_km, p.created_at DESC
+LIMIT $6 OFFSET $7;
+"#,
+                distance_km_sql(1, 2),
+                distance_km_sql(1, 2),
+                visibility
+            ),
+            values,
+        ))
+        .all(self.db.as_ref())
+        .await?;
+
+        let distances: HashMap<Uuid, f64> =
+            rows.iter().map(|row| (row.id, row.distance_km)).collect();
+        let post_ids = rows.into_iter().map(|row| row.id).collect::<Vec<_>>();
+
+        let posts =
+            get_wall_post_tuples(self.db.clone(), post_ids, viewer_id.as_ref(), false).await?;
+
+        Ok(posts
+            .into_iter()
+            .map(|post| {
+                let distance_km = distances.get(&post.0.id.id).copied().unwrap_or_default();
+
+                (post, distance_km)
+            })
+            .collect())
+    }
+
+    async fn get_saved_posts(
+        &self,
+        user_id: Id<User>,
+        collection_id: Option<Id<SavedCollection>>,
+        offset: i64,
+        limit: i64,
+    ) -> Result<Vec<WallPostTuple>, DbErr> {
+        // Saved posts go through the same visibility rules, so posts which turned private drop out
+        let posts = models::schema::post::Entity::find()
+            .from_raw_sql(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                format!(
+                    r#"
+SELECT p.* 
+FROM saved_post sp
+JOIN post p ON p.id = sp.post_id
+WHERE sp.user_id = $1
+    AND ($2::uuid IS NULL OR sp.collection_id = $2)
+    AND p.deleted_at IS NULL
+    AND {}
+ORDER BY sp.created_at DESC
+LIMIT $3 OFFSET $4;
+"#,
+                    visible_to_user_condition(1)
+                ),
+                [
+                    user_id.id.into(),
+                    collection_id.map(|id| id.id).into(),
+                    limit.into(),
+                    offset.into(),
+                ],
+            ))
+            .all(self.db.as_ref())
+            .await?;
+
+        let post_ids = posts.iter().map(|post| post.id).collect::<Vec<_>>();
+
+        get_wall_post_tuples(self.db.clone(), post_ids, Some(&user_id), false).await
+    }
+
+    async fn is_post_visible_to(
+        &self,
+        post_id: Id<Post>,
+        user_id: Option<Id<User>>,
+    ) -> Result<bool, DbErr> {
+        let post = if let Some(user_id) = user_id {
+            models::schema::post::Entity::find()
+                .from_raw_sql(Statement::from_sql_and_values(
+                    DbBackend::Postgres,
+                    format!(
+                        r#"
+SELECT * 
+FROM post p
+WHERE p.id = $2 AND p.deleted_at IS NULL AND {};
+"#,
+                        visible_to_user_condition(1)
+                    ),
+                    [user_id.id.into(), post_id.id.into()],
+                ))
+                .one(self.db.as_ref())
+                .await?
+        } else {
+            models::schema::post::Entity::find()
+                .from_raw_sql(Statement::from_sql_and_values(
+                    DbBackend::Postgres,
+                    format!(
+                        r#"
+SELECT * 
+FROM post p
+WHERE p.id = $1 AND {} AND p.deleted_at IS NULL;
+"#,
+                        visible_to_anonymous_condition()
+                    ),
+                    [post_id.id.into()],
+                ))
+                .one(self.db.as_ref())
+                .await?
+        };
+
+        Ok(post.is_some())
+    }
 }
 
 async fn get_posts_by_tag_helper(
@@ -301,6 +632,7 @@ async fn get_posts_by_tag_helper(
     limit: i64,
     is_mod: bool,
 ) -> Result<Vec<WallPostTuple>, DbErr> {
+    let viewer_id = user_id.clone();
     let post_tags = if is_mod {
         models::schema::post_tag::Entity::find()
             .from_raw_sql(Statement::from_sql_and_values(
@@ -310,7 +642,7 @@ WITH visible_posts AS (
     SELECT * 
     FROM post_tag pt 
     JOIN post p ON p.id = pt.post_id 
-    WHERE pt.tag = $1
+    WHERE pt.tag = $1 AND p.deleted_at IS NULL
 ),
 paged_posts AS (
     SELECT * 
@@ -330,25 +662,14 @@ FROM paged_posts;
         models::schema::post_tag::Entity::find()
             .from_raw_sql(Statement::from_sql_and_values(
                 DbBackend::Postgres,
-                r#"
+                format!(
+                    r#"
 WITH visible_posts AS (
     SELECT * 
     FROM post_tag pt 
     JOIN post p ON p.id = pt.post_id 
     WHERE 
-        (pt.tag = $1) AND (p.visibility = 'public'
-        OR (p.visibility = 'private' AND EXISTS (
-            SELECT 1 
-            FROM post_user_visibility puv 
-            WHERE puv.post_id = p.id AND puv.user_id = $2 
-        ))
-        OR (p.visibility = 'private' AND EXISTS (
-            SELECT 1 
-            FROM post_group_visibility pgv
-            JOIN group_member gm ON pgv.group_id = gm.group_id
-            WHERE pgv.post_id = p.id AND gm.user_id = $2
-        ))
-        OR (p.visibility = 'private' AND p.author_id = $2))
+        (pt.tag = $1) AND (p.deleted_at IS NULL) AND {}
 ),
 paged_posts AS (
     SELECT * 
@@ -360,6 +681,8 @@ paged_posts AS (
 SELECT * 
 FROM paged_posts;
 "#,
+                    visible_to_user_condition(2)
+                ),
                 [tag.into(), user_id.id.into(), limit.into(), offset.into()],
             ))
             .all(db_conn.as_ref())
@@ -368,83 +691,29 @@ FROM paged_posts;
         models::schema::post_tag::Entity::find()
             .from_raw_sql(Statement::from_sql_and_values(
                 DbBackend::Postgres,
-                r#"
+                format!(
+                    r#"
 SELECT * 
 from post_tag pt 
 join post p on p.id = pt.post_id 
-WHERE pt.tag = $1 and p.visibility = 'public'
+WHERE pt.tag = $1 and {} and p.deleted_at is null
 ORDER BY created_at DESC
 LIMIT $2 OFFSET $3;
                 "#,
+                    visible_to_anonymous_condition()
+                ),
                 [tag.into(), limit.into(), offset.into()],
             ))
             .all(db_conn.as_ref())
             .await?
     };
 
-    let future_posts = post_tags.iter().map(|post_tag| {
-        let db_ref = db_conn.clone();
-
-        async move {
-            let Some((post, Some(author))) =
-                models::schema::post::Entity::find_by_id(post_tag.post_id)
-                    .find_also_related(schema::user::Entity)
-                    .one(db_ref.as_ref())
-                    .await?
-            else {
-                unreachable!("Post without author");
-            };
-
-            let comments = models::schema::post_comment::Entity::find()
-                .filter(
-                    models::schema::post_comment::Column::PostId
-                        .into_simple_expr()
-                        .eq(post_tag.post_id),
-                )
-                .find_also_related(schema::user::Entity)
-                .all(db_ref.as_ref())
-                .await?
-                .into_iter()
-                .map(|(comment, user)| (comment.into(), user.expect("Comment without user").into()))
-                .collect::<Vec<(PostComment, User)>>();
-
-            let likes = models::schema::post_like::Entity::find()
-                .filter(
-                    models::schema::post_like::Column::PostId
-                        .into_simple_expr()
-                        .eq(post_tag.post_id),
-                )
-                .find_also_related(schema::user::Entity)
-                .all(db_ref.as_ref())
-                .await?
-                .into_iter()
-                .map(|(like, user)| (like.into(), user.expect("Like without user").into()))
-                .collect::<Vec<(PostLike, User)>>();
-
-            let tags = models::schema::post_tag::Entity::find()
-                .filter(
-                    models::schema::post_tag::Column::PostId
-                        .into_simple_expr()
-                        .eq(post_tag.post_id),
-                )
-                .all(db_ref.as_ref())
-                .await?
-                .into_iter()
-                .map(|tag| tag.tag)
-                .collect::<Vec<String>>();
-
-            let res: WallPostTuple = (Post::from(post), User::from(author), comments, likes, tags);
-
-            Ok(Some(res))
-        }
-    });
-
-    let posts_awaited: Vec<Result<Option<WallPostTuple>, DbErr>> =
-        futures::future::join_all(future_posts).await;
-
-    let posts: Vec<Option<WallPostTuple>> = posts_awaited.into_iter().collect::<Result<_, _>>()?;
+    let post_ids = post_tags
+        .iter()
+        .map(|post_tag| post_tag.post_id)
+        .collect::<Vec<_>>();
 
-    Ok(posts.into_iter().filter_map(|x| x).collect())
+    get_wall_post_tuples(db_conn, post_ids, viewer_id.as_ref(), is_mod).await
 }
 
 async fn get_feed_helper(
@@ -453,28 +722,18 @@ async fn get_feed_helper(
     offset: i64,
     limit: i64,
 ) -> Result<Vec<WallPostTuple>, DbErr> {
+    let viewer_id = user_id.clone();
     let posts = if let Some(user_id) = user_id {
         models::schema::post::Entity::find()
             .from_raw_sql(Statement::from_sql_and_values(
                 DbBackend::Postgres,
-                r#"
+                format!(
+                    r#"
 WITH visible_posts AS (
     SELECT * 
     FROM post p
     WHERE 
-        (p.visibility = 'public'
-        OR (p.visibility = 'private' AND EXISTS (
-            SELECT 1 
-            FROM post_user_visibility puv 
-            WHERE puv.post_id = p.id AND puv.user_id = $1
-        ))
-        OR (p.visibility = 'private' AND EXISTS (
-            SELECT 1 
-            FROM post_group_visibility pgv
-            JOIN group_member gm ON pgv.group_id = gm.group_id
-            WHERE pgv.post_id = p.id AND gm.user_id = $1
-        ))
-        OR (p.visibility = 'private' AND p.author_id = $1))
+        (p.deleted_at IS NULL) AND {} AND {}
 ),
 paged_posts AS (
     SELECT * 
@@ -486,6 +745,9 @@ paged_posts AS (
 SELECT * 
 FROM paged_posts;
 "#,
+                    visible_to_user_condition(1),
+                    author_not_muted_condition(1)
+                ),
                 [user_id.id.into(), limit.into(), offset.into()],
             ))
             .all(db_conn.as_ref())
@@ -494,46 +756,97 @@ FROM paged_posts;
         models::schema::post::Entity::find()
             .from_raw_sql(Statement::from_sql_and_values(
                 DbBackend::Postgres,
-                r#"
+                format!(
+                    r#"
 SELECT * 
 FROM post p
-WHERE p.visibility = 'public'
+WHERE {} AND p.deleted_at IS NULL
 ORDER BY created_at DESC
 LIMIT $1 OFFSET $2;
 "#,
+                    visible_to_anonymous_condition()
+                ),
                 [limit.into(), offset.into()],
             ))
             .all(db_conn.as_ref())
             .await?
     };
 
-    let future_posts = posts.into_iter().map(|post| {
+    let post_ids = posts.iter().map(|post| post.id).collect::<Vec<_>>();
+
+    get_wall_post_tuples(db_conn, post_ids, viewer_id.as_ref(), false).await
+}
+
+/// Loads the posts with their comments, likes and tags.
+/// Comments of limited users are left out unless the viewer wrote them or is a moderator,
+/// as are hidden comments the viewer may not see and comments of users who blocked the viewer.
+async fn get_wall_post_tuples(
+    db_conn: Arc<DbConn>,
+    post_ids: Vec<Uuid>,
+    viewer_id: Option<&Id<User>>,
+    is_mod: bool,
+) -> Result<Vec<WallPostTuple>, DbErr> {
+    let blocker_ids = get_blocker_ids(db_conn.as_ref(), viewer_id, is_mod).await?;
+
+    let future_posts = post_ids.into_iter().map(|post_id| {
         let db_ref = db_conn.clone();
+        let blocker_ids = &blocker_ids;
 
         async move {
-            let author = models::schema::user::Entity::find_by_id(post.author_id)
+            let Some((post, Some(author))) = models::schema::post::Entity::find_by_id(post_id)
+                .find_also_related(schema::user::Entity)
                 .one(db_ref.as_ref())
                 .await?
-                .expect("Post without author");
+            else {
+                return Ok(None);
+            };
 
             let comments = models::schema::post_comment::Entity::find()
                 .filter(
                     models::schema::post_comment::Column::PostId
                         .into_simple_expr()
-                        .eq(post.id),
+                        .eq(post_id)
+                        .and(
+                            models::schema::post_comment::Column::DeletedAt
+                                .into_simple_expr()
+                                .is_null(),
+                        ),
                 )
+                .order_by_asc(models::schema::post_comment::Column::CreatedAt)
                 .find_also_related(schema::user::Entity)
                 .all(db_ref.as_ref())
-                .await?
+                .await?;
+
+            let like_counts = get_comment_like_counts(
+                db_ref.as_ref(),
+                comments.iter().map(|(comment, _)| comment.id).collect(),
+            )
+            .await?;
+
+            let post_author_id = Id::new(post.author_id);
+            let comments = comments
                 .into_iter()
-                .map(|(comment, user)| (comment.into(), user.expect("Comment without user").into()))
-                .collect::<Vec<(PostComment, User)>>();
+                .map(|(comment, user)| {
+                    let like_count = like_counts.get(&comment.id).copied().unwrap_or(0);
+
+                    (
+                        PostComment::from(comment),
+                        User::from(user.expect("Comment without user")),
+                        like_count,
+                    )
+                })
+                .filter(|(comment, user, _)| {
+                    user.is_content_visible_to(viewer_id, is_mod)
+                        && comment.is_visible_to(viewer_id, &post_author_id, is_mod)
+                        && !blocker_ids.contains(&user.id.id)
+                })
+                .collect::<Vec<(PostComment, User, i64)>>();
 
             let likes = models::schema::post_like::Entity::find()
                 .filter(
                     models::schema::post_like::Column::PostId
                         .into_simple_expr()
-                        .eq(post.id),
+                        .eq(post_id),
                 )
                 .find_also_related(schema::user::Entity)
                 .all(db_ref.as_ref())
@@ -546,7 +859,7 @@ LIMIT $1 OFFSET $2;
                 .filter(
                     models::schema::post_tag::Column::PostId
                         .into_simple_expr()
-                        .eq(post.id),
+                        .eq(post_id),
                 )
                 .all(db_ref.as_ref())
                 .await?
@@ -554,7 +867,16 @@ LIMIT $1 OFFSET $2;
                 .map(|tag| tag.tag)
                 .collect::<Vec<String>>();
 
-            let res: WallPostTuple = (Post::from(post), User::from(author), comments, likes, tags);
+            let mentions = get_mentions_with_users(db_ref.as_ref(), vec![post_id]).await?;
+
+            let res: WallPostTuple = (
+                Post::from(post),
+                User::from(author),
+                comments,
+                likes,
+                tags,
+                mentions,
+            );
 
             Ok(Some(res))
         }
@@ -565,5 +887,5 @@ LIMIT $1 OFFSET $2;
 
     let posts: Vec<Option<WallPostTuple>> = posts_awaited.into_iter().collect::<Result<_, _>>()?;
 
-    Ok(posts.into_iter().filter_map(|x| x).collect())
+    Ok(posts.into_iter().flatten().collect())
 }
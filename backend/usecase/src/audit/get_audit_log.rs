@@ -0,0 +1,51 @@
+use models::{
+    domain::audit_log::AuditLogEntry,
+    errors::{AppError, AppResult},
+};
+use repository::audit_log_repository::{AuditLogFilter, AuditLogRepository};
+
+#[derive(Debug)]
+pub struct GetAuditLogInput {
+    pub filter: AuditLogFilter,
+    pub is_administrator: bool,
+    pub pagination: (i64, i64),
+}
+
+pub struct GetAuditLogOutput {
+    pub entries: Vec<AuditLogEntry>,
+}
+
+pub struct GetAuditLogUseCase<T>
+where
+    T: AuditLogRepository,
+{
+    audit_log_repository: T,
+}
+
+impl<T> GetAuditLogUseCase<T>
+where
+    T: AuditLogRepository,
+{
+    pub fn new(audit_log_repository: T) -> Self {
+        Self {
+            audit_log_repository,
+        }
+    }
+
+    pub async fn execute(&self, input: GetAuditLogInput) -> AppResult<GetAuditLogOutput> {
+        if !input.is_administrator {
+            return Err(AppError::Unauthorized(
+                "Only administrators can read the audit log".into(),
+            ));
+        }
+
+        let (offset, limit) = input.pagination;
+
+        let entries = self
+            .audit_log_repository
+            .get_entries(input.filter, offset, limit)
+            .await?;
+
+        Ok(GetAuditLogOutput { entries })
+    }
+}
@@ -0,0 +1,62 @@
+use models::{
+    domain::{
+        content_filter::{ContentFilter, ContentFilterAction, ContentFilterKind},
+        Id,
+    },
+    errors::{AppError, AppResult},
+};
+use repository::content_filter_repository::ContentFilterRepository;
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub struct CreateContentFilterInput {
+    pub pattern: String,
+    pub kind: ContentFilterKind,
+    pub action: ContentFilterAction,
+    pub created_by: Uuid,
+    pub is_administrator: bool,
+}
+
+pub struct CreateContentFilterOutput {
+    pub filter: ContentFilter,
+}
+
+pub struct CreateContentFilterUseCase<T>
+where
+    T: ContentFilterRepository,
+{
+    content_filter_repository: T,
+}
+
+impl<T> CreateContentFilterUseCase<T>
+where
+    T: ContentFilterRepository,
+{
+    pub fn new(content_filter_repository: T) -> Self {
+        Self {
+            content_filter_repository,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        input: CreateContentFilterInput,
+    ) -> AppResult<CreateContentFilterOutput> {
+        if !input.is_administrator {
+            return Err(AppError::Unauthorized(
+                "Only administrators can manage content filters".into(),
+            ));
+        }
+
+        let filter = ContentFilter::new(
+            input.pattern,
+            input.kind,
+            input.action,
+            Id::new(input.created_by),
+        )?;
+
+        let filter = self.content_filter_repository.create(filter).await?;
+
+        Ok(CreateContentFilterOutput { filter })
+    }
+}
@@ -0,0 +1,48 @@
+use models::{
+    domain::Id,
+    errors::{AppError, AppResult},
+};
+use repository::content_filter_repository::ContentFilterRepository;
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub struct DeleteContentFilterInput {
+    pub id: Uuid,
+    pub is_administrator: bool,
+}
+
+pub struct DeleteContentFilterUseCase<T>
+where
+    T: ContentFilterRepository,
+{
+    content_filter_repository: T,
+}
+
+impl<T> DeleteContentFilterUseCase<T>
+where
+    T: ContentFilterRepository,
+{
+    pub fn new(content_filter_repository: T) -> Self {
+        Self {
+            content_filter_repository,
+        }
+    }
+
+    pub async fn execute(&self, input: DeleteContentFilterInput) -> AppResult<()> {
+        if !input.is_administrator {
+            return Err(AppError::Unauthorized(
+                "Only administrators can manage content filters".into(),
+            ));
+        }
+
+        if !self
+            .content_filter_repository
+            .delete(Id::new(input.id))
+            .await?
+        {
+            return Err(AppError::NotFound("Content filter".into()));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,133 @@
+use models::{
+    domain::{
+        content_filter::{CompiledContentFilters, ContentFilterAction, ContentFilterHit},
+        report::{Report, ReportTargetType},
+        user::User,
+        Id,
+    },
+    errors::{AppError, AppResult},
+};
+use repository::{
+    content_filter_repository::ContentFilterRepository, report_repository::ReportRepository,
+};
+use uuid::Uuid;
+use validator::{ValidationError, ValidationErrors};
+
+/// A piece of user content to run through the content filters.
+#[derive(Debug)]
+pub struct FilterField {
+    pub name: &'static str,
+    pub text: String,
+    /// Fields used as identifiers (titles, usernames) can not be masked,
+    /// any hit on them is treated as a rejection
+    pub maskable: bool,
+}
+
+#[derive(Debug)]
+pub struct FilteredContent {
+    /// Texts of the fields in the order they were given, with masked matches
+    pub texts: Vec<String>,
+    /// Whether the content has to wait for a moderator before being shown
+    pub held: bool,
+    pub hits: Vec<ContentFilterHit>,
+}
+
+/// Runs the fields through every content filter. Rejected content is reported
+/// against its author and surfaced as a validation error on the offending field.
+pub(crate) async fn filter_content<F, R>(
+    content_filter_repository: &F,
+    report_repository: &R,
+    author_id: Option<Id<User>>,
+    fields: Vec<FilterField>,
+) -> AppResult<FilteredContent>
+where
+    F: ContentFilterRepository,
+    R: ReportRepository,
+{
+    let filters = CompiledContentFilters::new(content_filter_repository.get_all().await?);
+
+    let mut texts = vec![];
+    let mut hits = vec![];
+    let mut errors = ValidationErrors::new();
+
+    for field in fields {
+        let filtered = filters.apply(&field.text);
+
+        let rejected = match filtered.action() {
+            Some(ContentFilterAction::Reject) => true,
+            Some(_) => !field.maskable,
+            None => false,
+        };
+
+        if rejected {
+            errors.add(
+                field.name,
+                ValidationError::new("Content contains blocked terms")
+                    .with_message("Content contains blocked terms".into()),
+            );
+        }
+
+        texts.push(if field.maskable {
+            filtered.text
+        } else {
+            field.text
+        });
+        hits.extend(filtered.hits);
+    }
+
+    if !errors.is_empty() {
+        if let Some(author_id) = author_id {
+            flag_filtered_content(
+                report_repository,
+                ReportTargetType::User,
+                author_id.id,
+                &hits,
+            )
+            .await?;
+        }
+
+        return Err(AppError::ValidationError(errors));
+    }
+
+    let held = hits
+        .iter()
+        .any(|hit| hit.action == ContentFilterAction::Hold);
+
+    Ok(FilteredContent { texts, held, hits })
+}
+
+/// Files a report on the target for the moderation queue, unless the filter
+/// already has one pending for it.
+pub(crate) async fn flag_filtered_content<R>(
+    report_repository: &R,
+    target_type: ReportTargetType,
+    target_id: Uuid,
+    hits: &[ContentFilterHit],
+) -> AppResult<()>
+where
+    R: ReportRepository,
+{
+    if hits.is_empty()
+        || report_repository
+            .has_pending_report(None, target_type, target_id)
+            .await?
+    {
+        return Ok(());
+    }
+
+    let details = hits
+        .iter()
+        .map(|hit| format!("\"{}\" ({})", hit.matched, hit.action))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    report_repository
+        .create(Report::flag(
+            target_type,
+            target_id,
+            format!("Matched content filters: {details}"),
+        ))
+        .await?;
+
+    Ok(())
+}
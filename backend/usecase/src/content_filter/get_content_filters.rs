@@ -0,0 +1,47 @@
+use models::{
+    domain::content_filter::ContentFilter,
+    errors::{AppError, AppResult},
+};
+use repository::content_filter_repository::ContentFilterRepository;
+
+#[derive(Debug)]
+pub struct GetContentFiltersInput {
+    pub is_administrator: bool,
+}
+
+pub struct GetContentFiltersOutput {
+    pub filters: Vec<ContentFilter>,
+}
+
+pub struct GetContentFiltersUseCase<T>
+where
+    T: ContentFilterRepository,
+{
+    content_filter_repository: T,
+}
+
+impl<T> GetContentFiltersUseCase<T>
+where
+    T: ContentFilterRepository,
+{
+    pub fn new(content_filter_repository: T) -> Self {
+        Self {
+            content_filter_repository,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        input: GetContentFiltersInput,
+    ) -> AppResult<GetContentFiltersOutput> {
+        if !input.is_administrator {
+            return Err(AppError::Unauthorized(
+                "Only administrators can manage content filters".into(),
+            ));
+        }
+
+        let filters = self.content_filter_repository.get_all().await?;
+
+        Ok(GetContentFiltersOutput { filters })
+    }
+}
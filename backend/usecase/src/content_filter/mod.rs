@@ -0,0 +1,4 @@
+pub mod create_content_filter;
+pub mod delete_content_filter;
+pub mod filter_content;
+pub mod get_content_filters;
@@ -1,7 +1,15 @@
+pub mod audit;
+pub mod content_filter;
 pub mod group;
 pub mod location;
+pub mod notification;
 pub mod post;
 pub mod post_tag;
+pub mod report;
+pub mod saved;
+pub mod stats;
 pub mod user;
+pub mod user_relation;
 pub mod visibility;
 pub mod wall;
+pub mod tests;
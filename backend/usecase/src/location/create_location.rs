@@ -1,4 +1,7 @@
-use models::{domain::location::Location, errors::AppResult};
+use models::{
+    domain::location::{Location, DUPLICATE_DISTANCE_KM},
+    errors::AppResult,
+};
 use repository::location_repository::LocationRepository;
 use uuid::Uuid;
 
@@ -10,8 +13,12 @@ pub struct CreateLocationInput {
     pub picture_url: Option<String>,
 }
 
+const DUPLICATE_CANDIDATES_LIMIT: i64 = 20;
+
 pub struct CreateLocationOutput {
     pub id: Uuid,
+    /// Existing locations which most likely describe the same place
+    pub duplicates: Vec<Location>,
 }
 
 pub struct CreateLocationUseCase<T>
@@ -39,8 +46,22 @@ where
             input.longitude,
         )?;
 
+        let duplicates = self
+            .Location_repository
+            .get_nearby(
+                location.point(),
+                DUPLICATE_DISTANCE_KM,
+                DUPLICATE_CANDIDATES_LIMIT,
+            )
+            .await?
+            .into_iter()
+            .map(|(candidate, _)| candidate)
+            .filter(|candidate| location.is_likely_duplicate_of(candidate))
+            .collect();
+
         Ok(CreateLocationOutput {
             id: self.Location_repository.create(location).await?.id,
+            duplicates,
         })
     }
 }
@@ -0,0 +1,65 @@
+use models::{
+    domain::{location::Location, user::User, Id},
+    errors::AppResult,
+};
+use repository::{
+    location_repository::LocationRepository,
+    wall_repository::{WallPostTuple, WallRepository},
+};
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub struct GetLocationInput {
+    pub id: Uuid,
+    pub user_id: Option<Id<User>>,
+    pub pagination: (i64, i64),
+}
+
+pub struct GetLocationOutput {
+    pub location: Location,
+    pub posts: Vec<WallPostTuple>,
+}
+
+pub struct GetLocationUseCase<T, W>
+where
+    T: LocationRepository,
+    W: WallRepository,
+{
+    location_repository: T,
+    wall_repository: W,
+}
+
+impl<T, W> GetLocationUseCase<T, W>
+where
+    T: LocationRepository,
+    W: WallRepository,
+{
+    pub fn new(location_repository: T, wall_repository: W) -> Self {
+        Self {
+            location_repository,
+            wall_repository,
+        }
+    }
+
+    pub async fn execute(&self, input: GetLocationInput) -> AppResult<Option<GetLocationOutput>> {
+        let Some(location) = self
+            .location_repository
+            .get_by_id(Id::new(input.id))
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        let posts = self
+            .wall_repository
+            .get_posts_by_location(
+                location.id.clone(),
+                input.user_id,
+                input.pagination.0,
+                input.pagination.1,
+            )
+            .await?;
+
+        Ok(Some(GetLocationOutput { location, posts }))
+    }
+}
@@ -0,0 +1,50 @@
+use models::{
+    domain::location::{BoundingBox, Location},
+    errors::AppResult,
+};
+use repository::location_repository::LocationRepository;
+
+#[derive(Debug)]
+pub struct GetLocationsInBoundsInput {
+    pub south: f64,
+    pub west: f64,
+    pub north: f64,
+    pub east: f64,
+    pub limit: i64,
+}
+
+pub struct GetLocationsInBoundsOutput {
+    pub locations: Vec<Location>,
+}
+
+pub struct GetLocationsInBoundsUseCase<T>
+where
+    T: LocationRepository,
+{
+    location_repository: T,
+}
+
+impl<T> GetLocationsInBoundsUseCase<T>
+where
+    T: LocationRepository,
+{
+    pub fn new(location_repository: T) -> Self {
+        Self {
+            location_repository,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        input: GetLocationsInBoundsInput,
+    ) -> AppResult<GetLocationsInBoundsOutput> {
+        let bounds = BoundingBox::new(input.south, input.west, input.north, input.east)?;
+
+        let locations = self
+            .location_repository
+            .get_in_bounds(bounds, input.limit)
+            .await?;
+
+        Ok(GetLocationsInBoundsOutput { locations })
+    }
+}
@@ -0,0 +1,82 @@
+use models::{
+    domain::{
+        location::{GeoPoint, Location},
+        user::User,
+        Id,
+    },
+    errors::{AppError, AppResult},
+};
+use repository::{
+    location_repository::LocationRepository,
+    wall_repository::{WallPostTuple, WallRepository},
+};
+
+pub const MAX_NEARBY_RADIUS_KM: f64 = 200.0;
+const NEARBY_LOCATIONS_LIMIT: i64 = 50;
+
+#[derive(Debug)]
+pub struct GetNearbyInput {
+    pub latitude: f64,
+    pub longitude: f64,
+    pub radius_km: f64,
+    pub user_id: Option<Id<User>>,
+    pub pagination: (i64, i64),
+}
+
+/// Locations and posts within the radius together with their distance in kilometers,
+/// the closest first.
+pub struct GetNearbyOutput {
+    pub locations: Vec<(Location, f64)>,
+    pub posts: Vec<(WallPostTuple, f64)>,
+}
+
+pub struct GetNearbyUseCase<T, W>
+where
+    T: LocationRepository,
+    W: WallRepository,
+{
+    location_repository: T,
+    wall_repository: W,
+}
+
+impl<T, W> GetNearbyUseCase<T, W>
+where
+    T: LocationRepository,
+    W: WallRepository,
+{
+    pub fn new(location_repository: T, wall_repository: W) -> Self {
+        Self {
+            location_repository,
+            wall_repository,
+        }
+    }
+
+    pub async fn execute(&self, input: GetNearbyInput) -> AppResult<GetNearbyOutput> {
+        let center = GeoPoint::new(input.latitude, input.longitude)?;
+
+        if !(input.radius_km > 0.0 && input.radius_km <= MAX_NEARBY_RADIUS_KM) {
+            return Err(AppError::BadRequest(format!(
+                "Radius must be between 0 and {} km",
+                MAX_NEARBY_RADIUS_KM
+            )));
+        }
+
+        let locations = self
+            .location_repository
+            .get_nearby(center, input.radius_km, NEARBY_LOCATIONS_LIMIT)
+            .await?;
+
+        let posts = self
+            .wall_repository
+            .get_posts_nearby(
+                center,
+                input.radius_km,
+                input.user_id,
+                input.pagination.0,
+                input.pagination.1,
+            )
+            .await?;
+
+        Ok(GetNearbyOutput { locations, posts })
+    }
+}
@@ -0,0 +1,90 @@
+use models::{
+    domain::{location::Location, Id},
+    errors::{AppError, AppResult},
+};
+use repository::location_repository::LocationRepository;
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub struct MergeLocationsInput {
+    pub canonical_id: Uuid,
+    pub duplicate_ids: Vec<Uuid>,
+    pub is_moderator: bool,
+}
+
+pub struct MergeLocationsOutput {
+    pub location: Location,
+    pub moved_posts: u64,
+}
+
+pub struct MergeLocationsUseCase<T>
+where
+    T: LocationRepository,
+{
+    location_repository: T,
+}
+
+impl<T> MergeLocationsUseCase<T>
+where
+    T: LocationRepository,
+{
+    pub fn new(location_repository: T) -> Self {
+        Self {
+            location_repository,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        input: MergeLocationsInput,
+    ) -> AppResult<Option<MergeLocationsOutput>> {
+        if !input.is_moderator {
+            return Err(AppError::Unauthorized(
+                "Only moderators can merge locations".into(),
+            ));
+        }
+
+        let mut duplicate_ids = input.duplicate_ids;
+        duplicate_ids.sort();
+        duplicate_ids.dedup();
+
+        if duplicate_ids.is_empty() || duplicate_ids.contains(&input.canonical_id) {
+            return Err(AppError::BadRequest(
+                "Duplicates must be other locations than the canonical one".into(),
+            ));
+        }
+
+        let Some(location) = self
+            .location_repository
+            .get_by_id(Id::new(input.canonical_id))
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        let duplicates = self
+            .location_repository
+            .get_by_ids(duplicate_ids.iter().copied().map(Id::new).collect())
+            .await?;
+
+        if duplicates.len() != duplicate_ids.len() {
+            return Ok(None);
+        }
+
+        let moved_posts = self
+            .location_repository
+            .merge(
+                location.id.clone(),
+                duplicates
+                    .into_iter()
+                    .map(|duplicate| duplicate.id)
+                    .collect(),
+            )
+            .await?;
+
+        Ok(Some(MergeLocationsOutput {
+            location,
+            moved_posts,
+        }))
+    }
+}
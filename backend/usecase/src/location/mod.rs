@@ -1,3 +1,7 @@
 pub mod create_location;
 pub mod delete_location;
+pub mod get_location;
+pub mod get_locations_in_bounds;
+pub mod get_nearby;
+pub mod merge_locations;
 pub mod search_location;
@@ -0,0 +1,58 @@
+use models::{
+    domain::{notification::Notification, user::User, Id},
+    errors::AppResult,
+};
+use repository::notification_repository::NotificationRepository;
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub struct GetNotificationsInput {
+    pub user_id: Uuid,
+    pub unread_only: bool,
+    pub pagination: (i64, i64),
+}
+
+pub struct GetNotificationsOutput {
+    pub notifications: Vec<(Notification, User)>,
+    pub unread_count: u64,
+}
+
+pub struct GetNotificationsUseCase<T>
+where
+    T: NotificationRepository,
+{
+    notification_repository: T,
+}
+
+impl<T> GetNotificationsUseCase<T>
+where
+    T: NotificationRepository,
+{
+    pub fn new(notification_repository: T) -> Self {
+        Self {
+            notification_repository,
+        }
+    }
+
+    pub async fn execute(&self, input: GetNotificationsInput) -> AppResult<GetNotificationsOutput> {
+        let notifications = self
+            .notification_repository
+            .get_by_user_id(
+                Id::new(input.user_id),
+                input.unread_only,
+                input.pagination.0,
+                input.pagination.1,
+            )
+            .await?;
+
+        let unread_count = self
+            .notification_repository
+            .count_unread(Id::new(input.user_id))
+            .await?;
+
+        Ok(GetNotificationsOutput {
+            notifications,
+            unread_count,
+        })
+    }
+}
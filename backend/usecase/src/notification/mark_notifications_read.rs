@@ -0,0 +1,47 @@
+use models::{domain::Id, errors::AppResult};
+use repository::notification_repository::NotificationRepository;
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub struct MarkNotificationsReadInput {
+    pub user_id: Uuid,
+    /// Marks all notifications of the user when `None`
+    pub ids: Option<Vec<Uuid>>,
+}
+
+pub struct MarkNotificationsReadOutput {
+    pub updated: u64,
+}
+
+pub struct MarkNotificationsReadUseCase<T>
+where
+    T: NotificationRepository,
+{
+    notification_repository: T,
+}
+
+impl<T> MarkNotificationsReadUseCase<T>
+where
+    T: NotificationRepository,
+{
+    pub fn new(notification_repository: T) -> Self {
+        Self {
+            notification_repository,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        input: MarkNotificationsReadInput,
+    ) -> AppResult<MarkNotificationsReadOutput> {
+        let updated = self
+            .notification_repository
+            .mark_read(
+                Id::new(input.user_id),
+                input.ids.map(|ids| ids.into_iter().map(Id::new).collect()),
+            )
+            .await?;
+
+        Ok(MarkNotificationsReadOutput { updated })
+    }
+}
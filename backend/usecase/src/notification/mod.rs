@@ -0,0 +1,3 @@
+pub mod get_notifications;
+pub mod mark_notifications_read;
+pub mod notify_mentions;
@@ -0,0 +1,98 @@
+use models::{
+    domain::{
+        mention::Mention,
+        notification::{Notification, NotificationKind},
+        user_relation::UserRelationKind,
+    },
+    errors::AppResult,
+};
+use repository::{
+    mention_repository::MentionRepository, notification_repository::NotificationRepository,
+    user_relation_repository::UserRelationRepository, wall_repository::WallRepository,
+};
+
+#[derive(Debug)]
+pub struct NotifyMentionsInput {
+    pub mentions: Vec<Mention>,
+}
+
+pub struct NotifyMentionsOutput {
+    pub notified: usize,
+}
+
+pub struct NotifyMentionsUseCase<T, U, M, B>
+where
+    T: WallRepository,
+    U: NotificationRepository,
+    M: MentionRepository,
+    B: UserRelationRepository,
+{
+    wall_repository: T,
+    notification_repository: U,
+    mention_repository: M,
+    user_relation_repository: B,
+}
+
+impl<T, U, M, B> NotifyMentionsUseCase<T, U, M, B>
+where
+    T: WallRepository,
+    U: NotificationRepository,
+    M: MentionRepository,
+    B: UserRelationRepository,
+{
+    pub fn new(
+        wall_repository: T,
+        notification_repository: U,
+        mention_repository: M,
+        user_relation_repository: B,
+    ) -> Self {
+        Self {
+            wall_repository,
+            notification_repository,
+            mention_repository,
+            user_relation_repository,
+        }
+    }
+
+    /// Has to run once the visibility of the post is set up, users who can't see the post
+    /// or who blocked the author of the mention are not told they were mentioned in it
+    /// and their mentions are dropped.
+    pub async fn execute(&self, input: NotifyMentionsInput) -> AppResult<NotifyMentionsOutput> {
+        let mut notified = 0;
+
+        for mention in input.mentions {
+            let visible = self
+                .wall_repository
+                .is_post_visible_to(mention.post_id.clone(), Some(mention.user_id.clone()))
+                .await?;
+
+            let blocked = self
+                .user_relation_repository
+                .exists(
+                    mention.user_id.clone(),
+                    mention.author_id.clone(),
+                    UserRelationKind::Block,
+                )
+                .await?;
+
+            if !visible || blocked {
+                self.mention_repository.delete(mention.id).await?;
+                continue;
+            }
+
+            self.notification_repository
+                .create(Notification::new(
+                    mention.user_id,
+                    mention.author_id,
+                    NotificationKind::Mention,
+                    Some(mention.post_id),
+                    mention.comment_id,
+                ))
+                .await?;
+
+            notified += 1;
+        }
+
+        Ok(NotifyMentionsOutput { notified })
+    }
+}
@@ -1,10 +1,26 @@
 use models::{
-    domain::{post_comment::PostComment, Id},
-    errors::AppResult,
+    domain::{
+        mention::Mention,
+        post::CommentPolicy,
+        post_comment::{PostComment, MAX_COMMENT_DEPTH},
+        report::ReportTargetType,
+        user_relation::UserRelationKind,
+        Id,
+    },
+    errors::{AppError, AppResult},
+};
+use repository::{
+    content_filter_repository::ContentFilterRepository, mention_repository::MentionRepository,
+    post_comments_repository::PostCommentsRepository, post_repository::PostRepository,
+    report_repository::ReportRepository, user_relation_repository::UserRelationRepository,
+    user_repository::UserRepository,
 };
-use repository::post_comments_repository::PostCommentsRepository;
 use uuid::Uuid;
 
+use crate::content_filter::filter_content::{filter_content, flag_filtered_content, FilterField};
+
+use super::mentions::resolve_mentions;
+
 #[derive(Debug)]
 pub struct CommentPostInput {
     pub post_id: Uuid,
@@ -15,35 +31,201 @@ pub struct CommentPostInput {
 
 pub struct CommentPostOutput {
     pub id: Uuid,
+    /// Content as stored, with terms masked by the content filter
+    pub content: String,
+    pub mentions: Vec<Mention>,
+    /// Whether the comment waits for a moderator before being shown
+    pub held: bool,
 }
 
-pub struct CommentPostUseCase<T>
+pub struct CommentPostUseCase<T, P, U, M, F, R, B>
 where
     T: PostCommentsRepository,
+    P: PostRepository,
+    U: UserRepository,
+    M: MentionRepository,
+    F: ContentFilterRepository,
+    R: ReportRepository,
+    B: UserRelationRepository,
 {
     post_comments_repository: T,
+    post_repository: P,
+    user_repository: U,
+    mention_repository: M,
+    content_filter_repository: F,
+    report_repository: R,
+    user_relation_repository: B,
 }
 
-impl<T> CommentPostUseCase<T>
+impl<T, P, U, M, F, R, B> CommentPostUseCase<T, P, U, M, F, R, B>
 where
     T: PostCommentsRepository,
+    P: PostRepository,
+    U: UserRepository,
+    M: MentionRepository,
+    F: ContentFilterRepository,
+    R: ReportRepository,
+    B: UserRelationRepository,
 {
-    pub fn new(post_comments_repository: T) -> Self {
+    pub fn new(
+        post_comments_repository: T,
+        post_repository: P,
+        user_repository: U,
+        mention_repository: M,
+        content_filter_repository: F,
+        report_repository: R,
+        user_relation_repository: B,
+    ) -> Self {
         Self {
             post_comments_repository,
+            post_repository,
+            user_repository,
+            mention_repository,
+            content_filter_repository,
+            report_repository,
+            user_relation_repository,
         }
     }
 
     pub async fn execute(&self, input: CommentPostInput) -> AppResult<Option<CommentPostOutput>> {
-        let post_comment = PostComment::new(
+        let user_id = Id::new(input.user_id);
+        let post = self
+            .post_repository
+            .get_by_id(Id::new(input.post_id))
+            .await?
+            .ok_or(AppError::NotFound("Post".into()))?;
+
+        // Posts of authors who blocked the commenter are hidden from them, as if they did not exist
+        if self
+            .user_relation_repository
+            .exists(
+                post.author_id.clone(),
+                user_id.clone(),
+                UserRelationKind::Block,
+            )
+            .await?
+        {
+            return Err(AppError::NotFound("Post".into()));
+        }
+
+        let is_follower = post.comment_policy == CommentPolicy::Followers
+            && self
+                .user_relation_repository
+                .exists(
+                    user_id.clone(),
+                    post.author_id.clone(),
+                    UserRelationKind::Follow,
+                )
+                .await?;
+
+        if !post.accepts_comments_from(&user_id, is_follower) {
+            return Err(AppError::Unauthorized(match post.comment_policy {
+                CommentPolicy::Followers => {
+                    "Only followers of the author can comment on this post".into()
+                }
+                _ => "Comments are turned off for this post".into(),
+            }));
+        }
+
+        if let Some(parent_id) = input.parent_id {
+            let parent = self
+                .post_comments_repository
+                .get_comment_by_id(Id::new(parent_id))
+                .await?
+                .ok_or(AppError::NotFound("Parent comment".into()))?;
+
+            if parent.post_id.id != input.post_id {
+                return Err(AppError::BadRequest(
+                    "Parent comment belongs to a different post".into(),
+                ));
+            }
+
+            if self
+                .user_relation_repository
+                .exists(
+                    parent.user_id,
+                    Id::new(input.user_id),
+                    UserRelationKind::Block,
+                )
+                .await?
+            {
+                return Err(AppError::NotFound("Parent comment".into()));
+            }
+
+            let parent_depth = self
+                .post_comments_repository
+                .get_comment_depth(parent.id)
+                .await?;
+
+            if parent_depth >= MAX_COMMENT_DEPTH {
+                return Err(AppError::BadRequest(format!(
+                    "Replies can't be nested more than {MAX_COMMENT_DEPTH} levels deep"
+                )));
+            }
+        }
+
+        let filtered = filter_content(
+            &self.content_filter_repository,
+            &self.report_repository,
+            Some(Id::new(input.user_id)),
+            vec![FilterField {
+                name: "content",
+                text: input.content,
+                maskable: true,
+            }],
+        )
+        .await?;
+
+        let mut post_comment = PostComment::new(
             Id::new(input.post_id),
             Id::new(input.user_id),
-            input.content,
+            filtered.texts.into_iter().next().unwrap_or_default(),
             input.parent_id.map(Id::new),
         )?;
 
+        if filtered.held {
+            post_comment.hold_for_review();
+        }
+
+        // Nobody gets notified about a comment that may never be shown
+        let mentioned_user_ids = if filtered.held {
+            vec![]
+        } else {
+            resolve_mentions(
+                &self.user_repository,
+                &self.user_relation_repository,
+                &post_comment.content,
+                &post_comment.user_id,
+            )
+            .await?
+        };
+
+        let content = post_comment.content.clone();
+        let comment_id = self.post_comments_repository.create(post_comment).await?;
+
+        flag_filtered_content(
+            &self.report_repository,
+            ReportTargetType::Comment,
+            comment_id.id,
+            &filtered.hits,
+        )
+        .await?;
+
+        let mentions = self
+            .mention_repository
+            .replace_mentions(
+                Id::new(input.post_id),
+                Some(comment_id.clone()),
+                Id::new(input.user_id),
+                mentioned_user_ids,
+            )
+            .await?;
+
         Ok(Some(CommentPostOutput {
-            id: self.post_comments_repository.create(post_comment).await?.id,
+            id: comment_id.id,
+            content,
+            mentions,
+            held: filtered.held,
         }))
     }
 }
@@ -1,17 +1,26 @@
 use models::{
     domain::{
-        post::{Post, PostType, PostVisibilityType},
+        image_variant::ImageVariant,
+        mention::Mention,
+        post::{CommentPolicy, Post, PostType, PostVisibilityType},
+        report::ReportTargetType,
         wall_post::WallPost,
         Id,
     },
     errors::{AppError, AppResult},
 };
 use repository::{
-    post_repository::PostRepository, user_repository::UserRepository,
+    content_filter_repository::ContentFilterRepository, mention_repository::MentionRepository,
+    post_repository::PostRepository, report_repository::ReportRepository,
+    user_relation_repository::UserRelationRepository, user_repository::UserRepository,
     wall_post_repository::WallPostRepository,
 };
 use uuid::Uuid;
 
+use crate::content_filter::filter_content::{filter_content, flag_filtered_content, FilterField};
+
+use super::mentions::resolve_mentions;
+
 #[derive(Debug)]
 pub struct CreatePostInput {
     pub title: String,
@@ -21,34 +30,65 @@ pub struct CreatePostInput {
     pub content_url: String,
     pub visibility: PostVisibilityType,
     pub location_id: Option<Uuid>,
+    pub image_variants: Vec<ImageVariant>,
+    pub comment_policy: CommentPolicy,
 }
 
 pub struct CreatePostOutput {
     pub id: Uuid,
+    /// Description as stored, with terms masked by the content filter
+    pub description: String,
+    pub mentions: Vec<Mention>,
+    /// Whether the post waits for a moderator before being published
+    pub held: bool,
 }
 
-pub struct CreatePostUseCase<T, U, XD>
+pub struct CreatePostUseCase<T, U, XD, M, F, R, B>
 where
     T: PostRepository,
     U: WallPostRepository,
     XD: UserRepository,
+    M: MentionRepository,
+    F: ContentFilterRepository,
+    R: ReportRepository,
+    B: UserRelationRepository,
 {
     post_repository: T,
     wall_post_repository: U,
     user_repository: XD,
+    mention_repository: M,
+    content_filter_repository: F,
+    report_repository: R,
+    user_relation_repository: B,
 }
 
-impl<T, U, XD> CreatePostUseCase<T, U, XD>
+impl<T, U, XD, M, F, R, B> CreatePostUseCase<T, U, XD, M, F, R, B>
 where
     T: PostRepository,
     U: WallPostRepository,
     XD: UserRepository,
+    M: MentionRepository,
+    F: ContentFilterRepository,
+    R: ReportRepository,
+    B: UserRelationRepository,
 {
-    pub fn new(post_repository: T, wall_post_repository: U, user_repository: XD) -> Self {
+    pub fn new(
+        post_repository: T,
+        wall_post_repository: U,
+        user_repository: XD,
+        mention_repository: M,
+        content_filter_repository: F,
+        report_repository: R,
+        user_relation_repository: B,
+    ) -> Self {
         Self {
             post_repository,
             wall_post_repository,
             user_repository,
+            mention_repository,
+            content_filter_repository,
+            report_repository,
+            user_relation_repository,
         }
     }
 
@@ -59,24 +99,85 @@ where
             .await?
             .ok_or_else(|| AppError::NotFound("Author".to_string()))?;
 
-        let post = Post::new(
-            input.title,
-            input.description,
+        let filtered = filter_content(
+            &self.content_filter_repository,
+            &self.report_repository,
+            Some(author.id.clone()),
+            vec![
+                FilterField {
+                    name: "title",
+                    text: input.title,
+                    maskable: false,
+                },
+                FilterField {
+                    name: "description",
+                    text: input.description,
+                    maskable: true,
+                },
+            ],
+        )
+        .await?;
+        let mut texts = filtered.texts.into_iter();
+
+        let mut post = Post::new(
+            texts.next().unwrap_or_default(),
+            texts.next().unwrap_or_default(),
             Id::new(input.author_id),
             input.post_type,
             input.content_url,
             input.visibility,
             input.location_id.map(Id::new),
-        )?;
+        )?
+        .with_image_variants(input.image_variants)?;
+        post.comment_policy = input.comment_policy;
+
+        if filtered.held {
+            post.hold_for_review();
+        }
+
+        // Nobody gets notified about a post that may never be published
+        let mentioned_user_ids = if filtered.held {
+            vec![]
+        } else {
+            resolve_mentions(
+                &self.user_repository,
+                &self.user_relation_repository,
+                &post.description,
+                &author.id,
+            )
+            .await?
+        };
 
+        let description = post.description.clone();
         let inserted_post_id = self.post_repository.create(post).await?.id;
 
+        flag_filtered_content(
+            &self.report_repository,
+            ReportTargetType::Post,
+            inserted_post_id,
+            &filtered.hits,
+        )
+        .await?;
+
         let wall_post = WallPost::new(inserted_post_id.into(), author.wall_id);
 
         self.wall_post_repository.create(wall_post).await?;
 
+        let mentions = self
+            .mention_repository
+            .replace_mentions(
+                Id::new(inserted_post_id),
+                None,
+                author.id,
+                mentioned_user_ids,
+            )
+            .await?;
+
         Ok(CreatePostOutput {
             id: inserted_post_id,
+            description,
+            mentions,
+            held: filtered.held,
         })
     }
 }
@@ -1,5 +1,8 @@
 use models::{
-    domain::Id,
+    domain::{
+        audit_log::{post_snapshot, AuditAction, AuditLogEntry},
+        Id,
+    },
     errors::AppResult,
 };
 use repository::post_repository::PostRepository;
@@ -8,6 +11,9 @@ use uuid::Uuid;
 #[derive(Debug)]
 pub struct DeletePostInput {
     pub id: Uuid,
+    pub deleted_by: Uuid,
+    /// Recorded in the audit log when the post is deleted by someone else than its author
+    pub reason: Option<String>,
 }
 
 pub struct DeletePostOutput {
@@ -30,7 +36,29 @@ where
     }
 
     pub async fn execute(&self, input: DeletePostInput) -> AppResult<Option<DeletePostOutput>> {
-        match self.post_repository.delete_by_id(Id::new(input.id)).await? {
+        let Some(post) = self.post_repository.get_by_id(Id::new(input.id)).await? else {
+            return Ok(Some(DeletePostOutput { success: false }));
+        };
+
+        // Authors removing their own posts are not moderation
+        let audit = if post.author_id.id != input.deleted_by {
+            Some(AuditLogEntry::new(
+                Id::new(input.deleted_by),
+                AuditAction::DeletePost,
+                input.id,
+                input.reason,
+                Some(post_snapshot(&post)),
+                None,
+            )?)
+        } else {
+            None
+        };
+
+        match self
+            .post_repository
+            .soft_delete_by_id(post.id, Id::new(input.deleted_by), audit)
+            .await?
+        {
             true => Ok(Some(DeletePostOutput { success: true })),
             false => Ok(Some(DeletePostOutput { success: false })),
         }
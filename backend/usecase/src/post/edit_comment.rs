@@ -0,0 +1,108 @@
+use models::{
+    domain::{post_comment::PostComment, report::ReportTargetType, Id},
+    errors::{AppError, AppResult},
+};
+use repository::{
+    content_filter_repository::ContentFilterRepository,
+    post_comments_repository::PostCommentsRepository, report_repository::ReportRepository,
+};
+use uuid::Uuid;
+
+use crate::content_filter::filter_content::{filter_content, flag_filtered_content, FilterField};
+
+#[derive(Debug)]
+pub struct EditCommentInput {
+    pub id: Uuid,
+    pub post_id: Uuid,
+    pub user_id: Uuid,
+    pub content: String,
+}
+
+pub struct EditCommentOutput {
+    pub comment: PostComment,
+    /// Whether the comment went back to waiting for a moderator
+    pub held: bool,
+}
+
+pub struct EditCommentUseCase<T, F, R>
+where
+    T: PostCommentsRepository,
+    F: ContentFilterRepository,
+    R: ReportRepository,
+{
+    post_comments_repository: T,
+    content_filter_repository: F,
+    report_repository: R,
+}
+
+impl<T, F, R> EditCommentUseCase<T, F, R>
+where
+    T: PostCommentsRepository,
+    F: ContentFilterRepository,
+    R: ReportRepository,
+{
+    pub fn new(
+        post_comments_repository: T,
+        content_filter_repository: F,
+        report_repository: R,
+    ) -> Self {
+        Self {
+            post_comments_repository,
+            content_filter_repository,
+            report_repository,
+        }
+    }
+
+    pub async fn execute(&self, input: EditCommentInput) -> AppResult<Option<EditCommentOutput>> {
+        let Some(mut comment) = self
+            .post_comments_repository
+            .get_comment_by_id(Id::new(input.id))
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        if comment.post_id.id != input.post_id {
+            return Ok(None);
+        }
+
+        if comment.user_id.id != input.user_id {
+            return Err(AppError::Unauthorized(
+                "Only the author can edit the comment".into(),
+            ));
+        }
+
+        let filtered = filter_content(
+            &self.content_filter_repository,
+            &self.report_repository,
+            Some(comment.user_id.clone()),
+            vec![FilterField {
+                name: "content",
+                text: input.content,
+                maskable: true,
+            }],
+        )
+        .await?;
+
+        comment.edit(filtered.texts.into_iter().next().unwrap_or_default())?;
+
+        if filtered.held {
+            comment.hold_for_review();
+        }
+
+        let comment = self.post_comments_repository.update(comment).await?;
+
+        flag_filtered_content(
+            &self.report_repository,
+            ReportTargetType::Comment,
+            comment.id.id,
+            &filtered.hits,
+        )
+        .await?;
+
+        Ok(Some(EditCommentOutput {
+            comment,
+            held: filtered.held,
+        }))
+    }
+}
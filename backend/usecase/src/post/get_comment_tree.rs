@@ -0,0 +1,87 @@
+use models::{
+    domain::{
+        comment_tree::{build_comment_tree, CommentNode, CommentTreeOptions},
+        Id,
+    },
+    errors::AppResult,
+};
+use repository::{
+    post_comments_repository::PostCommentsRepository,
+    user_relation_repository::UserRelationRepository,
+};
+use uuid::Uuid;
+
+use crate::user_relation::check_user_block::ensure_not_blocked_by_post_author;
+
+#[derive(Debug)]
+pub struct GetCommentTreeInput {
+    pub post_id: Uuid,
+    pub pagination: (i64, i64),
+    pub max_depth: usize,
+    pub replies_limit: usize,
+    pub viewer_id: Option<Uuid>,
+    pub is_moderator: bool,
+}
+
+pub struct GetCommentTreeOutput {
+    pub comments: Vec<CommentNode>,
+    pub total: usize,
+}
+
+pub struct GetCommentTreeUseCase<T, B>
+where
+    T: PostCommentsRepository,
+    B: UserRelationRepository,
+{
+    post_comments_repository: T,
+    user_relation_repository: B,
+}
+
+impl<T, B> GetCommentTreeUseCase<T, B>
+where
+    T: PostCommentsRepository,
+    B: UserRelationRepository,
+{
+    pub fn new(post_comments_repository: T, user_relation_repository: B) -> Self {
+        Self {
+            post_comments_repository,
+            user_relation_repository,
+        }
+    }
+
+    pub async fn execute(&self, input: GetCommentTreeInput) -> AppResult<GetCommentTreeOutput> {
+        ensure_not_blocked_by_post_author(
+            &self.user_relation_repository,
+            Id::new(input.post_id),
+            input.viewer_id.filter(|_| !input.is_moderator),
+        )
+        .await?;
+
+        let comments = self
+            .post_comments_repository
+            .get_comments_by_post_id(
+                Id::new(input.post_id),
+                input.viewer_id.map(Id::new),
+                input.is_moderator,
+            )
+            .await?
+            .unwrap_or_default();
+
+        let (offset, limit) = input.pagination;
+        let tree = build_comment_tree(
+            comments,
+            None,
+            &CommentTreeOptions {
+                offset: offset.max(0) as usize,
+                limit: limit.max(0) as usize,
+                max_depth: input.max_depth,
+                replies_limit: input.replies_limit,
+            },
+        );
+
+        Ok(GetCommentTreeOutput {
+            comments: tree.comments,
+            total: tree.total,
+        })
+    }
+}
@@ -0,0 +1,43 @@
+use models::{
+    domain::{post::Post, Id},
+    errors::AppResult,
+};
+use repository::post_repository::PostRepository;
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub struct GetDeletedPostsInput {
+    pub author_id: Option<Uuid>,
+    pub pagination: (i64, i64),
+}
+
+pub struct GetDeletedPostsOutput {
+    pub posts: Vec<Post>,
+}
+
+pub struct GetDeletedPostsUseCase<T>
+where
+    T: PostRepository,
+{
+    post_repository: T,
+}
+
+impl<T> GetDeletedPostsUseCase<T>
+where
+    T: PostRepository,
+{
+    pub fn new(post_repository: T) -> Self {
+        Self { post_repository }
+    }
+
+    pub async fn execute(&self, input: GetDeletedPostsInput) -> AppResult<GetDeletedPostsOutput> {
+        let (offset, limit) = input.pagination;
+
+        let posts = self
+            .post_repository
+            .get_deleted(input.author_id.map(Id::new), offset, limit)
+            .await?;
+
+        Ok(GetDeletedPostsOutput { posts })
+    }
+}
@@ -0,0 +1,58 @@
+use models::{
+    domain::{post::Post, post_comment::PostComment},
+    errors::{AppError, AppResult},
+};
+use repository::{
+    post_comments_repository::PostCommentsRepository, post_repository::PostRepository,
+};
+
+#[derive(Debug)]
+pub struct GetHeldContentInput {
+    pub is_moderator: bool,
+    pub pagination: (i64, i64),
+}
+
+pub struct GetHeldContentOutput {
+    pub posts: Vec<Post>,
+    pub comments: Vec<PostComment>,
+}
+
+pub struct GetHeldContentUseCase<T, U>
+where
+    T: PostRepository,
+    U: PostCommentsRepository,
+{
+    post_repository: T,
+    post_comments_repository: U,
+}
+
+impl<T, U> GetHeldContentUseCase<T, U>
+where
+    T: PostRepository,
+    U: PostCommentsRepository,
+{
+    pub fn new(post_repository: T, post_comments_repository: U) -> Self {
+        Self {
+            post_repository,
+            post_comments_repository,
+        }
+    }
+
+    pub async fn execute(&self, input: GetHeldContentInput) -> AppResult<GetHeldContentOutput> {
+        if !input.is_moderator {
+            return Err(AppError::Unauthorized(
+                "Only moderators can review held content".into(),
+            ));
+        }
+
+        let (offset, limit) = input.pagination;
+
+        let posts = self.post_repository.get_held(offset, limit).await?;
+        let comments = self
+            .post_comments_repository
+            .get_held(offset, limit)
+            .await?;
+
+        Ok(GetHeldContentOutput { posts, comments })
+    }
+}
@@ -1,7 +1,5 @@
 use models::{
-    domain::{
-        post_comment::PostComment, user::User, Id
-    },
+    domain::{post_comment::PostComment, user::User, Id},
     errors::AppResult,
 };
 use repository::post_comments_repository::PostCommentsRepository;
@@ -10,10 +8,12 @@ use uuid::Uuid;
 #[derive(Debug)]
 pub struct GetPostCommentsInput {
     pub id: Uuid,
+    pub viewer_id: Option<Uuid>,
+    pub is_moderator: bool,
 }
 
 pub struct GetPostCommentsOutput {
-    pub comments: Vec<(PostComment, User)>,
+    pub comments: Vec<(PostComment, User, i64)>,
 }
 
 pub struct GetPostCommentsUseCase<T>
@@ -37,7 +37,11 @@ where
     ) -> AppResult<Option<GetPostCommentsOutput>> {
         let result = self
             .post_repository
-            .get_comments_by_post_id(Id::new(input.id))
+            .get_comments_by_post_id(
+                Id::new(input.id),
+                input.viewer_id.map(Id::new),
+                input.is_moderator,
+            )
             .await?;
 
         match result {
@@ -0,0 +1,41 @@
+use models::{
+    domain::{mention::Mention, user::User},
+    errors::AppResult,
+};
+use repository::mention_repository::MentionRepository;
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub struct GetPostMentionsInput {
+    pub post_id: Uuid,
+}
+
+pub struct GetPostMentionsOutput {
+    /// Mentions of the post description and of its comments with the mentioned users
+    pub mentions: Vec<(Mention, User)>,
+}
+
+pub struct GetPostMentionsUseCase<T>
+where
+    T: MentionRepository,
+{
+    mention_repository: T,
+}
+
+impl<T> GetPostMentionsUseCase<T>
+where
+    T: MentionRepository,
+{
+    pub fn new(mention_repository: T) -> Self {
+        Self { mention_repository }
+    }
+
+    pub async fn execute(&self, input: GetPostMentionsInput) -> AppResult<GetPostMentionsOutput> {
+        let mentions = self
+            .mention_repository
+            .get_by_post_id(input.post_id.into())
+            .await?;
+
+        Ok(GetPostMentionsOutput { mentions })
+    }
+}
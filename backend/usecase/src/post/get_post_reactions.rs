@@ -0,0 +1,72 @@
+use models::{
+    domain::{reaction::ReactionCount, Id},
+    errors::AppResult,
+};
+use repository::{
+    post_likes_repository::PostLikesRepository, user_relation_repository::UserRelationRepository,
+};
+use uuid::Uuid;
+
+use crate::user_relation::check_user_block::ensure_not_blocked_by_post_author;
+
+#[derive(Debug)]
+pub struct GetPostReactionsInput {
+    pub post_id: Uuid,
+    pub user_id: Option<Uuid>,
+    pub is_moderator: bool,
+}
+
+pub struct GetPostReactionsOutput {
+    pub reactions: Vec<ReactionCount>,
+    pub user_reaction: Option<String>,
+}
+
+pub struct GetPostReactionsUseCase<T, B>
+where
+    T: PostLikesRepository,
+    B: UserRelationRepository,
+{
+    post_likes_repository: T,
+    user_relation_repository: B,
+}
+
+impl<T, B> GetPostReactionsUseCase<T, B>
+where
+    T: PostLikesRepository,
+    B: UserRelationRepository,
+{
+    pub fn new(post_likes_repository: T, user_relation_repository: B) -> Self {
+        Self {
+            post_likes_repository,
+            user_relation_repository,
+        }
+    }
+
+    pub async fn execute(&self, input: GetPostReactionsInput) -> AppResult<GetPostReactionsOutput> {
+        ensure_not_blocked_by_post_author(
+            &self.user_relation_repository,
+            Id::new(input.post_id),
+            input.user_id.filter(|_| !input.is_moderator),
+        )
+        .await?;
+
+        let reactions = self
+            .post_likes_repository
+            .get_reaction_counts(Id::new(input.post_id))
+            .await?;
+
+        let user_reaction = match input.user_id {
+            Some(user_id) => {
+                self.post_likes_repository
+                    .get_user_reaction(Id::new(input.post_id), Id::new(user_id))
+                    .await?
+            }
+            None => None,
+        };
+
+        Ok(GetPostReactionsOutput {
+            reactions,
+            user_reaction,
+        })
+    }
+}
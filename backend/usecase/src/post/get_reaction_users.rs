@@ -0,0 +1,66 @@
+use models::{
+    domain::{post_like::PostLike, user::User, Id},
+    errors::AppResult,
+};
+use repository::{
+    post_likes_repository::PostLikesRepository, user_relation_repository::UserRelationRepository,
+};
+use uuid::Uuid;
+
+use crate::user_relation::check_user_block::ensure_not_blocked_by_post_author;
+
+#[derive(Debug)]
+pub struct GetReactionUsersInput {
+    pub post_id: Uuid,
+    pub kind: String,
+    pub pagination: (i64, i64),
+    pub viewer_id: Option<Uuid>,
+    pub is_moderator: bool,
+}
+
+pub struct GetReactionUsersOutput {
+    pub reactions: Vec<(PostLike, User)>,
+}
+
+pub struct GetReactionUsersUseCase<T, B>
+where
+    T: PostLikesRepository,
+    B: UserRelationRepository,
+{
+    post_likes_repository: T,
+    user_relation_repository: B,
+}
+
+impl<T, B> GetReactionUsersUseCase<T, B>
+where
+    T: PostLikesRepository,
+    B: UserRelationRepository,
+{
+    pub fn new(post_likes_repository: T, user_relation_repository: B) -> Self {
+        Self {
+            post_likes_repository,
+            user_relation_repository,
+        }
+    }
+
+    pub async fn execute(&self, input: GetReactionUsersInput) -> AppResult<GetReactionUsersOutput> {
+        ensure_not_blocked_by_post_author(
+            &self.user_relation_repository,
+            Id::new(input.post_id),
+            input.viewer_id.filter(|_| !input.is_moderator),
+        )
+        .await?;
+
+        let reactions = self
+            .post_likes_repository
+            .get_users_by_reaction(
+                Id::new(input.post_id),
+                input.kind.trim().to_lowercase(),
+                input.pagination.0,
+                input.pagination.1,
+            )
+            .await?;
+
+        Ok(GetReactionUsersOutput { reactions })
+    }
+}
@@ -0,0 +1,79 @@
+use models::{
+    domain::{post_comment::PostComment, Id},
+    errors::{AppError, AppResult},
+};
+use repository::{
+    post_comments_repository::PostCommentsRepository, post_repository::PostRepository,
+};
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub struct HideCommentInput {
+    pub id: Uuid,
+    pub post_id: Uuid,
+    pub user_id: Uuid,
+    /// `false` shows a hidden comment again
+    pub hidden: bool,
+}
+
+pub struct HideCommentOutput {
+    pub comment: PostComment,
+}
+
+pub struct HideCommentUseCase<T, P>
+where
+    T: PostCommentsRepository,
+    P: PostRepository,
+{
+    post_comments_repository: T,
+    post_repository: P,
+}
+
+impl<T, P> HideCommentUseCase<T, P>
+where
+    T: PostCommentsRepository,
+    P: PostRepository,
+{
+    pub fn new(post_comments_repository: T, post_repository: P) -> Self {
+        Self {
+            post_comments_repository,
+            post_repository,
+        }
+    }
+
+    /// Only the author of the post can hide comments under it, moderators delete them instead.
+    pub async fn execute(&self, input: HideCommentInput) -> AppResult<Option<HideCommentOutput>> {
+        let Some(post) = self
+            .post_repository
+            .get_by_id(Id::new(input.post_id))
+            .await?
+        else {
+            return Err(AppError::NotFound("Post".into()));
+        };
+
+        let Some(mut comment) = self
+            .post_comments_repository
+            .get_comment_by_id(Id::new(input.id))
+            .await?
+            .filter(|comment| comment.post_id == post.id)
+        else {
+            return Ok(None);
+        };
+
+        if post.author_id.id != input.user_id {
+            return Err(AppError::Unauthorized(
+                "Only the author of the post can hide its comments".into(),
+            ));
+        }
+
+        match (input.hidden, comment.is_hidden()) {
+            (true, false) => comment.hide(),
+            (false, true) => comment.unhide(),
+            _ => return Ok(Some(HideCommentOutput { comment })),
+        }
+
+        let comment = self.post_comments_repository.update(comment).await?;
+
+        Ok(Some(HideCommentOutput { comment }))
+    }
+}
@@ -0,0 +1,88 @@
+use models::{
+    domain::{comment_like::CommentLike, user_relation::UserRelationKind, Id},
+    errors::AppResult,
+};
+use repository::{
+    comment_likes_repository::CommentLikesRepository,
+    post_comments_repository::PostCommentsRepository,
+    user_relation_repository::UserRelationRepository,
+};
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub struct LikeCommentInput {
+    pub comment_id: Uuid,
+    pub post_id: Uuid,
+    pub user_id: Uuid,
+}
+
+pub struct LikeCommentOutput {
+    pub success: bool,
+}
+
+pub struct LikeCommentUseCase<T, U, B>
+where
+    T: PostCommentsRepository,
+    U: CommentLikesRepository,
+    B: UserRelationRepository,
+{
+    post_comments_repository: T,
+    comment_likes_repository: U,
+    user_relation_repository: B,
+}
+
+impl<T, U, B> LikeCommentUseCase<T, U, B>
+where
+    T: PostCommentsRepository,
+    U: CommentLikesRepository,
+    B: UserRelationRepository,
+{
+    pub fn new(
+        post_comments_repository: T,
+        comment_likes_repository: U,
+        user_relation_repository: B,
+    ) -> Self {
+        Self {
+            post_comments_repository,
+            comment_likes_repository,
+            user_relation_repository,
+        }
+    }
+
+    pub async fn execute(&self, input: LikeCommentInput) -> AppResult<Option<LikeCommentOutput>> {
+        let comment = self
+            .post_comments_repository
+            .get_comment_by_id(Id::new(input.comment_id))
+            .await?;
+
+        match comment {
+            Some(comment) if comment.post_id.id == input.post_id => {
+                // Neither the comment nor the post can be liked by a user their author blocked
+                let user_id = Id::new(input.user_id);
+                let blocked = self
+                    .user_relation_repository
+                    .exists(
+                        comment.user_id.clone(),
+                        user_id.clone(),
+                        UserRelationKind::Block,
+                    )
+                    .await?
+                    || self
+                        .user_relation_repository
+                        .is_blocked_by_post_author(comment.post_id.clone(), user_id)
+                        .await?;
+
+                if blocked {
+                    return Ok(None);
+                }
+
+                self.comment_likes_repository
+                    .create(CommentLike::new(comment.id, Id::new(input.user_id)))
+                    .await?;
+
+                Ok(Some(LikeCommentOutput { success: true }))
+            }
+            _ => Ok(None),
+        }
+    }
+}
@@ -2,7 +2,9 @@ use models::{
     domain::{post_like::PostLike, Id},
     errors::AppResult,
 };
-use repository::post_likes_repository::PostLikesRepository;
+use repository::{
+    post_likes_repository::PostLikesRepository, user_relation_repository::UserRelationRepository,
+};
 use uuid::Uuid;
 
 #[derive(Debug)]
@@ -15,24 +17,36 @@ pub struct LikePostOutput {
     pub id: Uuid,
 }
 
-pub struct LikePostUseCase<T>
+pub struct LikePostUseCase<T, B>
 where
     T: PostLikesRepository,
+    B: UserRelationRepository,
 {
     post_likes_repository: T,
+    user_relation_repository: B,
 }
 
-impl<T> LikePostUseCase<T>
+impl<T, B> LikePostUseCase<T, B>
 where
     T: PostLikesRepository,
+    B: UserRelationRepository,
 {
-    pub fn new(post_likes_repository: T) -> Self {
+    pub fn new(post_likes_repository: T, user_relation_repository: B) -> Self {
         Self {
             post_likes_repository,
+            user_relation_repository,
         }
     }
 
     pub async fn execute(&self, input: LikePostInput) -> AppResult<Option<LikePostOutput>> {
+        if self
+            .user_relation_repository
+            .is_blocked_by_post_author(Id::new(input.post_id), Id::new(input.user_id))
+            .await?
+        {
+            return Ok(None);
+        }
+
         let post_like = PostLike::new(Id::new(input.post_id), Id::new(input.user_id));
 
         Ok(Some(LikePostOutput {
@@ -0,0 +1,36 @@
+use models::domain::{
+    mention::mentioned_usernames, user::User, user_relation::UserRelationKind, Id,
+};
+use repository::{
+    user_relation_repository::UserRelationRepository, user_repository::UserRepository,
+};
+use sea_orm::DbErr;
+
+/// Resolves the `@username` mentions of a text to user ids.
+/// Unknown usernames, mentions of the author and users who blocked the author are left out.
+pub(crate) async fn resolve_mentions<T, B>(
+    user_repository: &T,
+    user_relation_repository: &B,
+    text: &str,
+    author_id: &Id<User>,
+) -> Result<Vec<Id<User>>, DbErr>
+where
+    T: UserRepository,
+    B: UserRelationRepository,
+{
+    let mut user_ids = vec![];
+
+    for username in mentioned_usernames(text) {
+        if let Some(user) = user_repository.get_by_username(username).await? {
+            if &user.id != author_id
+                && !user_relation_repository
+                    .exists(user.id.clone(), author_id.clone(), UserRelationKind::Block)
+                    .await?
+            {
+                user_ids.push(user.id);
+            }
+        }
+    }
+
+    Ok(user_ids)
+}
@@ -2,14 +2,30 @@ pub mod add_post_to_wall;
 pub mod comment_post;
 pub mod create_post;
 pub mod delete_post;
+pub mod edit_comment;
 pub mod get_comment;
+pub mod get_comment_replies;
+pub mod get_comment_tree;
+pub mod get_deleted_posts;
+pub mod get_held_content;
 pub mod get_post;
 pub mod get_post_comments;
 pub mod get_post_is_liked_by_user;
 pub mod get_post_likes;
+pub mod get_post_mentions;
+pub mod get_post_reactions;
+pub mod get_reaction_users;
+pub mod hide_comment;
+pub mod like_comment;
 pub mod like_post;
+pub mod mentions;
+pub mod purge_deleted;
+pub mod react_post;
 pub mod remove_post_from_wall;
+pub mod restore_comment;
+pub mod restore_post;
 pub mod uncomment_post;
+pub mod unlike_comment;
 pub mod unlike_post;
 pub mod update_post;
 pub mod upload_image;
@@ -0,0 +1,53 @@
+use chrono::{Duration, Utc};
+use models::errors::AppResult;
+use repository::{
+    post_comments_repository::PostCommentsRepository, post_repository::PostRepository,
+};
+
+#[derive(Debug)]
+pub struct PurgeDeletedInput {
+    pub retention: Duration,
+}
+
+#[derive(Debug)]
+pub struct PurgeDeletedOutput {
+    pub purged_posts: u64,
+    pub purged_comments: u64,
+}
+
+pub struct PurgeDeletedUseCase<T, U>
+where
+    T: PostRepository,
+    U: PostCommentsRepository,
+{
+    post_repository: T,
+    post_comments_repository: U,
+}
+
+impl<T, U> PurgeDeletedUseCase<T, U>
+where
+    T: PostRepository,
+    U: PostCommentsRepository,
+{
+    pub fn new(post_repository: T, post_comments_repository: U) -> Self {
+        Self {
+            post_repository,
+            post_comments_repository,
+        }
+    }
+
+    pub async fn execute(&self, input: PurgeDeletedInput) -> AppResult<PurgeDeletedOutput> {
+        let before = Utc::now() - input.retention;
+
+        let purged_comments = self
+            .post_comments_repository
+            .purge_deleted_before(before)
+            .await?;
+        let purged_posts = self.post_repository.purge_deleted_before(before).await?;
+
+        Ok(PurgeDeletedOutput {
+            purged_posts,
+            purged_comments,
+        })
+    }
+}
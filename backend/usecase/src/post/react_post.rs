@@ -0,0 +1,93 @@
+use models::{
+    domain::{post_like::PostLike, reaction::ReactionKinds, user_relation::UserRelationKind, Id},
+    errors::{AppError, AppResult},
+};
+use repository::{
+    post_likes_repository::PostLikesRepository, post_repository::PostRepository,
+    user_relation_repository::UserRelationRepository,
+};
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub struct ReactPostInput {
+    pub post_id: Uuid,
+    pub user_id: Uuid,
+    pub kind: String,
+}
+
+pub struct ReactPostOutput {
+    pub kind: String,
+}
+
+pub struct ReactPostUseCase<T, U, B>
+where
+    T: PostRepository,
+    U: PostLikesRepository,
+    B: UserRelationRepository,
+{
+    post_repository: T,
+    post_likes_repository: U,
+    user_relation_repository: B,
+    reaction_kinds: ReactionKinds,
+}
+
+impl<T, U, B> ReactPostUseCase<T, U, B>
+where
+    T: PostRepository,
+    U: PostLikesRepository,
+    B: UserRelationRepository,
+{
+    pub fn new(
+        post_repository: T,
+        post_likes_repository: U,
+        user_relation_repository: B,
+        reaction_kinds: ReactionKinds,
+    ) -> Self {
+        Self {
+            post_repository,
+            post_likes_repository,
+            user_relation_repository,
+            reaction_kinds,
+        }
+    }
+
+    pub async fn execute(&self, input: ReactPostInput) -> AppResult<Option<ReactPostOutput>> {
+        let kind = input.kind.trim().to_lowercase();
+
+        if !self.reaction_kinds.contains(&kind) {
+            return Err(AppError::BadRequest(format!(
+                "Unknown reaction kind '{}'",
+                input.kind
+            )));
+        }
+
+        let post = self
+            .post_repository
+            .get_by_id(Id::new(input.post_id))
+            .await?;
+
+        match post {
+            Some(post)
+                if !self
+                    .user_relation_repository
+                    .exists(
+                        post.author_id.clone(),
+                        Id::new(input.user_id),
+                        UserRelationKind::Block,
+                    )
+                    .await? =>
+            {
+                self.post_likes_repository
+                    .create(PostLike::with_kind(
+                        post.id,
+                        Id::new(input.user_id),
+                        kind.clone(),
+                    ))
+                    .await?;
+
+                Ok(Some(ReactPostOutput { kind }))
+            }
+            _ => Ok(None),
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use models::{
+    domain::Id,
+    errors::{AppError, AppResult},
+};
+use repository::post_comments_repository::PostCommentsRepository;
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub struct RestoreCommentInput {
+    pub id: Uuid,
+    pub post_id: Uuid,
+    pub user_id: Uuid,
+    pub is_moderator: bool,
+}
+
+pub struct RestoreCommentOutput {
+    pub success: bool,
+}
+
+pub struct RestoreCommentUseCase<T>
+where
+    T: PostCommentsRepository,
+{
+    post_comments_repository: T,
+}
+
+impl<T> RestoreCommentUseCase<T>
+where
+    T: PostCommentsRepository,
+{
+    pub fn new(post_comments_repository: T) -> Self {
+        Self {
+            post_comments_repository,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        input: RestoreCommentInput,
+    ) -> AppResult<Option<RestoreCommentOutput>> {
+        let Some(comment) = self
+            .post_comments_repository
+            .get_deleted_comment_by_id(Id::new(input.id))
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        if comment.post_id.id != input.post_id {
+            return Ok(None);
+        }
+
+        let deleted_by_author = comment.deleted_by.as_ref() == Some(&comment.user_id);
+
+        if !input.is_moderator && (comment.user_id.id != input.user_id || !deleted_by_author) {
+            return Err(AppError::Unauthorized(
+                "Only moderators can restore this comment".into(),
+            ));
+        }
+
+        let success = self
+            .post_comments_repository
+            .restore_by_id(comment.id)
+            .await?;
+
+        Ok(Some(RestoreCommentOutput { success }))
+    }
+}
@@ -0,0 +1,56 @@
+use models::{
+    domain::Id,
+    errors::{AppError, AppResult},
+};
+use repository::post_repository::PostRepository;
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub struct RestorePostInput {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub is_moderator: bool,
+}
+
+pub struct RestorePostOutput {
+    pub success: bool,
+}
+
+pub struct RestorePostUseCase<T>
+where
+    T: PostRepository,
+{
+    post_repository: T,
+}
+
+impl<T> RestorePostUseCase<T>
+where
+    T: PostRepository,
+{
+    pub fn new(post_repository: T) -> Self {
+        Self { post_repository }
+    }
+
+    pub async fn execute(&self, input: RestorePostInput) -> AppResult<Option<RestorePostOutput>> {
+        let Some(post) = self
+            .post_repository
+            .get_deleted_by_id(Id::new(input.id))
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        // Authors can only undo their own deletions, posts removed by a moderator stay in the trash
+        let deleted_by_author = post.deleted_by.as_ref() == Some(&post.author_id);
+
+        if !input.is_moderator && (post.author_id.id != input.user_id || !deleted_by_author) {
+            return Err(AppError::Unauthorized(
+                "Only moderators can restore this post".into(),
+            ));
+        }
+
+        let success = self.post_repository.restore_by_id(post.id).await?;
+
+        Ok(Some(RestorePostOutput { success }))
+    }
+}
@@ -1,40 +1,107 @@
-use models::{domain::Id, errors::AppResult};
-use repository::post_comments_repository::PostCommentsRepository;
+use models::{
+    domain::{
+        audit_log::{comment_snapshot, AuditAction, AuditLogEntry},
+        post_comment::PostComment,
+        Id,
+    },
+    errors::{AppError, AppResult},
+};
+use repository::{
+    post_comments_repository::PostCommentsRepository, post_repository::PostRepository,
+};
 use uuid::Uuid;
 
 #[derive(Debug)]
 pub struct UncommentPostInput {
     pub id: Uuid,
+    pub post_id: Uuid,
+    pub deleted_by: Uuid,
+    pub is_moderator: bool,
+    /// Recorded in the audit log when a moderator deletes the comment
+    pub reason: Option<String>,
 }
 
 pub struct UncommentPostOutput {
     pub success: bool,
+    /// The comment as it was before the deletion
+    pub comment: PostComment,
+    /// Whether the comment was deleted by its author or the author of the post
+    pub by_owner: bool,
 }
 
-pub struct UncommentPostUseCase<T>
+pub struct UncommentPostUseCase<T, P>
 where
     T: PostCommentsRepository,
+    P: PostRepository,
 {
     post_comments_repository: T,
+    post_repository: P,
 }
 
-impl<T> UncommentPostUseCase<T>
+impl<T, P> UncommentPostUseCase<T, P>
 where
     T: PostCommentsRepository,
+    P: PostRepository,
 {
-    pub fn new(post_comments_repository: T) -> Self {
+    pub fn new(post_comments_repository: T, post_repository: P) -> Self {
         Self {
             post_comments_repository,
+            post_repository,
         }
     }
 
+    /// Comments can be deleted by their author, by the author of the post and by moderators.
     pub async fn execute(
         &self,
         input: UncommentPostInput,
     ) -> AppResult<Option<UncommentPostOutput>> {
-        self.post_comments_repository
-            .delete_by_id(Id::new(input.id))
+        let Some(post) = self
+            .post_repository
+            .get_by_id(Id::new(input.post_id))
+            .await?
+        else {
+            return Err(AppError::NotFound("Post".into()));
+        };
+
+        let Some(comment) = self
+            .post_comments_repository
+            .get_comment_by_id(Id::new(input.id))
+            .await?
+            .filter(|comment| comment.post_id == post.id)
+        else {
+            return Ok(None);
+        };
+
+        let by_owner =
+            input.deleted_by == post.author_id.id || input.deleted_by == comment.user_id.id;
+
+        if !by_owner && !input.is_moderator {
+            return Err(AppError::Unauthorized("Unauthorized".into()));
+        }
+
+        // Comment and post authors cleaning up their own threads are not moderation
+        let audit = if !by_owner {
+            Some(AuditLogEntry::new(
+                Id::new(input.deleted_by),
+                AuditAction::DeleteComment,
+                input.id,
+                input.reason,
+                Some(comment_snapshot(&comment)),
+                None,
+            )?)
+        } else {
+            None
+        };
+
+        let success = self
+            .post_comments_repository
+            .soft_delete_by_id(comment.id.clone(), Id::new(input.deleted_by), audit)
             .await?;
-        Ok(Some(UncommentPostOutput { success: true }))
+
+        Ok(Some(UncommentPostOutput {
+            success,
+            comment,
+            by_owner,
+        }))
     }
 }
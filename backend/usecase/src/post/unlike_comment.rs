@@ -0,0 +1,60 @@
+use models::{domain::Id, errors::AppResult};
+use repository::{
+    comment_likes_repository::CommentLikesRepository,
+    post_comments_repository::PostCommentsRepository,
+};
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub struct UnlikeCommentInput {
+    pub comment_id: Uuid,
+    pub post_id: Uuid,
+    pub user_id: Uuid,
+}
+
+pub struct UnlikeCommentOutput {
+    pub success: bool,
+}
+
+pub struct UnlikeCommentUseCase<T, U>
+where
+    T: PostCommentsRepository,
+    U: CommentLikesRepository,
+{
+    post_comments_repository: T,
+    comment_likes_repository: U,
+}
+
+impl<T, U> UnlikeCommentUseCase<T, U>
+where
+    T: PostCommentsRepository,
+    U: CommentLikesRepository,
+{
+    pub fn new(post_comments_repository: T, comment_likes_repository: U) -> Self {
+        Self {
+            post_comments_repository,
+            comment_likes_repository,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        input: UnlikeCommentInput,
+    ) -> AppResult<Option<UnlikeCommentOutput>> {
+        let comment = self
+            .post_comments_repository
+            .get_comment_by_id(Id::new(input.comment_id))
+            .await?;
+
+        match comment {
+            Some(comment) if comment.post_id.id == input.post_id => {
+                self.comment_likes_repository
+                    .delete(comment.id, Id::new(input.user_id))
+                    .await?;
+
+                Ok(Some(UnlikeCommentOutput { success: true }))
+            }
+            _ => Ok(None),
+        }
+    }
+}
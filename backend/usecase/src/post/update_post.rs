@@ -1,8 +1,16 @@
 use models::{
-    domain::post::Post,
+    domain::{mention::Mention, post::Post, report::ReportTargetType},
     errors::{AppError, AppResult},
 };
-use repository::post_repository::PostRepository;
+use repository::{
+    content_filter_repository::ContentFilterRepository, mention_repository::MentionRepository,
+    post_repository::PostRepository, report_repository::ReportRepository,
+    user_relation_repository::UserRelationRepository, user_repository::UserRepository,
+};
+
+use crate::content_filter::filter_content::{filter_content, flag_filtered_content, FilterField};
+
+use super::mentions::resolve_mentions;
 
 #[derive(Debug)]
 pub struct UpdatePostInput {
@@ -11,27 +19,124 @@ pub struct UpdatePostInput {
 
 pub struct UpdatePostOutput {
     pub post: Post,
+    /// Mentions added by the update
+    pub mentions: Vec<Mention>,
+    /// Whether the post went back to waiting for a moderator
+    pub held: bool,
 }
 
-pub struct UpdatePostUseCase<T>
+pub struct UpdatePostUseCase<T, U, M, F, R, B>
 where
     T: PostRepository,
+    U: UserRepository,
+    M: MentionRepository,
+    F: ContentFilterRepository,
+    R: ReportRepository,
+    B: UserRelationRepository,
 {
     post_repository: T,
+    user_repository: U,
+    mention_repository: M,
+    content_filter_repository: F,
+    report_repository: R,
+    user_relation_repository: B,
 }
 
-impl<T> UpdatePostUseCase<T>
+impl<T, U, M, F, R, B> UpdatePostUseCase<T, U, M, F, R, B>
 where
     T: PostRepository,
+    U: UserRepository,
+    M: MentionRepository,
+    F: ContentFilterRepository,
+    R: ReportRepository,
+    B: UserRelationRepository,
 {
-    pub fn new(post_repository: T) -> Self {
-        Self { post_repository }
+    pub fn new(
+        post_repository: T,
+        user_repository: U,
+        mention_repository: M,
+        content_filter_repository: F,
+        report_repository: R,
+        user_relation_repository: B,
+    ) -> Self {
+        Self {
+            post_repository,
+            user_repository,
+            mention_repository,
+            content_filter_repository,
+            report_repository,
+            user_relation_repository,
+        }
     }
 
     pub async fn execute(&self, input: UpdatePostInput) -> AppResult<Option<UpdatePostOutput>> {
-        match self.post_repository.update(input.post).await? {
-            Some(post) => Ok(Some(UpdatePostOutput { post })),
-            None => Err(AppError::NotFound("Post not found".to_string())),
+        let mut post = input.post;
+
+        let filtered = filter_content(
+            &self.content_filter_repository,
+            &self.report_repository,
+            Some(post.author_id.clone()),
+            vec![
+                FilterField {
+                    name: "title",
+                    text: post.title.clone(),
+                    maskable: false,
+                },
+                FilterField {
+                    name: "description",
+                    text: post.description.clone(),
+                    maskable: true,
+                },
+            ],
+        )
+        .await?;
+
+        if let Some(description) = filtered.texts.into_iter().nth(1) {
+            post.description = description;
         }
+
+        if filtered.held {
+            post.hold_for_review();
+        }
+
+        let Some(post) = self.post_repository.update(post).await? else {
+            return Err(AppError::NotFound("Post not found".to_string()));
+        };
+
+        flag_filtered_content(
+            &self.report_repository,
+            ReportTargetType::Post,
+            post.id.id,
+            &filtered.hits,
+        )
+        .await?;
+
+        let mentioned_user_ids = if filtered.held {
+            vec![]
+        } else {
+            resolve_mentions(
+                &self.user_repository,
+                &self.user_relation_repository,
+                &post.description,
+                &post.author_id,
+            )
+            .await?
+        };
+
+        let mentions = self
+            .mention_repository
+            .replace_mentions(
+                post.id.clone(),
+                None,
+                post.author_id.clone(),
+                mentioned_user_ids,
+            )
+            .await?;
+
+        Ok(Some(UpdatePostOutput {
+            post,
+            mentions,
+            held: filtered.held,
+        }))
     }
 }
@@ -1,38 +1,147 @@
-use models::errors::AppResult;
-use repository::cloudinary_repository::CloudinaryRepository;
+use models::{
+    domain::{
+        image_metadata::{strip_identifying_metadata, DataUrlImage, ImageMetadata},
+        image_variant::{
+            can_process_image, process_image, ImageLimits, ImageVariant, ImageVariantKind,
+            ProcessedImage,
+        },
+        location::Location,
+    },
+    errors::AppResult,
+};
+use repository::{
+    location_repository::LocationRepository,
+    media_storage::{media_key, MediaStorage},
+};
+
+const LOCATION_SUGGESTION_RADIUS_KM: f64 = 0.5;
 
 #[derive(Debug)]
 pub struct UploadImageInput {
     pub image: String,
+    /// Keeps the GPS position and other identifying EXIF in the stored image
+    pub keep_metadata: bool,
+    pub limits: ImageLimits,
 }
 
 pub struct UploadImageOutput {
+    /// URL of the full size variant, or of the uploaded image when it has no variants
     pub url: String,
+    pub variants: Vec<ImageVariant>,
+    pub metadata: ImageMetadata,
+    /// The closest known location to where the image was taken
+    pub suggested_location: Option<Location>,
 }
 
-pub struct UploadImageUseCase<T>
+pub struct UploadImageUseCase<T, L>
 where
-    T: CloudinaryRepository,
+    T: MediaStorage,
+    L: LocationRepository,
 {
-    cloudinary_repository: T,
+    media_storage: T,
+    location_repository: L,
 }
 
-impl<T> UploadImageUseCase<T>
+impl<T, L> UploadImageUseCase<T, L>
 where
-    T: CloudinaryRepository,
+    T: MediaStorage,
+    L: LocationRepository,
 {
-    pub fn new(cloudinary_repository: T) -> Self {
+    pub fn new(media_storage: T, location_repository: L) -> Self {
         Self {
-            cloudinary_repository,
+            media_storage,
+            location_repository,
         }
     }
 
     pub async fn execute(&self, input: UploadImageInput) -> AppResult<UploadImageOutput> {
-        let result = self.cloudinary_repository.upload_image(input.image).await;
+        let mut image = DataUrlImage::parse(&input.image)?;
+        input.limits.check_size(image.bytes.len())?;
+
+        let metadata = if image.has_exif_format() {
+            ImageMetadata::read(&image.bytes)
+        } else {
+            ImageMetadata::default()
+        };
+
+        if image.has_exif_format() && !input.keep_metadata {
+            image.bytes = strip_identifying_metadata(&image.bytes, metadata.orientation)?;
+        }
+
+        // HEIC can't be decoded on the server, such images are stored as uploaded
+        let (url, variants) = if image.is_heif() && !can_process_image(&image.bytes) {
+            let key = media_key(&image.mime_type, &image.bytes);
+            let url = self.store(&key, &image.mime_type, image.bytes).await?;
+
+            (url, vec![])
+        } else {
+            let variants = self
+                .store_variants(image.bytes, metadata.orientation, input.limits)
+                .await?;
+            let url = variants
+                .iter()
+                .find(|variant| variant.kind == ImageVariantKind::Full)
+                .map(|variant| variant.url.clone())
+                .ok_or_else(|| anyhow::anyhow!("Image processing produced no full size variant"))?;
+
+            (url, variants)
+        };
+
+        let suggested_location = match metadata.location {
+            Some(point) => self
+                .location_repository
+                .get_nearby(point, LOCATION_SUGGESTION_RADIUS_KM, 1)
+                .await?
+                .into_iter()
+                .next()
+                .map(|(location, _)| location),
+            None => None,
+        };
 
-        match result {
-            Ok(url) => Ok(UploadImageOutput { url }),
-            Err(_) => Err(anyhow::anyhow!("Failed to upload an image").into()),
+        Ok(UploadImageOutput {
+            url,
+            variants,
+            metadata,
+            suggested_location,
+        })
+    }
+
+    /// Variants are encoded from scratch, so only the orientation of the original is used
+    async fn store_variants(
+        &self,
+        bytes: Vec<u8>,
+        orientation: Option<u16>,
+        limits: ImageLimits,
+    ) -> AppResult<Vec<ImageVariant>> {
+        let processed =
+            tokio::task::spawn_blocking(move || process_image(&bytes, orientation, &limits))
+                .await
+                .map_err(|err| anyhow::anyhow!("Image processing failed: {}", err))??;
+
+        let mut variants = Vec::with_capacity(processed.len());
+
+        for ProcessedImage {
+            kind,
+            width,
+            height,
+            bytes,
+        } in processed
+        {
+            let key = media_key(ProcessedImage::CONTENT_TYPE, &bytes);
+            let url = self
+                .store(&key, ProcessedImage::CONTENT_TYPE, bytes)
+                .await?;
+
+            variants.push(ImageVariant::new(kind, width, height, url)?);
         }
+
+        Ok(variants)
+    }
+
+    async fn store(&self, key: &str, content_type: &str, bytes: Vec<u8>) -> AppResult<String> {
+        self.media_storage
+            .store(key, content_type, bytes)
+            .await
+            .map_err(|err| anyhow::anyhow!("Failed to upload an image: {}", err).into())
     }
 }
@@ -42,8 +42,9 @@ where
         }
     }
 
+    /// Tags are normalized before they are stored, adding a tag the post already has succeeds.
     pub async fn execute(&self, input: CreatePostTagInput) -> AppResult<CreatePostTagOutput> {
-        let tag = PostTag::new(Id::new(input.post_id), input.tag);
+        let tag = PostTag::new(Id::new(input.post_id), input.tag)?;
         let inserted = self.post_tag_repository.create(tag).await?;
 
         Ok(CreatePostTagOutput {
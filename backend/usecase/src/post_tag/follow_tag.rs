@@ -0,0 +1,43 @@
+use models::{
+    domain::{tag_follow::TagFollow, Id},
+    errors::AppResult,
+};
+use repository::tag_follow_repository::TagFollowRepository;
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub struct FollowTagInput {
+    pub tag: String,
+    pub user_id: Uuid,
+}
+
+pub struct FollowTagOutput {
+    pub tag: String,
+}
+
+pub struct FollowTagUseCase<F>
+where
+    F: TagFollowRepository,
+{
+    tag_follow_repository: F,
+}
+
+impl<F> FollowTagUseCase<F>
+where
+    F: TagFollowRepository,
+{
+    pub fn new(tag_follow_repository: F) -> Self {
+        Self {
+            tag_follow_repository,
+        }
+    }
+
+    pub async fn execute(&self, input: FollowTagInput) -> AppResult<FollowTagOutput> {
+        let tag_follow = TagFollow::new(Id::new(input.user_id), &input.tag)?;
+        let tag = tag_follow.tag.clone();
+
+        self.tag_follow_repository.follow(tag_follow).await?;
+
+        Ok(FollowTagOutput { tag })
+    }
+}
@@ -0,0 +1,42 @@
+use models::{
+    domain::{tag_follow::TagFollow, Id},
+    errors::AppResult,
+};
+use repository::tag_follow_repository::TagFollowRepository;
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub struct GetFollowedTagsInput {
+    pub user_id: Uuid,
+}
+
+pub struct GetFollowedTagsOutput {
+    pub tags: Vec<TagFollow>,
+}
+
+pub struct GetFollowedTagsUseCase<F>
+where
+    F: TagFollowRepository,
+{
+    tag_follow_repository: F,
+}
+
+impl<F> GetFollowedTagsUseCase<F>
+where
+    F: TagFollowRepository,
+{
+    pub fn new(tag_follow_repository: F) -> Self {
+        Self {
+            tag_follow_repository,
+        }
+    }
+
+    pub async fn execute(&self, input: GetFollowedTagsInput) -> AppResult<GetFollowedTagsOutput> {
+        let tags = self
+            .tag_follow_repository
+            .get_followed_tags(Id::new(input.user_id))
+            .await?;
+
+        Ok(GetFollowedTagsOutput { tags })
+    }
+}
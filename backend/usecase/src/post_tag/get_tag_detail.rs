@@ -0,0 +1,70 @@
+use models::{
+    domain::{
+        post_tag::{normalize_tag, TagDetail},
+        user::User,
+        Id,
+    },
+    errors::AppResult,
+};
+use repository::{tag_follow_repository::TagFollowRepository, tag_repository::TagRepository};
+
+const RELATED_TAGS_LIMIT: i64 = 10;
+
+#[derive(Debug)]
+pub struct GetTagDetailInput {
+    pub tag: String,
+    pub user_id: Option<Id<User>>,
+}
+
+pub struct GetTagDetailOutput {
+    pub detail: TagDetail,
+    pub is_following: bool,
+}
+
+pub struct GetTagDetailUseCase<T, F>
+where
+    T: TagRepository,
+    F: TagFollowRepository,
+{
+    post_tag_repository: T,
+    tag_follow_repository: F,
+}
+
+impl<T, F> GetTagDetailUseCase<T, F>
+where
+    T: TagRepository,
+    F: TagFollowRepository,
+{
+    pub fn new(post_tag_repository: T, tag_follow_repository: F) -> Self {
+        Self {
+            post_tag_repository,
+            tag_follow_repository,
+        }
+    }
+
+    pub async fn execute(&self, input: GetTagDetailInput) -> AppResult<Option<GetTagDetailOutput>> {
+        let tag = normalize_tag(&input.tag);
+
+        let Some(detail) = self
+            .post_tag_repository
+            .get_tag_detail(tag.clone(), input.user_id.clone(), RELATED_TAGS_LIMIT)
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        let is_following = match input.user_id {
+            Some(user_id) => {
+                self.tag_follow_repository
+                    .is_following(user_id, tag)
+                    .await?
+            }
+            None => false,
+        };
+
+        Ok(Some(GetTagDetailOutput {
+            detail,
+            is_following,
+        }))
+    }
+}
@@ -0,0 +1,44 @@
+use chrono::Utc;
+use models::{
+    domain::{post_tag::TrendingTag, user::User, Id},
+    errors::AppResult,
+};
+use repository::tag_repository::TagRepository;
+
+#[derive(Debug)]
+pub struct GetTrendingTagsInput {
+    pub user_id: Option<Id<User>>,
+    pub window: chrono::Duration,
+    pub limit: i64,
+}
+
+pub struct GetTrendingTagsOutput {
+    pub tags: Vec<TrendingTag>,
+}
+
+pub struct GetTrendingTagsUseCase<T>
+where
+    T: TagRepository,
+{
+    post_tag_repository: T,
+}
+
+impl<T> GetTrendingTagsUseCase<T>
+where
+    T: TagRepository,
+{
+    pub fn new(post_tag_repository: T) -> Self {
+        Self {
+            post_tag_repository,
+        }
+    }
+
+    pub async fn execute(&self, input: GetTrendingTagsInput) -> AppResult<GetTrendingTagsOutput> {
+        let tags = self
+            .post_tag_repository
+            .get_trending(input.user_id, Utc::now() - input.window, input.limit)
+            .await?;
+
+        Ok(GetTrendingTagsOutput { tags })
+    }
+}
@@ -1,4 +1,9 @@
 pub mod create_post_tag;
 pub mod delete_tag;
+pub mod follow_tag;
+pub mod get_followed_tags;
 pub mod get_post_tags;
+pub mod get_tag_detail;
+pub mod get_trending_tags;
 pub mod search_tag;
+pub mod unfollow_tag;
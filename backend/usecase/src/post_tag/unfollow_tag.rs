@@ -0,0 +1,43 @@
+use models::{
+    domain::{post_tag::normalize_tag, Id},
+    errors::AppResult,
+};
+use repository::tag_follow_repository::TagFollowRepository;
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub struct UnfollowTagInput {
+    pub tag: String,
+    pub user_id: Uuid,
+}
+
+pub struct UnfollowTagOutput {
+    pub success: bool,
+}
+
+pub struct UnfollowTagUseCase<F>
+where
+    F: TagFollowRepository,
+{
+    tag_follow_repository: F,
+}
+
+impl<F> UnfollowTagUseCase<F>
+where
+    F: TagFollowRepository,
+{
+    pub fn new(tag_follow_repository: F) -> Self {
+        Self {
+            tag_follow_repository,
+        }
+    }
+
+    pub async fn execute(&self, input: UnfollowTagInput) -> AppResult<UnfollowTagOutput> {
+        let success = self
+            .tag_follow_repository
+            .unfollow(Id::new(input.user_id), normalize_tag(&input.tag))
+            .await?;
+
+        Ok(UnfollowTagOutput { success })
+    }
+}
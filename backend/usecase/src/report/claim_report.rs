@@ -0,0 +1,55 @@
+use models::{
+    domain::{report::Report, Id},
+    errors::{AppError, AppResult},
+};
+use repository::report_repository::ReportRepository;
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub struct ClaimReportInput {
+    pub report_id: Uuid,
+    pub moderator_id: Uuid,
+    pub is_moderator: bool,
+}
+
+pub struct ClaimReportOutput {
+    pub report: Report,
+}
+
+pub struct ClaimReportUseCase<T>
+where
+    T: ReportRepository,
+{
+    report_repository: T,
+}
+
+impl<T> ClaimReportUseCase<T>
+where
+    T: ReportRepository,
+{
+    pub fn new(report_repository: T) -> Self {
+        Self { report_repository }
+    }
+
+    pub async fn execute(&self, input: ClaimReportInput) -> AppResult<Option<ClaimReportOutput>> {
+        if !input.is_moderator {
+            return Err(AppError::Unauthorized(
+                "Only moderators can claim reports".into(),
+            ));
+        }
+
+        let Some(mut report) = self
+            .report_repository
+            .get_by_id(Id::new(input.report_id))
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        report.claim(Id::new(input.moderator_id))?;
+
+        let report = self.report_repository.update(report).await?;
+
+        Ok(Some(ClaimReportOutput { report }))
+    }
+}
@@ -0,0 +1,138 @@
+use models::{
+    domain::{
+        report::{Report, ReportReason, ReportTargetType},
+        Id,
+    },
+    errors::{AppError, AppResult},
+};
+use repository::{
+    group_repository::GroupRepository, post_comments_repository::PostCommentsRepository,
+    report_repository::ReportRepository, user_repository::UserRepository,
+    wall_repository::WallRepository,
+};
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub struct CreateReportInput {
+    pub reporter_id: Uuid,
+    pub target_type: ReportTargetType,
+    pub target_id: Uuid,
+    pub reason: ReportReason,
+    pub details: String,
+}
+
+pub struct CreateReportOutput {
+    pub report: Report,
+}
+
+pub struct CreateReportUseCase<R, C, U, G, W>
+where
+    R: ReportRepository,
+    C: PostCommentsRepository,
+    U: UserRepository,
+    G: GroupRepository,
+    W: WallRepository,
+{
+    report_repository: R,
+    post_comments_repository: C,
+    user_repository: U,
+    group_repository: G,
+    wall_repository: W,
+}
+
+impl<R, C, U, G, W> CreateReportUseCase<R, C, U, G, W>
+where
+    R: ReportRepository,
+    C: PostCommentsRepository,
+    U: UserRepository,
+    G: GroupRepository,
+    W: WallRepository,
+{
+    pub fn new(
+        report_repository: R,
+        post_comments_repository: C,
+        user_repository: U,
+        group_repository: G,
+        wall_repository: W,
+    ) -> Self {
+        Self {
+            report_repository,
+            post_comments_repository,
+            user_repository,
+            group_repository,
+            wall_repository,
+        }
+    }
+
+    pub async fn execute(&self, input: CreateReportInput) -> AppResult<CreateReportOutput> {
+        // Posts and comments the reporter can't see are reported as missing, like in `get_post`
+        let target_exists = match input.target_type {
+            ReportTargetType::Post => {
+                self.is_post_visible(input.target_id, input.reporter_id)
+                    .await?
+            }
+            ReportTargetType::Comment => {
+                match self
+                    .post_comments_repository
+                    .get_comment_by_id(Id::new(input.target_id))
+                    .await?
+                {
+                    Some(comment) => {
+                        self.is_post_visible(comment.post_id.id, input.reporter_id)
+                            .await?
+                    }
+                    None => false,
+                }
+            }
+            ReportTargetType::User => self
+                .user_repository
+                .get_by_id(Id::new(input.target_id))
+                .await?
+                .is_some(),
+            ReportTargetType::Group => self
+                .group_repository
+                .get_by_id(&Id::new(input.target_id))
+                .await?
+                .is_some(),
+        };
+
+        if !target_exists {
+            return Err(AppError::NotFound(input.target_type.to_string()));
+        }
+
+        if input.target_type == ReportTargetType::User && input.target_id == input.reporter_id {
+            return Err(AppError::BadRequest("You can not report yourself".into()));
+        }
+
+        if self
+            .report_repository
+            .has_pending_report(
+                Some(Id::new(input.reporter_id)),
+                input.target_type,
+                input.target_id,
+            )
+            .await?
+        {
+            return Err(AppError::Conflict("You have already reported this".into()));
+        }
+
+        let report = Report::new(
+            Id::new(input.reporter_id),
+            input.target_type,
+            input.target_id,
+            input.reason,
+            input.details,
+        )?;
+
+        let report = self.report_repository.create(report).await?;
+
+        Ok(CreateReportOutput { report })
+    }
+
+    async fn is_post_visible(&self, post_id: Uuid, reporter_id: Uuid) -> AppResult<bool> {
+        Ok(self
+            .wall_repository
+            .is_post_visible_to(Id::new(post_id), Some(Id::new(reporter_id)))
+            .await?)
+    }
+}
@@ -0,0 +1,59 @@
+use models::{
+    domain::{report::Report, Id},
+    errors::{AppError, AppResult},
+};
+use repository::report_repository::ReportRepository;
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub struct DismissReportInput {
+    pub report_id: Uuid,
+    pub moderator_id: Uuid,
+    pub is_moderator: bool,
+    pub note: Option<String>,
+}
+
+pub struct DismissReportOutput {
+    pub report: Report,
+}
+
+pub struct DismissReportUseCase<T>
+where
+    T: ReportRepository,
+{
+    report_repository: T,
+}
+
+impl<T> DismissReportUseCase<T>
+where
+    T: ReportRepository,
+{
+    pub fn new(report_repository: T) -> Self {
+        Self { report_repository }
+    }
+
+    pub async fn execute(
+        &self,
+        input: DismissReportInput,
+    ) -> AppResult<Option<DismissReportOutput>> {
+        if !input.is_moderator {
+            return Err(AppError::Unauthorized(
+                "Only moderators can dismiss reports".into(),
+            ));
+        }
+
+        let Some(mut report) = self
+            .report_repository
+            .get_by_id(Id::new(input.report_id))
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        report.dismiss(Id::new(input.moderator_id), input.note)?;
+
+        let report = self.report_repository.update(report).await?;
+
+        Ok(Some(DismissReportOutput { report }))
+    }
+}
@@ -0,0 +1,56 @@
+use models::{
+    domain::report::{Report, ReportStatus, ReportTargetType},
+    errors::{AppError, AppResult},
+};
+use repository::report_repository::ReportRepository;
+
+#[derive(Debug)]
+pub struct GetReportQueueInput {
+    /// Reports that still need attention when not given
+    pub status: Option<ReportStatus>,
+    pub target_type: Option<ReportTargetType>,
+    pub is_moderator: bool,
+    pub pagination: (i64, i64),
+}
+
+pub struct GetReportQueueOutput {
+    pub reports: Vec<Report>,
+}
+
+pub struct GetReportQueueUseCase<T>
+where
+    T: ReportRepository,
+{
+    report_repository: T,
+}
+
+impl<T> GetReportQueueUseCase<T>
+where
+    T: ReportRepository,
+{
+    pub fn new(report_repository: T) -> Self {
+        Self { report_repository }
+    }
+
+    pub async fn execute(&self, input: GetReportQueueInput) -> AppResult<GetReportQueueOutput> {
+        if !input.is_moderator {
+            return Err(AppError::Unauthorized(
+                "Only moderators can see reports".into(),
+            ));
+        }
+
+        let statuses = match input.status {
+            Some(status) => vec![status],
+            None => vec![ReportStatus::Open, ReportStatus::Claimed],
+        };
+
+        let (offset, limit) = input.pagination;
+
+        let reports = self
+            .report_repository
+            .get_queue(statuses, input.target_type, offset, limit)
+            .await?;
+
+        Ok(GetReportQueueOutput { reports })
+    }
+}
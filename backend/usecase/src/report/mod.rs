@@ -0,0 +1,5 @@
+pub mod claim_report;
+pub mod create_report;
+pub mod dismiss_report;
+pub mod get_report_queue;
+pub mod resolve_report;
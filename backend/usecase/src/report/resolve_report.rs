@@ -0,0 +1,207 @@
+use models::{
+    domain::{
+        report::{Report, ReportOutcome, ReportTargetType},
+        user::{User, UserType},
+        Id,
+    },
+    errors::{AppError, AppResult},
+};
+use repository::{
+    post_comments_repository::PostCommentsRepository, post_repository::PostRepository,
+    report_repository::ReportRepository, user_repository::UserRepository,
+};
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::{
+    post::delete_post::{DeletePostInput, DeletePostUseCase},
+    user::block_user::{BlockUserInput, BlockUserUseCase},
+};
+
+/// What the moderator does about the reported content when resolving the report.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum ReportAction {
+    #[default]
+    None,
+    DeletePost,
+    /// Blocks the reported user or the author of the reported post or comment
+    BlockUser,
+}
+
+#[derive(Debug)]
+pub struct ResolveReportInput {
+    pub report_id: Uuid,
+    pub moderator_id: Uuid,
+    pub moderator_role: UserType,
+    pub action: ReportAction,
+    pub note: Option<String>,
+}
+
+pub struct ResolveReportOutput {
+    pub report: Report,
+}
+
+pub struct ResolveReportUseCase<R, P, C, U>
+where
+    R: ReportRepository,
+    P: PostRepository + Clone,
+    C: PostCommentsRepository,
+    U: UserRepository + Clone,
+{
+    report_repository: R,
+    post_repository: P,
+    post_comments_repository: C,
+    user_repository: U,
+    delete_post_use_case: DeletePostUseCase<P>,
+    block_user_use_case: BlockUserUseCase<U>,
+}
+
+impl<R, P, C, U> ResolveReportUseCase<R, P, C, U>
+where
+    R: ReportRepository,
+    P: PostRepository + Clone,
+    C: PostCommentsRepository,
+    U: UserRepository + Clone,
+{
+    pub fn new(
+        report_repository: R,
+        post_repository: P,
+        post_comments_repository: C,
+        user_repository: U,
+    ) -> Self {
+        Self {
+            report_repository,
+            delete_post_use_case: DeletePostUseCase::new(post_repository.clone()),
+            block_user_use_case: BlockUserUseCase::new(user_repository.clone()),
+            post_repository,
+            post_comments_repository,
+            user_repository,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        input: ResolveReportInput,
+    ) -> AppResult<Option<ResolveReportOutput>> {
+        if !input
+            .moderator_role
+            .has_higher_privilege_than(&UserType::Regular)
+        {
+            return Err(AppError::Unauthorized(
+                "Only moderators can resolve reports".into(),
+            ));
+        }
+
+        let Some(mut report) = self
+            .report_repository
+            .get_by_id(Id::new(input.report_id))
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        let outcome = match input.action {
+            ReportAction::None => ReportOutcome::NoAction,
+            ReportAction::DeletePost => ReportOutcome::PostDeleted,
+            ReportAction::BlockUser => ReportOutcome::UserBlocked,
+        };
+
+        // The report is closed first, so nothing is done about an already handled report
+        report.resolve(Id::new(input.moderator_id), outcome, input.note)?;
+
+        // Everything the action needs is checked before the report is stored as resolved
+        let blocked_user = match input.action {
+            ReportAction::None => None,
+            ReportAction::DeletePost => {
+                if report.target_type != ReportTargetType::Post {
+                    return Err(AppError::BadRequest(
+                        "Only reported posts can be deleted".into(),
+                    ));
+                }
+
+                None
+            }
+            ReportAction::BlockUser => {
+                let user = self.responsible_user(&report).await?;
+
+                if input
+                    .moderator_role
+                    .has_lower_or_same_privilege_as(&user.user_type)
+                {
+                    return Err(AppError::Unauthorized(
+                        "You do not have sufficient privileges to block this user.".into(),
+                    ));
+                }
+
+                Some(user)
+            }
+        };
+
+        // The resolution is stored before acting on it, so a retried request finds the report
+        // closed instead of deleting the post or blocking the user a second time
+        let report = self.report_repository.update(report).await?;
+
+        // Both actions are recorded in the audit log by their use cases
+        let reason = report
+            .resolution_note
+            .clone()
+            .unwrap_or_else(|| format!("Reported for {}", report.reason));
+
+        match input.action {
+            ReportAction::None => {}
+            ReportAction::DeletePost => {
+                self.delete_post_use_case
+                    .execute(DeletePostInput {
+                        id: report.target_id,
+                        deleted_by: input.moderator_id,
+                        reason: Some(reason),
+                    })
+                    .await?;
+            }
+            ReportAction::BlockUser => {
+                // A user blocked in the meantime keeps their current suspension
+                if let Some(user) = blocked_user.filter(|user| !user.is_blocked) {
+                    self.block_user_use_case
+                        .execute(BlockUserInput {
+                            user_id: user.id.into(),
+                            moderator_id: input.moderator_id,
+                            reason,
+                            ends_at: None,
+                        })
+                        .await?;
+                }
+            }
+        }
+
+        Ok(Some(ResolveReportOutput { report }))
+    }
+
+    async fn responsible_user(&self, report: &Report) -> AppResult<User> {
+        let user_id = match report.target_type {
+            ReportTargetType::User => Some(Id::new(report.target_id)),
+            ReportTargetType::Post => self
+                .post_repository
+                .get_by_id(Id::new(report.target_id))
+                .await?
+                .map(|post| post.author_id),
+            ReportTargetType::Comment => self
+                .post_comments_repository
+                .get_comment_by_id(Id::new(report.target_id))
+                .await?
+                .map(|comment| comment.user_id),
+            ReportTargetType::Group => {
+                return Err(AppError::BadRequest(
+                    "Reported groups have no author to block".into(),
+                ))
+            }
+        };
+
+        let user = match user_id {
+            Some(user_id) => self.user_repository.get_by_id(user_id).await?,
+            None => None,
+        };
+
+        user.ok_or_else(|| AppError::NotFound("User".into()))
+    }
+}
@@ -0,0 +1,44 @@
+use models::{
+    domain::{saved_collection::SavedCollection, Id},
+    errors::AppResult,
+};
+use repository::saved_posts_repository::SavedPostsRepository;
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub struct CreateCollectionInput {
+    pub user_id: Uuid,
+    pub name: String,
+}
+
+pub struct CreateCollectionOutput {
+    pub collection: SavedCollection,
+}
+
+pub struct CreateCollectionUseCase<T>
+where
+    T: SavedPostsRepository,
+{
+    saved_posts_repository: T,
+}
+
+impl<T> CreateCollectionUseCase<T>
+where
+    T: SavedPostsRepository,
+{
+    pub fn new(saved_posts_repository: T) -> Self {
+        Self {
+            saved_posts_repository,
+        }
+    }
+
+    pub async fn execute(&self, input: CreateCollectionInput) -> AppResult<CreateCollectionOutput> {
+        let collection = SavedCollection::new(Id::new(input.user_id), input.name)?;
+
+        self.saved_posts_repository
+            .create_collection(collection.clone())
+            .await?;
+
+        Ok(CreateCollectionOutput { collection })
+    }
+}
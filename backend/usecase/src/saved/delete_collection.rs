@@ -0,0 +1,58 @@
+use models::{
+    domain::Id,
+    errors::{AppError, AppResult},
+};
+use repository::saved_posts_repository::SavedPostsRepository;
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub struct DeleteCollectionInput {
+    pub id: Uuid,
+    pub user_id: Uuid,
+}
+
+pub struct DeleteCollectionOutput {}
+
+pub struct DeleteCollectionUseCase<T>
+where
+    T: SavedPostsRepository,
+{
+    saved_posts_repository: T,
+}
+
+impl<T> DeleteCollectionUseCase<T>
+where
+    T: SavedPostsRepository,
+{
+    pub fn new(saved_posts_repository: T) -> Self {
+        Self {
+            saved_posts_repository,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        input: DeleteCollectionInput,
+    ) -> AppResult<Option<DeleteCollectionOutput>> {
+        let Some(collection) = self
+            .saved_posts_repository
+            .get_collection_by_id(Id::new(input.id))
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        if collection.user_id.id != input.user_id {
+            return Err(AppError::Unauthorized(
+                "Only the owner can delete the collection".into(),
+            ));
+        }
+
+        // The posts stay saved, they are only taken out of the collection
+        self.saved_posts_repository
+            .delete_collection(collection.id)
+            .await?;
+
+        Ok(Some(DeleteCollectionOutput {}))
+    }
+}
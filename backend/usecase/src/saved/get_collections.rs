@@ -0,0 +1,42 @@
+use models::{
+    domain::{saved_collection::SavedCollection, Id},
+    errors::AppResult,
+};
+use repository::saved_posts_repository::SavedPostsRepository;
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub struct GetCollectionsInput {
+    pub user_id: Uuid,
+}
+
+pub struct GetCollectionsOutput {
+    pub collections: Vec<(SavedCollection, i64)>,
+}
+
+pub struct GetCollectionsUseCase<T>
+where
+    T: SavedPostsRepository,
+{
+    saved_posts_repository: T,
+}
+
+impl<T> GetCollectionsUseCase<T>
+where
+    T: SavedPostsRepository,
+{
+    pub fn new(saved_posts_repository: T) -> Self {
+        Self {
+            saved_posts_repository,
+        }
+    }
+
+    pub async fn execute(&self, input: GetCollectionsInput) -> AppResult<GetCollectionsOutput> {
+        let collections = self
+            .saved_posts_repository
+            .get_collections(Id::new(input.user_id))
+            .await?;
+
+        Ok(GetCollectionsOutput { collections })
+    }
+}
@@ -0,0 +1,65 @@
+use models::{
+    domain::{saved_collection::SavedCollection, user::User, Id},
+    errors::{AppError, AppResult},
+};
+use repository::{
+    saved_posts_repository::SavedPostsRepository,
+    wall_repository::{WallPostTuple, WallRepository},
+};
+
+#[derive(Debug)]
+pub struct GetSavedPostsInput {
+    pub user_id: Id<User>,
+    pub collection_id: Option<Id<SavedCollection>>,
+    pub pagination: (i64, i64),
+}
+
+pub type GetSavedPostsOutput = Vec<WallPostTuple>;
+
+pub struct GetSavedPostsUseCase<T, U>
+where
+    T: WallRepository,
+    U: SavedPostsRepository,
+{
+    wall_repository: T,
+    saved_posts_repository: U,
+}
+
+impl<T, U> GetSavedPostsUseCase<T, U>
+where
+    T: WallRepository,
+    U: SavedPostsRepository,
+{
+    pub fn new(wall_repository: T, saved_posts_repository: U) -> Self {
+        Self {
+            wall_repository,
+            saved_posts_repository,
+        }
+    }
+
+    pub async fn execute(&self, input: GetSavedPostsInput) -> AppResult<GetSavedPostsOutput> {
+        if let Some(collection_id) = &input.collection_id {
+            let collection = self
+                .saved_posts_repository
+                .get_collection_by_id(collection_id.clone())
+                .await?;
+
+            match collection {
+                Some(collection) if collection.user_id == input.user_id => {}
+                _ => return Err(AppError::NotFound("Collection".into())),
+            }
+        }
+
+        let posts = self
+            .wall_repository
+            .get_saved_posts(
+                input.user_id,
+                input.collection_id,
+                input.pagination.0,
+                input.pagination.1,
+            )
+            .await?;
+
+        Ok(posts)
+    }
+}
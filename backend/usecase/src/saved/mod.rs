@@ -0,0 +1,7 @@
+pub mod create_collection;
+pub mod delete_collection;
+pub mod get_collections;
+pub mod get_saved_posts;
+pub mod rename_collection;
+pub mod save_post;
+pub mod unsave_post;
@@ -0,0 +1,63 @@
+use models::{
+    domain::{saved_collection::SavedCollection, Id},
+    errors::{AppError, AppResult},
+};
+use repository::saved_posts_repository::SavedPostsRepository;
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub struct RenameCollectionInput {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+}
+
+pub struct RenameCollectionOutput {
+    pub collection: SavedCollection,
+}
+
+pub struct RenameCollectionUseCase<T>
+where
+    T: SavedPostsRepository,
+{
+    saved_posts_repository: T,
+}
+
+impl<T> RenameCollectionUseCase<T>
+where
+    T: SavedPostsRepository,
+{
+    pub fn new(saved_posts_repository: T) -> Self {
+        Self {
+            saved_posts_repository,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        input: RenameCollectionInput,
+    ) -> AppResult<Option<RenameCollectionOutput>> {
+        let Some(mut collection) = self
+            .saved_posts_repository
+            .get_collection_by_id(Id::new(input.id))
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        if collection.user_id.id != input.user_id {
+            return Err(AppError::Unauthorized(
+                "Only the owner can rename the collection".into(),
+            ));
+        }
+
+        collection.rename(input.name)?;
+
+        let collection = self
+            .saved_posts_repository
+            .update_collection(collection)
+            .await?;
+
+        Ok(Some(RenameCollectionOutput { collection }))
+    }
+}
@@ -0,0 +1,72 @@
+use models::{
+    domain::{saved_post::SavedPost, Id},
+    errors::{AppError, AppResult},
+};
+use repository::{saved_posts_repository::SavedPostsRepository, wall_repository::WallRepository};
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub struct SavePostInput {
+    pub user_id: Uuid,
+    pub post_id: Uuid,
+    pub collection_id: Option<Uuid>,
+}
+
+pub struct SavePostOutput {
+    pub saved_post: SavedPost,
+}
+
+pub struct SavePostUseCase<T, U>
+where
+    T: WallRepository,
+    U: SavedPostsRepository,
+{
+    wall_repository: T,
+    saved_posts_repository: U,
+}
+
+impl<T, U> SavePostUseCase<T, U>
+where
+    T: WallRepository,
+    U: SavedPostsRepository,
+{
+    pub fn new(wall_repository: T, saved_posts_repository: U) -> Self {
+        Self {
+            wall_repository,
+            saved_posts_repository,
+        }
+    }
+
+    pub async fn execute(&self, input: SavePostInput) -> AppResult<Option<SavePostOutput>> {
+        let visible = self
+            .wall_repository
+            .is_post_visible_to(Id::new(input.post_id), Some(Id::new(input.user_id)))
+            .await?;
+
+        if !visible {
+            return Ok(None);
+        }
+
+        if let Some(collection_id) = input.collection_id {
+            let collection = self
+                .saved_posts_repository
+                .get_collection_by_id(Id::new(collection_id))
+                .await?;
+
+            match collection {
+                Some(collection) if collection.user_id.id == input.user_id => {}
+                _ => return Err(AppError::NotFound("Collection".into())),
+            }
+        }
+
+        let saved_post = SavedPost::new(
+            Id::new(input.user_id),
+            Id::new(input.post_id),
+            input.collection_id.map(Id::new),
+        );
+
+        self.saved_posts_repository.save(saved_post.clone()).await?;
+
+        Ok(Some(SavePostOutput { saved_post }))
+    }
+}
@@ -0,0 +1,41 @@
+use models::{domain::Id, errors::AppResult};
+use repository::saved_posts_repository::SavedPostsRepository;
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub struct UnsavePostInput {
+    pub user_id: Uuid,
+    pub post_id: Uuid,
+}
+
+pub struct UnsavePostOutput {}
+
+pub struct UnsavePostUseCase<T>
+where
+    T: SavedPostsRepository,
+{
+    saved_posts_repository: T,
+}
+
+impl<T> UnsavePostUseCase<T>
+where
+    T: SavedPostsRepository,
+{
+    pub fn new(saved_posts_repository: T) -> Self {
+        Self {
+            saved_posts_repository,
+        }
+    }
+
+    pub async fn execute(&self, input: UnsavePostInput) -> AppResult<Option<UnsavePostOutput>> {
+        let removed = self
+            .saved_posts_repository
+            .unsave(Id::new(input.user_id), Id::new(input.post_id))
+            .await?;
+
+        match removed {
+            true => Ok(Some(UnsavePostOutput {})),
+            false => Ok(None),
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use models::{
+    domain::stats::{StatsPoint, StatsRange, StatsTotals},
+    errors::{AppError, AppResult},
+};
+use repository::stats_repository::{StatsMetric, StatsRepository};
+
+#[derive(Debug)]
+pub struct GetStatsInput {
+    pub range: StatsRange,
+    pub is_administrator: bool,
+}
+
+pub struct StatsSeries {
+    pub signups: Vec<StatsPoint>,
+    pub posts: Vec<StatsPoint>,
+    pub comments: Vec<StatsPoint>,
+    pub likes: Vec<StatsPoint>,
+    pub active_users: Vec<StatsPoint>,
+    pub groups: Vec<StatsPoint>,
+    pub join_requests: Vec<StatsPoint>,
+}
+
+pub struct GetStatsOutput {
+    pub totals: StatsTotals,
+    /// Distinct users active anywhere in the range, not the sum of the series
+    pub active_users: i64,
+    pub series: StatsSeries,
+}
+
+pub struct GetStatsUseCase<T>
+where
+    T: StatsRepository,
+{
+    stats_repository: T,
+}
+
+impl<T> GetStatsUseCase<T>
+where
+    T: StatsRepository,
+{
+    pub fn new(stats_repository: T) -> Self {
+        Self { stats_repository }
+    }
+
+    pub async fn execute(&self, input: GetStatsInput) -> AppResult<GetStatsOutput> {
+        if !input.is_administrator {
+            return Err(AppError::Unauthorized(
+                "Only administrators can read the statistics".into(),
+            ));
+        }
+
+        let range = input.range;
+        let series = |metric| self.stats_repository.get_series(metric, range);
+
+        Ok(GetStatsOutput {
+            totals: self.stats_repository.get_totals().await?,
+            active_users: self.stats_repository.get_active_user_count(range).await?,
+            series: StatsSeries {
+                signups: series(StatsMetric::Signups).await?,
+                posts: series(StatsMetric::Posts).await?,
+                comments: series(StatsMetric::Comments).await?,
+                likes: series(StatsMetric::Likes).await?,
+                active_users: series(StatsMetric::ActiveUsers).await?,
+                groups: series(StatsMetric::Groups).await?,
+                join_requests: series(StatsMetric::JoinRequests).await?,
+            },
+        })
+    }
+}
@@ -0,0 +1,2 @@
+pub mod notify_mentions;
+pub mod resolve_report;
@@ -0,0 +1,255 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use models::domain::{
+        location::{GeoPoint, Location},
+        mention::Mention,
+        notification::Notification,
+        post::Post,
+        saved_collection::SavedCollection,
+        user::User,
+        user_relation::{UserRelation, UserRelationKind},
+        wall::Wall,
+        Id,
+    };
+    use repository::{
+        mention_repository::MentionRepository,
+        notification_repository::NotificationRepository,
+        user_relation_repository::UserRelationRepository,
+        wall_repository::{WallPostTuple, WallRepository},
+    };
+    use sea_orm::DbErr;
+    use uuid::Uuid;
+
+    use crate::notification::notify_mentions::{NotifyMentionsInput, NotifyMentionsUseCase};
+
+    /// Every post is visible to everyone
+    struct VisibleWalls;
+
+    impl WallRepository for VisibleWalls {
+        async fn get_by_id(&self, _: Id<Wall>) -> Result<Option<Wall>, DbErr> {
+            unimplemented!()
+        }
+
+        async fn create(&self, _: Wall) -> Result<Id<Wall>, DbErr> {
+            unimplemented!()
+        }
+
+        async fn get_wall_posts(
+            &self,
+            _: Id<Wall>,
+            _: Option<Id<User>>,
+            _: i64,
+            _: i64,
+            _: bool,
+        ) -> Result<Vec<WallPostTuple>, DbErr> {
+            unimplemented!()
+        }
+
+        async fn get_posts_by_tag(
+            &self,
+            _: String,
+            _: Option<Id<User>>,
+            _: i64,
+            _: i64,
+            _: bool,
+        ) -> Result<Vec<WallPostTuple>, DbErr> {
+            unimplemented!()
+        }
+
+        async fn get_feed(
+            &self,
+            _: Option<Id<User>>,
+            _: i64,
+            _: i64,
+        ) -> Result<Vec<WallPostTuple>, DbErr> {
+            unimplemented!()
+        }
+
+        async fn get_followed_tags_feed(
+            &self,
+            _: Id<User>,
+            _: i64,
+            _: i64,
+        ) -> Result<Vec<WallPostTuple>, DbErr> {
+            unimplemented!()
+        }
+
+        async fn get_posts_by_location(
+            &self,
+            _: Id<Location>,
+            _: Option<Id<User>>,
+            _: i64,
+            _: i64,
+        ) -> Result<Vec<WallPostTuple>, DbErr> {
+            unimplemented!()
+        }
+
+        async fn get_posts_nearby(
+            &self,
+            _: GeoPoint,
+            _: f64,
+            _: Option<Id<User>>,
+            _: i64,
+            _: i64,
+        ) -> Result<Vec<(WallPostTuple, f64)>, DbErr> {
+            unimplemented!()
+        }
+
+        async fn get_saved_posts(
+            &self,
+            _: Id<User>,
+            _: Option<Id<SavedCollection>>,
+            _: i64,
+            _: i64,
+        ) -> Result<Vec<WallPostTuple>, DbErr> {
+            unimplemented!()
+        }
+
+        async fn is_post_visible_to(
+            &self,
+            _: Id<Post>,
+            _: Option<Id<User>>,
+        ) -> Result<bool, DbErr> {
+            Ok(true)
+        }
+    }
+
+    #[derive(Clone, Default)]
+    struct Notifications(Arc<Mutex<Vec<Notification>>>);
+
+    impl NotificationRepository for Notifications {
+        async fn create(&self, notification: Notification) -> Result<Id<Notification>, DbErr> {
+            let id = notification.id.clone();
+            self.0.lock().unwrap().push(notification);
+            Ok(id)
+        }
+
+        async fn get_by_user_id(
+            &self,
+            _: Id<User>,
+            _: bool,
+            _: i64,
+            _: i64,
+        ) -> Result<Vec<(Notification, User)>, DbErr> {
+            unimplemented!()
+        }
+
+        async fn count_unread(&self, _: Id<User>) -> Result<u64, DbErr> {
+            unimplemented!()
+        }
+
+        async fn mark_read(
+            &self,
+            _: Id<User>,
+            _: Option<Vec<Id<Notification>>>,
+        ) -> Result<u64, DbErr> {
+            unimplemented!()
+        }
+    }
+
+    #[derive(Clone, Default)]
+    struct DeletedMentions(Arc<Mutex<Vec<Uuid>>>);
+
+    impl MentionRepository for DeletedMentions {
+        async fn replace_mentions(
+            &self,
+            _: Id<Post>,
+            _: Option<Id<models::domain::post_comment::PostComment>>,
+            _: Id<User>,
+            _: Vec<Id<User>>,
+        ) -> Result<Vec<Mention>, DbErr> {
+            unimplemented!()
+        }
+
+        async fn get_by_post_id(&self, _: Id<Post>) -> Result<Vec<(Mention, User)>, DbErr> {
+            unimplemented!()
+        }
+
+        async fn delete(&self, id: Id<Mention>) -> Result<(), DbErr> {
+            self.0.lock().unwrap().push(id.id);
+            Ok(())
+        }
+    }
+
+    /// Blocks as `(user_id, target_id)` pairs
+    struct Blocks(Vec<(Uuid, Uuid)>);
+
+    impl UserRelationRepository for Blocks {
+        async fn create(&self, _: UserRelation) -> Result<(), DbErr> {
+            unimplemented!()
+        }
+
+        async fn delete(
+            &self,
+            _: Id<User>,
+            _: Id<User>,
+            _: UserRelationKind,
+        ) -> Result<bool, DbErr> {
+            unimplemented!()
+        }
+
+        async fn exists(
+            &self,
+            user_id: Id<User>,
+            target_id: Id<User>,
+            kind: UserRelationKind,
+        ) -> Result<bool, DbErr> {
+            Ok(kind == UserRelationKind::Block && self.0.contains(&(user_id.id, target_id.id)))
+        }
+
+        async fn get_by_user(
+            &self,
+            _: Id<User>,
+            _: UserRelationKind,
+        ) -> Result<Vec<(UserRelation, User)>, DbErr> {
+            unimplemented!()
+        }
+
+        async fn is_blocked_by_post_author(&self, _: Id<Post>, _: Id<User>) -> Result<bool, DbErr> {
+            unimplemented!()
+        }
+    }
+
+    #[tokio::test]
+    async fn mention_of_blocker_is_dropped() {
+        let author_id = Id::gen();
+        let blocker_id: Id<User> = Id::gen();
+        let friend_id: Id<User> = Id::gen();
+
+        // The mention comes from a comment, its author is not the author of the post
+        let blocked_mention = Mention::new(
+            Id::gen(),
+            Some(Id::gen()),
+            blocker_id.clone(),
+            author_id.clone(),
+        );
+        let mention = Mention::new(Id::gen(), None, friend_id.clone(), author_id.clone());
+
+        let notifications = Notifications::default();
+        let deleted_mentions = DeletedMentions::default();
+
+        let output = NotifyMentionsUseCase::new(
+            VisibleWalls,
+            notifications.clone(),
+            deleted_mentions.clone(),
+            Blocks(vec![(blocker_id.id, author_id.id)]),
+        )
+        .execute(NotifyMentionsInput {
+            mentions: vec![blocked_mention.clone(), mention],
+        })
+        .await
+        .unwrap();
+
+        let notifications = notifications.0.lock().unwrap();
+
+        assert_eq!(output.notified, 1);
+        assert_eq!(notifications.len(), 1);
+        assert_eq!(notifications[0].user_id, friend_id);
+        assert_eq!(
+            *deleted_mentions.0.lock().unwrap(),
+            vec![blocked_mention.id.id]
+        );
+    }
+}
@@ -0,0 +1,139 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use models::{
+        domain::{
+            report::{Report, ReportOutcome, ReportReason, ReportTargetType},
+            user::{User, UserType},
+            Id,
+        },
+        schema,
+    };
+    use repository::{
+        post_comments_repository::DbPostCommentsRepository, post_repository::DbPostRepository,
+        report_repository::DbReportRepository, user_repository::DbUserRepository,
+    };
+    use sea_orm::{DatabaseConnection, DbBackend, MockDatabase, MockExecResult};
+    use uuid::Uuid;
+
+    use crate::report::resolve_report::{ReportAction, ResolveReportInput, ResolveReportUseCase};
+
+    fn resolve_report_use_case(
+        db: &Arc<DatabaseConnection>,
+    ) -> ResolveReportUseCase<
+        DbReportRepository,
+        DbPostRepository,
+        DbPostCommentsRepository,
+        DbUserRepository,
+    > {
+        ResolveReportUseCase::new(
+            DbReportRepository::new(db.clone()),
+            DbPostRepository::new(db.clone()),
+            DbPostCommentsRepository::new(db.clone()),
+            DbUserRepository::new(db.clone()),
+        )
+    }
+
+    /// A user reported for spam and the report resolved by blocking them
+    fn reported_user(is_blocked: bool) -> (User, Report, Report) {
+        let mut user = User::new(
+            "spammer".into(),
+            None,
+            None,
+            None,
+            UserType::Regular,
+            Id::gen(),
+            "password_hash".into(),
+        )
+        .unwrap();
+        if is_blocked {
+            user.block();
+        }
+
+        let report = Report::new(
+            Id::gen(),
+            ReportTargetType::User,
+            user.id.id,
+            ReportReason::Spam,
+            "Sells watches".into(),
+        )
+        .unwrap();
+        let mut resolved = report.clone();
+        resolved
+            .resolve(Id::gen(), ReportOutcome::UserBlocked, None)
+            .unwrap();
+
+        (user, report, resolved)
+    }
+
+    fn block_user_input(report: &Report) -> ResolveReportInput {
+        ResolveReportInput {
+            report_id: report.id.id,
+            moderator_id: Uuid::new_v4(),
+            moderator_role: UserType::Moderator,
+            action: ReportAction::BlockUser,
+            note: None,
+        }
+    }
+
+    fn statements(db: Arc<DatabaseConnection>) -> Vec<String> {
+        Arc::try_unwrap(db)
+            .unwrap()
+            .into_transaction_log()
+            .iter()
+            .flat_map(|transaction| transaction.statements())
+            .map(|statement| statement.sql.clone())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn report_is_resolved_before_blocking() {
+        let (user, report, resolved) = reported_user(false);
+        let db = Arc::new(
+            MockDatabase::new(DbBackend::Postgres)
+                .append_query_results([vec![schema::report::Model::from(report.clone())]])
+                .append_query_results([vec![schema::user::Model::from(user.clone())]])
+                .append_query_results([vec![schema::report::Model::from(resolved)]])
+                .append_query_results([vec![schema::user::Model::from(user)]])
+                .append_exec_results((0..3).map(|_| MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 1,
+                }))
+                .into_connection(),
+        );
+
+        let output = resolve_report_use_case(&db)
+            .execute(block_user_input(&report))
+            .await
+            .unwrap();
+
+        assert!(output.is_some());
+        let statements = statements(db);
+        assert!(statements[2].starts_with(r#"UPDATE "report""#));
+        assert!(statements[5].starts_with(r#"INSERT INTO "user_suspension""#));
+        assert!(statements.last().unwrap() == "COMMIT");
+    }
+
+    #[tokio::test]
+    async fn blocked_user_is_not_blocked_again() {
+        let (user, report, resolved) = reported_user(true);
+        let db = Arc::new(
+            MockDatabase::new(DbBackend::Postgres)
+                .append_query_results([vec![schema::report::Model::from(report.clone())]])
+                .append_query_results([vec![schema::user::Model::from(user)]])
+                .append_query_results([vec![schema::report::Model::from(resolved)]])
+                .into_connection(),
+        );
+
+        let output = resolve_report_use_case(&db)
+            .execute(block_user_input(&report))
+            .await
+            .unwrap();
+
+        assert!(output.is_some());
+        let statements = statements(db);
+        assert!(statements.len() == 3);
+        assert!(statements[2].starts_with(r#"UPDATE "report""#));
+    }
+}
@@ -1,4 +1,12 @@
-use models::errors::{AppError, AppResult};
+use chrono::{DateTime, Utc};
+use models::{
+    domain::{
+        audit_log::{user_snapshot, AuditAction, AuditLogEntry},
+        user_suspension::UserSuspension,
+        Id,
+    },
+    errors::{AppError, AppResult},
+};
 
 use repository::user_repository::UserRepository;
 use uuid::Uuid;
@@ -6,6 +14,15 @@ use uuid::Uuid;
 #[derive(Debug)]
 pub struct BlockUserInput {
     pub user_id: Uuid,
+    pub moderator_id: Uuid,
+    /// Reason of the suspension, recorded in the audit log as well
+    pub reason: String,
+    /// `None` suspends the user until a moderator unblocks them
+    pub ends_at: Option<DateTime<Utc>>,
+}
+
+pub struct BlockUserOutput {
+    pub suspension: UserSuspension,
 }
 
 pub struct BlockUserUseCase<T>
@@ -23,19 +40,39 @@ where
         Self { user_repository }
     }
 
-    pub async fn execute(&self, input: BlockUserInput) -> AppResult<()> {
+    pub async fn execute(&self, input: BlockUserInput) -> AppResult<BlockUserOutput> {
         let user = self.user_repository.get_by_id(input.user_id.into()).await?;
 
         if user.is_none() {
             return Err(AppError::NotFound("User not found".into()));
         }
 
-        let mut user = user.unwrap();
+        let user = user.unwrap();
+
+        let suspension = UserSuspension::new(
+            user.id.clone(),
+            Some(Id::new(input.moderator_id)),
+            input.reason,
+            input.ends_at,
+        )?;
+
+        // The flag mirrors the active suspensions, so listings can filter by it
+        let mut blocked = user.clone();
+        blocked.block();
 
-        user.block();
+        let audit = AuditLogEntry::new(
+            Id::new(input.moderator_id),
+            AuditAction::BlockUser,
+            input.user_id,
+            Some(suspension.reason.clone()),
+            Some(user_snapshot(&user)),
+            Some(user_snapshot(&blocked)),
+        )?;
 
-        self.user_repository.update(user).await?;
+        self.user_repository
+            .block(suspension.clone(), audit)
+            .await?;
 
-        Ok(())
+        Ok(BlockUserOutput { suspension })
     }
 }
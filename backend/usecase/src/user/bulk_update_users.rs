@@ -0,0 +1,204 @@
+use std::collections::HashSet;
+
+use chrono::{DateTime, Utc};
+use models::{
+    domain::{
+        audit_log::{user_snapshot, AuditAction, AuditLogEntry},
+        user::{User, UserType},
+        user_suspension::UserSuspension,
+        Id,
+    },
+    errors::{AppError, AppResult},
+};
+use repository::user_repository::UserRepository;
+use uuid::Uuid;
+
+/// Upper bound of users changed by one request
+pub const MAX_BULK_USERS: usize = 100;
+
+#[derive(Debug)]
+pub enum BulkUserAction {
+    Block {
+        reason: String,
+        /// `None` suspends the users until a moderator unblocks them
+        ends_at: Option<DateTime<Utc>>,
+    },
+    Unblock,
+    ChangeRole {
+        role: UserType,
+    },
+}
+
+#[derive(Debug)]
+pub struct BulkUpdateUsersInput {
+    pub actor_id: Uuid,
+    pub actor_role: UserType,
+    pub user_ids: Vec<Uuid>,
+    pub action: BulkUserAction,
+    /// Recorded in the audit entry of every changed user
+    pub reason: Option<String>,
+}
+
+pub struct BulkUpdateUsersOutput {
+    /// Changed users as they were before and after the action
+    pub changes: Vec<(User, User)>,
+    /// Users which were already in the requested state
+    pub skipped: Vec<Uuid>,
+}
+
+pub struct BulkUpdateUsersUseCase<T>
+where
+    T: UserRepository,
+{
+    user_repository: T,
+}
+
+impl<T> BulkUpdateUsersUseCase<T>
+where
+    T: UserRepository,
+{
+    pub fn new(user_repository: T) -> Self {
+        Self { user_repository }
+    }
+
+    /// Either every user is changed or none is, a single user the actor can't
+    /// manage fails the whole request.
+    pub async fn execute(&self, input: BulkUpdateUsersInput) -> AppResult<BulkUpdateUsersOutput> {
+        let mut seen = HashSet::new();
+        let user_ids = input
+            .user_ids
+            .into_iter()
+            .filter(|id| seen.insert(*id))
+            .collect::<Vec<_>>();
+
+        if user_ids.is_empty() || user_ids.len() > MAX_BULK_USERS {
+            return Err(AppError::BadRequest(format!(
+                "Between 1 and {MAX_BULK_USERS} users can be changed at once"
+            )));
+        }
+
+        if let BulkUserAction::ChangeRole { role } = &input.action {
+            if !input.actor_role.is_administrator() {
+                return Err(AppError::Unauthorized(
+                    "Only administrators can change roles".into(),
+                ));
+            }
+
+            if input.actor_role.has_lower_privilege_than(role) {
+                return Err(AppError::Unauthorized(
+                    "You can't grant a role above your own".into(),
+                ));
+            }
+        }
+
+        let users = self
+            .user_repository
+            .get_by_ids(user_ids.iter().copied().map(Id::new).collect())
+            .await?;
+
+        if users.len() != user_ids.len() {
+            let found = users.iter().map(|user| user.id.id).collect::<HashSet<_>>();
+            let missing = user_ids
+                .iter()
+                .filter(|id| !found.contains(id))
+                .map(|id| id.to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            return Err(AppError::NotFound(format!("Users {missing}")));
+        }
+
+        let actor_id = Id::new(input.actor_id);
+
+        if let Some(user) = users
+            .iter()
+            .find(|user| !user.is_manageable_by(&actor_id, &input.actor_role))
+        {
+            return Err(AppError::Unauthorized(format!(
+                "You do not have sufficient privileges to change user {}",
+                user.username
+            )));
+        }
+
+        let (pending, skipped): (Vec<User>, Vec<User>) =
+            users.into_iter().partition(|user| match &input.action {
+                BulkUserAction::Block { .. } => !user.is_blocked,
+                BulkUserAction::Unblock => user.is_blocked,
+                BulkUserAction::ChangeRole { role } => &user.user_type != role,
+            });
+
+        let changes = pending
+            .into_iter()
+            .map(|before| {
+                let mut after = before.clone();
+                match &input.action {
+                    BulkUserAction::Block { .. } => after.block(),
+                    BulkUserAction::Unblock => after.unblock(),
+                    BulkUserAction::ChangeRole { role } => after.user_type = role.clone(),
+                }
+                (before, after)
+            })
+            .collect::<Vec<_>>();
+
+        let audit_action = match &input.action {
+            BulkUserAction::Block { .. } => AuditAction::BlockUser,
+            BulkUserAction::Unblock => AuditAction::UnblockUser,
+            BulkUserAction::ChangeRole { .. } => AuditAction::ChangeRole,
+        };
+
+        let audit = changes
+            .iter()
+            .map(|(before, after)| {
+                AuditLogEntry::new(
+                    actor_id.clone(),
+                    audit_action,
+                    after.id.id,
+                    input.reason.clone(),
+                    Some(user_snapshot(before)),
+                    Some(user_snapshot(after)),
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let changed_ids = changes
+            .iter()
+            .map(|(user, _)| user.id.clone())
+            .collect::<Vec<_>>();
+
+        // The audit entries are written in the same transaction as the change
+        if !changed_ids.is_empty() {
+            match input.action {
+                BulkUserAction::Block { reason, ends_at } => {
+                    let suspensions = changed_ids
+                        .into_iter()
+                        .map(|user_id| {
+                            UserSuspension::new(
+                                user_id,
+                                Some(actor_id.clone()),
+                                reason.clone(),
+                                ends_at,
+                            )
+                        })
+                        .collect::<Result<Vec<_>, _>>()?;
+
+                    self.user_repository.bulk_block(suspensions, audit).await?;
+                }
+                BulkUserAction::Unblock => {
+                    self.user_repository
+                        .bulk_unblock(changed_ids, actor_id, Utc::now(), audit)
+                        .await?;
+                }
+                BulkUserAction::ChangeRole { role } => {
+                    self.user_repository
+                        .bulk_set_role(changed_ids, role, audit)
+                        .await?;
+                }
+            }
+        }
+
+        Ok(BulkUpdateUsersOutput {
+            changes,
+            skipped: skipped.into_iter().map(|user| user.id.id).collect(),
+        })
+    }
+}
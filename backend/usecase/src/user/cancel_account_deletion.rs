@@ -0,0 +1,51 @@
+use models::{
+    domain::user::User,
+    errors::{AppError, AppResult},
+};
+
+use repository::user_repository::UserRepository;
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub struct CancelAccountDeletionInput {
+    pub user_id: Uuid,
+}
+
+pub type CancelAccountDeletionOutput = User;
+
+pub struct CancelAccountDeletionUseCase<T>
+where
+    T: UserRepository,
+{
+    user_repository: T,
+}
+
+impl<T> CancelAccountDeletionUseCase<T>
+where
+    T: UserRepository,
+{
+    pub fn new(user_repository: T) -> Self {
+        Self { user_repository }
+    }
+
+    pub async fn execute(
+        &self,
+        input: CancelAccountDeletionInput,
+    ) -> AppResult<CancelAccountDeletionOutput> {
+        let user = self.user_repository.get_by_id(input.user_id.into()).await?;
+
+        let Some(mut user) = user else {
+            return Err(AppError::NotFound("User not found".into()));
+        };
+
+        if !user.is_deactivated() {
+            return Ok(user);
+        }
+
+        user.cancel_deletion();
+
+        let user = self.user_repository.update(user).await?;
+
+        Ok(user)
+    }
+}
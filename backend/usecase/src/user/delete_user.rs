@@ -0,0 +1,52 @@
+use models::{
+    domain::{
+        audit_log::{user_snapshot, AuditAction, AuditLogEntry},
+        Id,
+    },
+    errors::{AppError, AppResult},
+};
+use repository::user_repository::UserRepository;
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub struct DeleteUserInput {
+    pub id: Uuid,
+    pub actor_id: Uuid,
+    /// Recorded in the audit log
+    pub reason: Option<String>,
+}
+
+pub struct DeleteUserUseCase<T>
+where
+    T: UserRepository,
+{
+    user_repository: T,
+}
+
+impl<T> DeleteUserUseCase<T>
+where
+    T: UserRepository,
+{
+    pub fn new(user_repository: T) -> Self {
+        Self { user_repository }
+    }
+
+    pub async fn execute(&self, input: DeleteUserInput) -> AppResult<()> {
+        let Some(user) = self.user_repository.get_by_id(Id::new(input.id)).await? else {
+            return Err(AppError::NotFound("User".into()));
+        };
+
+        let audit = AuditLogEntry::new(
+            Id::new(input.actor_id),
+            AuditAction::DeleteUser,
+            input.id,
+            input.reason,
+            Some(user_snapshot(&user)),
+            None,
+        )?;
+
+        self.user_repository.delete_audited(user.id, audit).await?;
+
+        Ok(())
+    }
+}
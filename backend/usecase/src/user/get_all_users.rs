@@ -7,6 +7,7 @@ use repository::user_repository::UserRepository;
 pub struct GetAllUsersInput {
     pub filter_role: Option<UserType>,
     pub filter_is_blocked: Option<bool>,
+    pub filter_is_limited: Option<bool>,
     pub filter_username: Option<String>,
 }
 
@@ -44,6 +45,13 @@ where
                 .collect();
         }
 
+        if let Some(filter_is_limited) = input.filter_is_limited {
+            data = data
+                .into_iter()
+                .filter(|user| user.is_limited == filter_is_limited)
+                .collect();
+        }
+
         if let Some(filter_username) = input.filter_username {
             data = data
                 .into_iter()
@@ -0,0 +1,62 @@
+use chrono::Utc;
+use models::errors::AppResult;
+use repository::{
+    user_repository::UserRepository, user_suspension_repository::UserSuspensionRepository,
+};
+
+pub struct LiftExpiredSuspensionsOutput {
+    pub lifted: usize,
+}
+
+pub struct LiftExpiredSuspensionsUseCase<T, S>
+where
+    T: UserRepository,
+    S: UserSuspensionRepository,
+{
+    user_repository: T,
+    user_suspension_repository: S,
+}
+
+impl<T, S> LiftExpiredSuspensionsUseCase<T, S>
+where
+    T: UserRepository,
+    S: UserSuspensionRepository,
+{
+    pub fn new(user_repository: T, user_suspension_repository: S) -> Self {
+        Self {
+            user_repository,
+            user_suspension_repository,
+        }
+    }
+
+    pub async fn execute(&self) -> AppResult<LiftExpiredSuspensionsOutput> {
+        let now = Utc::now();
+        let expired = self.user_suspension_repository.get_expired(now).await?;
+        let lifted = expired.len();
+
+        for mut suspension in expired {
+            let user_id = suspension.user_id.clone();
+
+            suspension.lift(None);
+            self.user_suspension_repository.update(suspension).await?;
+
+            // A longer suspension issued in the meantime keeps the user blocked
+            let still_suspended = !self
+                .user_suspension_repository
+                .get_active_by_user_id(user_id.clone(), now)
+                .await?
+                .is_empty();
+
+            if still_suspended {
+                continue;
+            }
+
+            if let Some(mut user) = self.user_repository.get_by_id(user_id).await? {
+                user.unblock();
+                self.user_repository.update(user).await?;
+            }
+        }
+
+        Ok(LiftExpiredSuspensionsOutput { lifted })
+    }
+}
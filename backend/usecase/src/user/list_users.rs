@@ -0,0 +1,72 @@
+use models::{
+    domain::user::User,
+    errors::{AppError, AppResult},
+};
+use repository::user_repository::{UserListFilter, UserListOrder, UserRepository};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum UserSortBy {
+    #[default]
+    Newest,
+    Oldest,
+    Username,
+}
+
+impl From<UserSortBy> for UserListOrder {
+    fn from(sort_by: UserSortBy) -> Self {
+        match sort_by {
+            UserSortBy::Newest => Self::Newest,
+            UserSortBy::Oldest => Self::Oldest,
+            UserSortBy::Username => Self::Username,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ListUsersInput {
+    pub filter: UserListFilter,
+    pub sort_by: UserSortBy,
+    pub is_moderator: bool,
+    pub pagination: (i64, i64),
+}
+
+pub struct ListUsersOutput {
+    pub users: Vec<User>,
+    /// Number of users matching the filter across all pages
+    pub total: u64,
+}
+
+pub struct ListUsersUseCase<T>
+where
+    T: UserRepository,
+{
+    user_repository: T,
+}
+
+impl<T> ListUsersUseCase<T>
+where
+    T: UserRepository,
+{
+    pub fn new(user_repository: T) -> Self {
+        Self { user_repository }
+    }
+
+    pub async fn execute(&self, input: ListUsersInput) -> AppResult<ListUsersOutput> {
+        if !input.is_moderator {
+            return Err(AppError::Unauthorized(
+                "Only moderators can list users".into(),
+            ));
+        }
+
+        let (offset, limit) = input.pagination;
+
+        let (users, total) = self
+            .user_repository
+            .get_page(input.filter, input.sort_by.into(), offset, limit)
+            .await?;
+
+        Ok(ListUsersOutput { users, total })
+    }
+}
@@ -1,10 +1,18 @@
 pub mod auth_utils;
 pub mod block_user;
+pub mod bulk_update_users;
+pub mod cancel_account_deletion;
+pub mod delete_user;
 pub mod get_all_users;
 pub mod get_user;
 pub mod get_user_by_username;
+pub mod lift_expired_suspensions;
+pub mod list_users;
+pub mod purge_deleted_accounts;
 pub mod register_user;
+pub mod request_account_deletion;
 pub mod search_user_by_username;
+pub mod set_user_limited;
 pub mod unblock_user;
 pub mod update_user;
 pub mod verify_user;
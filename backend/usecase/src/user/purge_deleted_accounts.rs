@@ -0,0 +1,58 @@
+use chrono::{Duration, Utc};
+use models::errors::AppResult;
+use repository::user_repository::UserRepository;
+
+#[derive(Debug)]
+pub struct PurgeDeletedAccountsInput {
+    pub grace_period: Duration,
+}
+
+#[derive(Debug)]
+pub struct PurgeDeletedAccountsOutput {
+    pub purged_users: usize,
+    /// Accounts that failed to purge, they are tried again on the next run
+    pub failed_users: usize,
+}
+
+pub struct PurgeDeletedAccountsUseCase<T>
+where
+    T: UserRepository,
+{
+    user_repository: T,
+}
+
+impl<T> PurgeDeletedAccountsUseCase<T>
+where
+    T: UserRepository,
+{
+    pub fn new(user_repository: T) -> Self {
+        Self { user_repository }
+    }
+
+    pub async fn execute(
+        &self,
+        input: PurgeDeletedAccountsInput,
+    ) -> AppResult<PurgeDeletedAccountsOutput> {
+        let before = Utc::now() - input.grace_period;
+        let users = self.user_repository.get_deletion_due(before).await?;
+
+        let mut purged_users = 0;
+        let mut failed_users = 0;
+
+        // One transaction per account, a failing account doesn't hold back the others
+        for user in users {
+            match self.user_repository.purge(user.id.clone()).await {
+                Ok(()) => purged_users += 1,
+                Err(err) => {
+                    println!("Failed to purge account {}: {:?}", user.id.id, err);
+                    failed_users += 1;
+                }
+            }
+        }
+
+        Ok(PurgeDeletedAccountsOutput {
+            purged_users,
+            failed_users,
+        })
+    }
+}
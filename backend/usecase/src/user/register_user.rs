@@ -1,5 +1,6 @@
 use models::{
     domain::{
+        content_filter::CompiledContentFilters,
         user::{User, UserType},
         wall::Wall,
         Id,
@@ -7,7 +8,10 @@ use models::{
     errors::AppResult,
 };
 
-use repository::{user_repository::UserRepository, wall_repository::WallRepository};
+use repository::{
+    content_filter_repository::ContentFilterRepository, user_repository::UserRepository,
+    wall_repository::WallRepository,
+};
 use uuid::Uuid;
 use validator::{Validate, ValidationError, ValidationErrors};
 
@@ -27,24 +31,28 @@ pub struct RegisterUserOutput {
     pub id: Uuid,
 }
 
-pub struct RegisterUserUseCase<T, U>
+pub struct RegisterUserUseCase<T, U, F>
 where
     T: UserRepository,
     U: WallRepository,
+    F: ContentFilterRepository,
 {
     user_repository: T,
     wall_repository: U,
+    content_filter_repository: F,
 }
 
-impl<T, U> RegisterUserUseCase<T, U>
+impl<T, U, F> RegisterUserUseCase<T, U, F>
 where
     T: UserRepository,
     U: WallRepository,
+    F: ContentFilterRepository,
 {
-    pub fn new(user_repository: T, wall_repository: U) -> Self {
+    pub fn new(user_repository: T, wall_repository: U, content_filter_repository: F) -> Self {
         Self {
             user_repository,
             wall_repository,
+            content_filter_repository,
         }
     }
 
@@ -81,6 +89,15 @@ where
             validation_errors.add("username", validation_error);
         }
 
+        // Usernames can't be masked or held, any filter hit refuses them
+        let filters = CompiledContentFilters::new(self.content_filter_repository.get_all().await?);
+        if !filters.apply(&input.username).hits.is_empty() {
+            let mut validation_error = ValidationError::new("username");
+            validation_error = validation_error.with_message("Username is not allowed".into());
+            validation_error.add_param("value".into(), &input.username);
+            validation_errors.add("username", validation_error);
+        }
+
         if input.email.is_some() {
             if let Some(u) = self
                 .user_repository
@@ -0,0 +1,52 @@
+use models::{
+    domain::user::User,
+    errors::{AppError, AppResult},
+};
+
+use repository::user_repository::UserRepository;
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub struct RequestAccountDeletionInput {
+    pub user_id: Uuid,
+}
+
+pub type RequestAccountDeletionOutput = User;
+
+pub struct RequestAccountDeletionUseCase<T>
+where
+    T: UserRepository,
+{
+    user_repository: T,
+}
+
+impl<T> RequestAccountDeletionUseCase<T>
+where
+    T: UserRepository,
+{
+    pub fn new(user_repository: T) -> Self {
+        Self { user_repository }
+    }
+
+    pub async fn execute(
+        &self,
+        input: RequestAccountDeletionInput,
+    ) -> AppResult<RequestAccountDeletionOutput> {
+        let user = self.user_repository.get_by_id(input.user_id.into()).await?;
+
+        let Some(mut user) = user else {
+            return Err(AppError::NotFound("User not found".into()));
+        };
+
+        // Asking again keeps the original grace period running
+        if user.is_deactivated() {
+            return Ok(user);
+        }
+
+        user.request_deletion();
+
+        let user = self.user_repository.update(user).await?;
+
+        Ok(user)
+    }
+}
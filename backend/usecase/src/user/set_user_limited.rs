@@ -0,0 +1,66 @@
+use models::{
+    domain::{
+        audit_log::{user_snapshot, AuditAction, AuditLogEntry},
+        user::User,
+        Id,
+    },
+    errors::{AppError, AppResult},
+};
+
+use repository::user_repository::UserRepository;
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub struct SetUserLimitedInput {
+    pub user_id: Uuid,
+    pub is_limited: bool,
+    pub actor_id: Uuid,
+    /// Recorded in the audit log
+    pub reason: Option<String>,
+}
+
+pub type SetUserLimitedOutput = User;
+
+pub struct SetUserLimitedUseCase<T>
+where
+    T: UserRepository,
+{
+    user_repository: T,
+}
+
+impl<T> SetUserLimitedUseCase<T>
+where
+    T: UserRepository,
+{
+    pub fn new(user_repository: T) -> Self {
+        Self { user_repository }
+    }
+
+    pub async fn execute(&self, input: SetUserLimitedInput) -> AppResult<SetUserLimitedOutput> {
+        let user = self.user_repository.get_by_id(input.user_id.into()).await?;
+
+        let Some(user) = user else {
+            return Err(AppError::NotFound("User not found".into()));
+        };
+
+        let mut updated = user.clone();
+        updated.set_limited(input.is_limited);
+
+        let audit = AuditLogEntry::new(
+            Id::new(input.actor_id),
+            if input.is_limited {
+                AuditAction::LimitUser
+            } else {
+                AuditAction::UnlimitUser
+            },
+            input.user_id,
+            input.reason,
+            Some(user_snapshot(&user)),
+            Some(user_snapshot(&updated)),
+        )?;
+
+        let user = self.user_repository.update_audited(updated, audit).await?;
+
+        Ok(user)
+    }
+}
@@ -1,4 +1,11 @@
-use models::errors::{AppError, AppResult};
+use chrono::Utc;
+use models::{
+    domain::{
+        audit_log::{user_snapshot, AuditAction, AuditLogEntry},
+        Id,
+    },
+    errors::{AppError, AppResult},
+};
 
 use repository::user_repository::UserRepository;
 use uuid::Uuid;
@@ -6,6 +13,9 @@ use uuid::Uuid;
 #[derive(Debug)]
 pub struct UnblockUserInput {
     pub user_id: Uuid,
+    pub lifted_by: Uuid,
+    /// Recorded in the audit log
+    pub reason: Option<String>,
 }
 
 pub struct UnblockUserUseCase<T>
@@ -30,11 +40,24 @@ where
             return Err(AppError::NotFound("User not found".into()));
         }
 
-        let mut user = user.unwrap();
+        let user = user.unwrap();
 
-        user.unblock();
+        let mut unblocked = user.clone();
+        unblocked.unblock();
 
-        self.user_repository.update(user).await?;
+        let audit = AuditLogEntry::new(
+            Id::new(input.lifted_by),
+            AuditAction::UnblockUser,
+            input.user_id,
+            input.reason,
+            Some(user_snapshot(&user)),
+            Some(user_snapshot(&unblocked)),
+        )?;
+
+        // Every active suspension is lifted, the user is unblocked and the entry written at once
+        self.user_repository
+            .unblock(user.id, Id::new(input.lifted_by), Utc::now(), audit)
+            .await?;
 
         Ok(())
     }
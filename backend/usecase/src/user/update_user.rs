@@ -1,5 +1,9 @@
 use models::{
-    domain::user::{User, UserType},
+    domain::{
+        audit_log::{user_snapshot, AuditAction, AuditLogEntry},
+        user::{User, UserType},
+        Id,
+    },
     errors::{AppError, AppResult},
 };
 use repository::user_repository::UserRepository;
@@ -20,6 +24,9 @@ pub struct UpdateUserInput {
     pub password: Option<String>,
     pub description: Option<String>,
     pub user: User,
+    pub actor_id: Uuid,
+    /// Recorded in the audit log when the user type changes
+    pub reason: Option<String>,
 }
 
 pub type UpdateUserOutput = User;
@@ -46,6 +53,9 @@ where
             .await?
             .ok_or(AppError::NotFound("User ".into()))?;
 
+        let before = user_snapshot(&user);
+        let role_changed = user.user_type != input.user_type;
+
         let model = User {
             id: input.id.into(),
             email: input.email.clone(),
@@ -60,6 +70,9 @@ where
             },
             wall_id: user.wall_id,
             is_blocked: user.is_blocked,
+            is_limited: user.is_limited,
+            created_at: user.created_at,
+            deletion_requested_at: user.deletion_requested_at,
         };
 
         let mut validation_errors = ValidationErrors::new();
@@ -115,7 +128,23 @@ where
             return Err(validation_errors.into());
         }
 
-        match self.user_repository.update(model).await {
+        // A role change is moderation, it is saved only together with its audit entry
+        let result = if role_changed {
+            let audit = AuditLogEntry::new(
+                Id::new(input.actor_id),
+                AuditAction::ChangeRole,
+                input.id,
+                input.reason,
+                Some(before),
+                Some(user_snapshot(&model)),
+            )?;
+
+            self.user_repository.update_audited(model, audit).await
+        } else {
+            self.user_repository.update(model).await
+        };
+
+        match result {
             Ok(user) => Ok(user),
             // Check if unique constraint is violated
             Err(DbErr::Query(RuntimeErr::SqlxError(SqlxError::Database(e))))
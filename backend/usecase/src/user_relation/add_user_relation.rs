@@ -0,0 +1,90 @@
+use models::{
+    domain::{
+        user_relation::{UserRelation, UserRelationKind},
+        Id,
+    },
+    errors::{AppError, AppResult},
+};
+use repository::{
+    user_relation_repository::UserRelationRepository, user_repository::UserRepository,
+};
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub struct AddUserRelationInput {
+    pub user_id: Uuid,
+    pub target_id: Uuid,
+    pub kind: UserRelationKind,
+}
+
+pub struct AddUserRelationOutput {
+    pub relation: UserRelation,
+}
+
+pub struct AddUserRelationUseCase<T, U>
+where
+    T: UserRelationRepository,
+    U: UserRepository,
+{
+    user_relation_repository: T,
+    user_repository: U,
+}
+
+impl<T, U> AddUserRelationUseCase<T, U>
+where
+    T: UserRelationRepository,
+    U: UserRepository,
+{
+    pub fn new(user_relation_repository: T, user_repository: U) -> Self {
+        Self {
+            user_relation_repository,
+            user_repository,
+        }
+    }
+
+    pub async fn execute(&self, input: AddUserRelationInput) -> AppResult<AddUserRelationOutput> {
+        let relation =
+            UserRelation::new(Id::new(input.user_id), Id::new(input.target_id), input.kind)?;
+
+        if self
+            .user_repository
+            .get_by_id(relation.target_id.clone())
+            .await?
+            .is_none()
+        {
+            return Err(AppError::NotFound("User".into()));
+        }
+
+        // Users can not follow someone who blocked them, and blocking someone ends their follow
+        match relation.kind {
+            UserRelationKind::Follow
+                if self
+                    .user_relation_repository
+                    .exists(
+                        relation.target_id.clone(),
+                        relation.user_id.clone(),
+                        UserRelationKind::Block,
+                    )
+                    .await? =>
+            {
+                return Err(AppError::NotFound("User".into()));
+            }
+            UserRelationKind::Block => {
+                self.user_relation_repository
+                    .delete(
+                        relation.target_id.clone(),
+                        relation.user_id.clone(),
+                        UserRelationKind::Follow,
+                    )
+                    .await?;
+            }
+            _ => {}
+        }
+
+        self.user_relation_repository
+            .create(relation.clone())
+            .await?;
+
+        Ok(AddUserRelationOutput { relation })
+    }
+}
@@ -0,0 +1,78 @@
+use models::{
+    domain::{post::Post, user_relation::UserRelationKind, Id},
+    errors::{AppError, AppResult},
+};
+use repository::user_relation_repository::UserRelationRepository;
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub struct CheckUserBlockInput {
+    /// The user whose content is accessed
+    pub user_id: Uuid,
+    pub viewer_id: Option<Uuid>,
+}
+
+pub struct CheckUserBlockOutput {
+    pub blocked: bool,
+}
+
+/// Tells whether the user blocked the viewer, anonymous viewers are never blocked.
+pub struct CheckUserBlockUseCase<T>
+where
+    T: UserRelationRepository,
+{
+    user_relation_repository: T,
+}
+
+impl<T> CheckUserBlockUseCase<T>
+where
+    T: UserRelationRepository,
+{
+    pub fn new(user_relation_repository: T) -> Self {
+        Self {
+            user_relation_repository,
+        }
+    }
+
+    pub async fn execute(&self, input: CheckUserBlockInput) -> AppResult<CheckUserBlockOutput> {
+        let Some(viewer_id) = input.viewer_id else {
+            return Ok(CheckUserBlockOutput { blocked: false });
+        };
+
+        let blocked = self
+            .user_relation_repository
+            .exists(
+                Id::new(input.user_id),
+                Id::new(viewer_id),
+                UserRelationKind::Block,
+            )
+            .await?;
+
+        Ok(CheckUserBlockOutput { blocked })
+    }
+}
+
+/// Fails as if the post did not exist when its author blocked the viewer, so everything
+/// read through the post (comments, reactions) is hidden like the post itself.
+/// Moderators are passed without a viewer and are never blocked.
+pub(crate) async fn ensure_not_blocked_by_post_author<B>(
+    user_relation_repository: &B,
+    post_id: Id<Post>,
+    viewer_id: Option<Uuid>,
+) -> AppResult<()>
+where
+    B: UserRelationRepository,
+{
+    let Some(viewer_id) = viewer_id else {
+        return Ok(());
+    };
+
+    if user_relation_repository
+        .is_blocked_by_post_author(post_id, Id::new(viewer_id))
+        .await?
+    {
+        return Err(AppError::NotFound("Post".into()));
+    }
+
+    Ok(())
+}
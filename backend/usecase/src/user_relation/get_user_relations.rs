@@ -0,0 +1,47 @@
+use models::{
+    domain::{
+        user::User,
+        user_relation::{UserRelation, UserRelationKind},
+        Id,
+    },
+    errors::AppResult,
+};
+use repository::user_relation_repository::UserRelationRepository;
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub struct GetUserRelationsInput {
+    pub user_id: Uuid,
+    pub kind: UserRelationKind,
+}
+
+pub struct GetUserRelationsOutput {
+    pub relations: Vec<(UserRelation, User)>,
+}
+
+pub struct GetUserRelationsUseCase<T>
+where
+    T: UserRelationRepository,
+{
+    user_relation_repository: T,
+}
+
+impl<T> GetUserRelationsUseCase<T>
+where
+    T: UserRelationRepository,
+{
+    pub fn new(user_relation_repository: T) -> Self {
+        Self {
+            user_relation_repository,
+        }
+    }
+
+    pub async fn execute(&self, input: GetUserRelationsInput) -> AppResult<GetUserRelationsOutput> {
+        let relations = self
+            .user_relation_repository
+            .get_by_user(Id::new(input.user_id), input.kind)
+            .await?;
+
+        Ok(GetUserRelationsOutput { relations })
+    }
+}
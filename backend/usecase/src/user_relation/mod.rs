@@ -0,0 +1,4 @@
+pub mod add_user_relation;
+pub mod check_user_block;
+pub mod get_user_relations;
+pub mod remove_user_relation;
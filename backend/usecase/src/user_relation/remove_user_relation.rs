@@ -0,0 +1,47 @@
+use models::{
+    domain::{user_relation::UserRelationKind, Id},
+    errors::AppResult,
+};
+use repository::user_relation_repository::UserRelationRepository;
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub struct RemoveUserRelationInput {
+    pub user_id: Uuid,
+    pub target_id: Uuid,
+    pub kind: UserRelationKind,
+}
+
+pub struct RemoveUserRelationOutput {
+    pub success: bool,
+}
+
+pub struct RemoveUserRelationUseCase<T>
+where
+    T: UserRelationRepository,
+{
+    user_relation_repository: T,
+}
+
+impl<T> RemoveUserRelationUseCase<T>
+where
+    T: UserRelationRepository,
+{
+    pub fn new(user_relation_repository: T) -> Self {
+        Self {
+            user_relation_repository,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        input: RemoveUserRelationInput,
+    ) -> AppResult<RemoveUserRelationOutput> {
+        let success = self
+            .user_relation_repository
+            .delete(Id::new(input.user_id), Id::new(input.target_id), input.kind)
+            .await?;
+
+        Ok(RemoveUserRelationOutput { success })
+    }
+}
@@ -1,24 +1,29 @@
 use models::{
-    domain::{post::Post, post_comment::PostComment, post_like::PostLike, user::User, Id},
-    errors::AppResult,
+    domain::{
+        mention::Mention, post::Post, post_comment::PostComment, post_like::PostLike, user::User,
+        Id,
+    },
+    errors::{AppError, AppResult},
 };
 use repository::wall_repository::WallRepository;
 
-use super::types::SortBy;
+use super::types::{FeedMode, SortBy};
 
 #[derive(Debug)]
 pub struct GetFeedPostsInput {
     pub user_id: Option<Id<User>>,
     pub pagination: (i64, i64),
     pub sort_by: SortBy,
+    pub mode: FeedMode,
 }
 
 pub type GetFeedPostsOutput = Vec<(
     Post,
     User,
-    Vec<(PostComment, User)>,
+    Vec<(PostComment, User, i64)>,
     Vec<(PostLike, User)>,
     Vec<String>,
+    Vec<(Mention, User)>,
 )>;
 
 pub struct GetFeedPostsUseCase<P: WallRepository> {
@@ -34,10 +39,23 @@ where
     }
 
     pub async fn execute(&self, input: GetFeedPostsInput) -> AppResult<GetFeedPostsOutput> {
-        let mut posts = self
-            .wall_repository
-            .get_feed(input.user_id, input.pagination.0, input.pagination.1)
-            .await?;
+        let mut posts = match (input.mode, input.user_id) {
+            (FeedMode::All, user_id) => {
+                self.wall_repository
+                    .get_feed(user_id, input.pagination.0, input.pagination.1)
+                    .await?
+            }
+            (FeedMode::FollowedTags, Some(user_id)) => {
+                self.wall_repository
+                    .get_followed_tags_feed(user_id, input.pagination.0, input.pagination.1)
+                    .await?
+            }
+            (FeedMode::FollowedTags, None) => {
+                return Err(AppError::Unauthorized(
+                    "Log in to see posts from followed tags".into(),
+                ))
+            }
+        };
 
         match input.sort_by {
             SortBy::Newest => {
@@ -1,5 +1,8 @@
 use models::{
-    domain::{post::Post, post_comment::PostComment, post_like::PostLike, user::User, Id},
+    domain::{
+        mention::Mention, post::Post, post_comment::PostComment, post_like::PostLike,
+        post_tag::normalize_tag, user::User, Id,
+    },
     errors::AppResult,
 };
 use repository::wall_repository::WallRepository;
@@ -18,9 +21,10 @@ pub struct GetTagPostsInput {
 pub type GetTagPostsOutput = Vec<(
     Post,
     User,
-    Vec<(PostComment, User)>,
+    Vec<(PostComment, User, i64)>,
     Vec<(PostLike, User)>,
     Vec<String>,
+    Vec<(Mention, User)>,
 )>;
 
 pub struct GetTagPostsUseCase<P: WallRepository> {
@@ -39,7 +43,7 @@ where
         let mut posts = self
             .wall_repository
             .get_posts_by_tag(
-                input.tag,
+                normalize_tag(&input.tag),
                 input.user_id,
                 input.pagination.0,
                 input.pagination.1,
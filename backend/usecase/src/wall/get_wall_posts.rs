@@ -1,6 +1,7 @@
 use models::{
     domain::{
-        post::Post, post_comment::PostComment, post_like::PostLike, user::User, wall::Wall, Id,
+        mention::Mention, post::Post, post_comment::PostComment, post_like::PostLike, user::User,
+        wall::Wall, Id,
     },
     errors::AppResult,
 };
@@ -21,9 +22,10 @@ pub struct GetWallPostsInput {
 pub type GetWallPostsOutput = Vec<(
     Post,
     User,
-    Vec<(PostComment, User)>,
+    Vec<(PostComment, User, i64)>,
     Vec<(PostLike, User)>,
     Vec<String>,
+    Vec<(Mention, User)>,
 )>;
 
 pub struct GetWallPostsUseCase<P: WallRepository> {
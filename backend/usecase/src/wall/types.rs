@@ -8,3 +8,13 @@ pub enum SortBy {
     Oldest,
     MostLiked,
 }
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum FeedMode {
+    /// Every post the user can see
+    #[default]
+    All,
+    /// Posts with tags the user follows, merged with their own posts
+    FollowedTags,
+}